@@ -0,0 +1,42 @@
+//! Benchmarks of the deserialization of the largest and most frequent gateway
+//! events.
+//!
+//! Run with `cargo bench --features fixtures`.
+
+#[macro_use] extern crate criterion;
+extern crate serde_json;
+extern crate serenity_model;
+
+use criterion::Criterion;
+use serenity_model::event::{GuildCreateEvent, MessageCreateEvent, PresenceUpdateEvent};
+use serenity_model::fixtures;
+
+fn guild_create(c: &mut Criterion) {
+    for &member_count in &[1_000, 10_000, 50_000] {
+        let payload = fixtures::guild_create(member_count);
+
+        c.bench_function(&format!("GUILD_CREATE ({} members)", member_count), move |b| {
+            b.iter(|| serde_json::from_str::<GuildCreateEvent>(&payload).unwrap())
+        });
+    }
+}
+
+fn message_create(c: &mut Criterion) {
+    c.bench_function("MESSAGE_CREATE", |b| {
+        b.iter(|| serde_json::from_str::<MessageCreateEvent>(fixtures::MESSAGE).unwrap())
+    });
+}
+
+fn presence_update(c: &mut Criterion) {
+    c.bench_function("PRESENCE_UPDATE", |b| {
+        b.iter(|| serde_json::from_str::<PresenceUpdateEvent>(fixtures::PRESENCE_UPDATE).unwrap())
+    });
+}
+
+criterion_group! {
+    name = large;
+    config = Criterion::default().sample_size(10);
+    targets = guild_create
+}
+criterion_group!(small, message_create, presence_update);
+criterion_main!(large, small);
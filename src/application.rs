@@ -0,0 +1,280 @@
+//! Models for applications and their linked roles.
+
+use chrono::{DateTime, TimeZone};
+use std::collections::HashMap;
+use std::fmt::Display;
+use super::utils::deserialize_localizations;
+use super::LocalizationMap;
+
+#[cfg(feature = "model")]
+use super::webhook::edit_webhook_message;
+#[cfg(feature = "model")]
+use super::{
+    ApplicationCommand,
+    ApplicationId,
+    CreateApplicationCommand,
+    EditWebhookMessage,
+    ExecuteWebhook,
+    GuildId,
+    Message,
+    MessageId,
+    ModelError,
+    commands_to_value,
+};
+
+#[cfg(feature = "model")]
+use http;
+#[cfg(feature = "model")]
+use internal::prelude::*;
+#[cfg(feature = "model")]
+use serde_json;
+
+enum_number!(
+    #[doc="The comparison made between a user's [`ApplicationRoleConnection`]
+metadata value and the value configured by a guild for a linked role.
+
+[`ApplicationRoleConnection`]: struct.ApplicationRoleConnection.html"]
+    ApplicationRoleConnectionMetadataType {
+        /// The user's integer must be less than or equal to the guild's.
+        IntegerLessThanOrEqual = 1,
+        /// The user's integer must be greater than or equal to the guild's.
+        IntegerGreaterThanOrEqual = 2,
+        /// The user's integer must be equal to the guild's.
+        IntegerEqual = 3,
+        /// The user's integer must not be equal to the guild's.
+        IntegerNotEqual = 4,
+        /// The user's date must be at most the guild's number of days ago.
+        DatetimeLessThanOrEqual = 5,
+        /// The user's date must be at least the guild's number of days ago.
+        DatetimeGreaterThanOrEqual = 6,
+        /// The user's boolean must be equal to the guild's.
+        BooleanEqual = 7,
+        /// The user's boolean must not be equal to the guild's.
+        BooleanNotEqual = 8,
+    }
+);
+
+/// A metadata field that an application exposes for linked roles, which
+/// guilds can require users to meet in order to receive a role.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationRoleConnectionMetadata {
+    /// The description of the field.
+    pub description: String,
+    /// The localized descriptions of the field.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_localizations",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description_localizations: Option<LocalizationMap>,
+    /// The key used for the field in each user's
+    /// [`ApplicationRoleConnection::metadata`].
+    ///
+    /// This may only contain `a-z`, `0-9`, and `_`, and be up to 50
+    /// characters long.
+    ///
+    /// [`ApplicationRoleConnection::metadata`]: struct.ApplicationRoleConnection.html#structfield.metadata
+    pub key: String,
+    /// The type of comparison made for the field.
+    #[serde(rename = "type")]
+    pub kind: ApplicationRoleConnectionMetadataType,
+    /// The name of the field.
+    pub name: String,
+    /// The localized names of the field.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_localizations",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name_localizations: Option<LocalizationMap>,
+}
+
+/// The connection between a user and an application, whose metadata is
+/// compared against the requirements of linked roles.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ApplicationRoleConnection {
+    /// The metadata values of the user, keyed by the
+    /// [`ApplicationRoleConnectionMetadata::key`] of each field.
+    ///
+    /// Values are always strings; use the `set_` methods to format them for
+    /// each type of field.
+    ///
+    /// [`ApplicationRoleConnectionMetadata::key`]: struct.ApplicationRoleConnectionMetadata.html#structfield.key
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// The vanity name of the platform the application links to.
+    pub platform_name: Option<String>,
+    /// The user's username on the platform.
+    pub platform_username: Option<String>,
+}
+
+impl ApplicationRoleConnection {
+    /// Sets the value of a boolean metadata field.
+    pub fn set_boolean<K: ToString>(&mut self, key: K, value: bool) {
+        let value = if value { "1" } else { "0" };
+
+        self.metadata.insert(key.to_string(), value.to_string());
+    }
+
+    /// Sets the value of a date metadata field.
+    pub fn set_datetime<K, Tz>(&mut self, key: K, value: &DateTime<Tz>)
+        where K: ToString, Tz: TimeZone, Tz::Offset: Display {
+        self.metadata.insert(key.to_string(), value.to_rfc3339());
+    }
+
+    /// Sets the value of an integer metadata field.
+    pub fn set_integer<K: ToString>(&mut self, key: K, value: i64) {
+        self.metadata.insert(key.to_string(), value.to_string());
+    }
+}
+
+#[cfg(feature = "model")]
+impl ApplicationId {
+    /// Creates a global application command, or overwrites the one with the
+    /// same name and type, and returns the registered command.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidCommand`] if the command is invalid.
+    ///
+    /// [`ModelError::InvalidCommand`]: enum.ModelError.html#variant.InvalidCommand
+    pub fn create_command(&self, command: &CreateApplicationCommand)
+        -> Result<ApplicationCommand> {
+        let map = command
+            .to_value()
+            .map_err(|why| Error::Model(ModelError::InvalidCommand(why)))?;
+
+        http::create_global_application_command(self.0, &map)
+    }
+
+    /// Sends a followup message in response to an interaction, using the
+    /// interaction's token, and returns the sent message.
+    ///
+    /// Setting [`MessageFlags::EPHEMERAL`] through [`ExecuteWebhook::flags`]
+    /// makes the message only visible to the user that used the interaction.
+    /// The username and avatar of the payload are ignored.
+    ///
+    /// [`ExecuteWebhook::flags`]: struct.ExecuteWebhook.html#method.flags
+    /// [`MessageFlags::EPHEMERAL`]: struct.MessageFlags.html#associatedconstant.EPHEMERAL
+    pub fn create_followup_message<F>(&self, token: &str, f: F) -> Result<Message>
+        where F: FnOnce(ExecuteWebhook) -> ExecuteWebhook {
+        let map = serde_json::to_value(f(ExecuteWebhook::default()))?;
+
+        http::create_followup_message(self.0, token, &map)
+    }
+
+    /// Creates an application command in a guild, or overwrites the one with
+    /// the same name and type, and returns the registered command.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidCommand`] if the command is invalid.
+    ///
+    /// [`ModelError::InvalidCommand`]: enum.ModelError.html#variant.InvalidCommand
+    pub fn create_guild_command<G>(&self, guild_id: G, command: &CreateApplicationCommand)
+        -> Result<ApplicationCommand>
+        where G: Into<GuildId> {
+        let map = command
+            .to_value()
+            .map_err(|why| Error::Model(ModelError::InvalidCommand(why)))?;
+
+        http::create_guild_application_command(self.0, guild_id.into().0, &map)
+    }
+
+    /// Deletes a followup message sent in response to an interaction, using
+    /// the interaction's token.
+    #[inline]
+    pub fn delete_followup_message<M: Into<MessageId>>(&self, token: &str, message_id: M)
+        -> Result<()> {
+        http::delete_webhook_message(self.0, token, message_id.into().0, None)
+    }
+
+    /// Deletes the original response to an interaction, using the
+    /// interaction's token.
+    #[inline]
+    pub fn delete_original_interaction_response(&self, token: &str) -> Result<()> {
+        http::delete_original_interaction_response(self.0, token)
+    }
+
+    /// Edits a followup message sent in response to an interaction, using the
+    /// interaction's token, and returns the edited message.
+    ///
+    /// Refer to [`Webhook::edit_message`] for more information.
+    ///
+    /// [`Webhook::edit_message`]: struct.Webhook.html#method.edit_message
+    pub fn edit_followup_message<M, F>(&self, token: &str, message_id: M, f: F) -> Result<Message>
+        where M: Into<MessageId>, F: FnOnce(EditWebhookMessage) -> EditWebhookMessage {
+        edit_webhook_message(self.0, token, message_id.into(), f)
+    }
+
+    /// Edits the original response to an interaction, using the interaction's
+    /// token, and returns the edited message.
+    ///
+    /// This can also be used to send the response after deferring it.
+    pub fn edit_original_interaction_response<F>(&self, token: &str, f: F) -> Result<Message>
+        where F: FnOnce(EditWebhookMessage) -> EditWebhookMessage {
+        let map = serde_json::to_value(f(EditWebhookMessage::default()))?;
+
+        http::edit_original_interaction_response(self.0, token, &map)
+    }
+
+    /// Gets the original response to an interaction, using the interaction's
+    /// token.
+    #[inline]
+    pub fn original_interaction_response(&self, token: &str) -> Result<Message> {
+        http::get_original_interaction_response(self.0, token)
+    }
+
+    /// Gets the metadata fields that the application exposes for linked
+    /// roles.
+    #[inline]
+    pub fn role_connection_metadata(&self) -> Result<Vec<ApplicationRoleConnectionMetadata>> {
+        http::get_application_role_connection_metadata(self.0)
+    }
+
+    /// Replaces all of the application's global commands with the given
+    /// commands, returning the registered commands.
+    ///
+    /// Refer to [`commands_to_value`] for the checks performed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidCommand`] if one of the commands is
+    /// invalid, or too many are given.
+    ///
+    /// [`commands_to_value`]: fn.commands_to_value.html
+    /// [`ModelError::InvalidCommand`]: enum.ModelError.html#variant.InvalidCommand
+    pub fn set_commands(&self, commands: &[CreateApplicationCommand])
+        -> Result<Vec<ApplicationCommand>> {
+        let map = commands_to_value(commands)
+            .map_err(|why| Error::Model(ModelError::InvalidCommand(why)))?;
+
+        http::bulk_overwrite_global_application_commands(self.0, &map)
+    }
+
+    /// Replaces all of the application's commands in a guild with the given
+    /// commands, returning the registered commands.
+    ///
+    /// Refer to [`set_commands`] for more information.
+    ///
+    /// [`set_commands`]: #method.set_commands
+    pub fn set_guild_commands<G>(&self, guild_id: G, commands: &[CreateApplicationCommand])
+        -> Result<Vec<ApplicationCommand>>
+        where G: Into<GuildId> {
+        let map = commands_to_value(commands)
+            .map_err(|why| Error::Model(ModelError::InvalidCommand(why)))?;
+
+        http::bulk_overwrite_guild_application_commands(self.0, guild_id.into().0, &map)
+    }
+
+    /// Replaces the metadata fields that the application exposes for linked
+    /// roles, returning the new fields.
+    ///
+    /// Up to 5 fields can be given.
+    pub fn set_role_connection_metadata(&self, fields: &[ApplicationRoleConnectionMetadata])
+        -> Result<Vec<ApplicationRoleConnectionMetadata>> {
+        let map = serde_json::to_value(fields)?;
+
+        http::edit_application_role_connection_metadata(self.0, &map)
+    }
+}
@@ -1,15 +1,23 @@
-use ::*;
+use super::super::{ChannelId, Guild, GuildId};
+use super::{AutoOrRegion, ChannelType, GuildChannel, PermissionOverwrite};
+
+#[cfg(feature = "model")]
+use internal::prelude::*;
+#[cfg(feature = "model")]
+use super::{Channel, EditChannel};
 
 /// A category of [`GuildChannel`]s.
 ///
 /// [`GuildChannel`]: struct.GuildChannel.html
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChannelCategory {
     /// Id of this category.
     pub id: ChannelId,
     /// If this category belongs to another category.
     #[serde(rename = "parent_id")]
     pub category_id: Option<ChannelId>,
+    /// The Id of the guild that the category belongs to.
+    pub guild_id: GuildId,
     /// The position of this category.
     pub position: i64,
     /// Indicator of the type of channel this is.
@@ -27,5 +35,82 @@ pub struct ChannelCategory {
     /// Permission overwrites for the [`GuildChannel`]s.
     ///
     /// [`GuildChannel`]: struct.GuildChannel.html
+    #[serde(default)]
     pub permission_overwrites: Vec<PermissionOverwrite>,
-}
\ No newline at end of file
+}
+
+impl From<ChannelCategory> for GuildChannel {
+    /// Converts a category into the form it is stored in a [`Guild`]'s
+    /// channels, as categories are received there alongside other channels.
+    ///
+    /// [`Guild`]: struct.Guild.html
+    fn from(category: ChannelCategory) -> GuildChannel {
+        GuildChannel {
+            id: category.id,
+            bitrate: None,
+            category_id: category.category_id,
+            guild_id: category.guild_id,
+            kind: category.kind,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            member: None,
+            member_count: None,
+            message_count: None,
+            name: category.name,
+            owner_id: None,
+            permission_overwrites: category.permission_overwrites,
+            position: category.position,
+            rate_limit_per_user: None,
+            rtc_region: AutoOrRegion::Automatic,
+            status: None,
+            thread_metadata: None,
+            topic: None,
+            user_limit: None,
+            nsfw: category.nsfw,
+        }
+    }
+}
+
+impl ChannelCategory {
+    /// Retrieves the channels within the category from the given guild.
+    ///
+    /// Refer to [`Guild::channels_in_category`] for more information.
+    ///
+    /// [`Guild::channels_in_category`]: struct.Guild.html#method.channels_in_category
+    #[inline]
+    pub fn channels<'a>(&self, guild: &'a Guild) -> Vec<&'a GuildChannel> {
+        guild.channels_in_category(self.id)
+    }
+}
+
+#[cfg(feature = "model")]
+impl ChannelCategory {
+    /// Deletes the category. The channels within it are kept, and are moved
+    /// out of the category.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    #[inline]
+    pub fn delete(&self, audit_log_reason: Option<&str>) -> Result<Channel> {
+        self.id.delete(audit_log_reason)
+    }
+
+    /// Edits the category, updating the local category with the result.
+    ///
+    /// Refer to [`ChannelId::edit`] for more information.
+    ///
+    /// [`ChannelId::edit`]: struct.ChannelId.html#method.edit
+    pub fn edit<F>(&mut self, f: F) -> Result<()>
+        where F: FnOnce(EditChannel) -> EditChannel {
+        let channel = self.id.edit(f)?;
+
+        self.category_id = channel.category_id;
+        self.name = channel.name;
+        self.nsfw = channel.nsfw;
+        self.permission_overwrites = channel.permission_overwrites;
+        self.position = channel.position;
+
+        Ok(())
+    }
+}
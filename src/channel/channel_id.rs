@@ -1,9 +1,419 @@
-use ::*;
+use super::super::{ChannelId, GuildId, MessageId};
+use super::Channel;
 
+#[cfg(all(feature = "cache", feature = "model"))]
+use CACHE;
+#[cfg(feature = "model")]
+use builder::CreateMessage;
+#[cfg(feature = "model")]
+use http::{self, AttachmentType};
+#[cfg(feature = "model")]
+use internal::prelude::*;
+#[cfg(feature = "model")]
+use serde_json;
+#[cfg(feature = "model")]
+use serenity_common::prelude::{JsonMap, Value};
 #[cfg(feature = "model")]
 use std::borrow::Cow;
 #[cfg(feature = "model")]
-use std::fmt::Write as FmtWrite;
+use super::super::utils::{split_message, MAX_MESSAGE_LENGTH};
+#[cfg(feature = "model")]
+use super::super::{ModelError, User, UserId, Webhook};
+#[cfg(feature = "model")]
+use super::{
+    ArchivedThreadsIter,
+    ChannelType,
+    CreateThread,
+    EditChannel,
+    EditThread,
+    GetMessages,
+    GuildChannel,
+    Message,
+    PermissionOverwriteType,
+    PinsIter,
+    ReactionKind,
+    ReactionType,
+};
+
+impl ChannelId {
+    /// Generates a link to a [`Message`] within the channel, which clients
+    /// can click on to jump to the message.
+    ///
+    /// The Id of the [`Guild`] that the channel belongs to must be given for
+    /// guild channels, and `None` for private channels and groups.
+    ///
+    /// [`Guild`]: struct.Guild.html
+    /// [`Message`]: struct.Message.html
+    pub fn message_link<M>(&self, message_id: M, guild_id: Option<GuildId>) -> String
+        where M: Into<MessageId> {
+        let message_id = message_id.into();
+
+        match guild_id {
+            Some(guild_id) => format!(
+                "https://discordapp.com/channels/{}/{}/{}",
+                guild_id,
+                self.0,
+                message_id,
+            ),
+            None => format!(
+                "https://discordapp.com/channels/@me/{}/{}",
+                self.0,
+                message_id,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "model")]
+impl ChannelId {
+    /// Gets the list of [`Message`]s which are pinned to the channel.
+    ///
+    /// To also retrieve when each message was pinned, use [`pins_iter`].
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [`pins_iter`]: #method.pins_iter
+    #[inline]
+    pub fn pins(&self) -> Result<Vec<Message>> { http::get_pins(self.0) }
+
+    /// Returns an iterator over the channel's [`ChannelPin`]s, which pages
+    /// through the pins as it is advanced.
+    ///
+    /// [`ChannelPin`]: struct.ChannelPin.html
+    #[inline]
+    pub fn pins_iter(&self) -> PinsIter { PinsIter::new(*self) }
+
+    /// Adds a member to the thread.
+    ///
+    /// **Note**: Requires the current user to be able to send messages in the
+    /// thread, and the thread must not be archived.
+    #[inline]
+    pub fn add_thread_member<U: Into<UserId>>(&self, user_id: U) -> Result<()> {
+        http::add_thread_channel_member(self.0, user_id.into().0)
+    }
+
+    /// Returns an iterator over the channel's archived private threads, which
+    /// pages through them as it is advanced.
+    ///
+    /// **Note**: Requires the [Manage Threads] permission.
+    ///
+    /// [Manage Threads]: permissions/constant.MANAGE_THREADS.html
+    #[inline]
+    pub fn archived_private_threads_iter(&self) -> ArchivedThreadsIter {
+        ArchivedThreadsIter::new(*self, true)
+    }
+
+    /// Returns an iterator over the channel's archived public threads, which
+    /// pages through them as it is advanced.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    #[inline]
+    pub fn archived_public_threads_iter(&self) -> ArchivedThreadsIter {
+        ArchivedThreadsIter::new(*self, false)
+    }
+
+    /// Starts a private thread in the channel which is not attached to a
+    /// message, configured through the [`CreateThread`] builder.
+    ///
+    /// **Note**: Requires the [Create Private Threads] permission.
+    ///
+    /// [`CreateThread`]: struct.CreateThread.html
+    /// [Create Private Threads]: permissions/constant.CREATE_PRIVATE_THREADS.html
+    pub fn create_private_thread<F>(&self, f: F) -> Result<GuildChannel>
+        where F: FnOnce(CreateThread) -> CreateThread {
+        let thread = f(CreateThread::default().kind(ChannelType::PrivateThread));
+        let map = serde_json::to_value(thread)?;
+
+        http::create_private_thread(self.0, &map)
+    }
+
+    /// Starts a public thread from a message in the channel, configured
+    /// through the [`CreateThread`] builder.
+    ///
+    /// **Note**: Requires the [Create Public Threads] permission.
+    ///
+    /// [`CreateThread`]: struct.CreateThread.html
+    /// [Create Public Threads]: permissions/constant.CREATE_PUBLIC_THREADS.html
+    pub fn create_public_thread<M, F>(&self, message_id: M, f: F) -> Result<GuildChannel>
+        where M: Into<MessageId>, F: FnOnce(CreateThread) -> CreateThread {
+        let map = serde_json::to_value(f(CreateThread::default()))?;
+
+        http::create_public_thread(self.0, message_id.into().0, &map)
+    }
+
+    /// Creates a webhook in the channel with the given name.
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission.
+    ///
+    /// [Manage Webhooks]: permissions/constant.MANAGE_WEBHOOKS.html
+    pub fn create_webhook(&self, name: &str) -> Result<Webhook> {
+        let mut map = JsonMap::new();
+        map.insert("name".to_string(), Value::String(name.to_string()));
+
+        http::create_webhook(self.0, &Value::Object(map))
+    }
+
+    /// Deletes the channel, or closes it if it is a private channel.
+    ///
+    /// An optional reason may be given, which is shown in the guild's audit
+    /// log.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission for guild channels.
+    ///
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    #[inline]
+    pub fn delete(&self, audit_log_reason: Option<&str>) -> Result<Channel> {
+        http::delete_channel(self.0, audit_log_reason)
+    }
+
+    /// Deletes the permission overwrite of a member or role from the channel.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    pub fn delete_permission(&self, permission_type: PermissionOverwriteType) -> Result<()> {
+        let id = match permission_type {
+            PermissionOverwriteType::Member(id) => id.0,
+            PermissionOverwriteType::Role(id) => id.0,
+        };
+
+        http::delete_permission(self.0, id)
+    }
+
+    /// Edits the guild channel, configured through the [`EditChannel`]
+    /// builder.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::OutOfLimits`] if the channel is outside of
+    /// Discord's limits.
+    ///
+    /// [`EditChannel`]: struct.EditChannel.html
+    /// [`ModelError::OutOfLimits`]: enum.ModelError.html#variant.OutOfLimits
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    pub fn edit<F>(&self, f: F) -> Result<GuildChannel>
+        where F: FnOnce(EditChannel) -> EditChannel {
+        let channel = f(EditChannel::default());
+        let map = channel.to_value().map_err(|why| Error::Model(ModelError::OutOfLimits(why)))?;
+
+        http::edit_channel(self.0, &map, channel.reason.as_ref().map(String::as_str))
+    }
+
+    /// Edits the thread, configured through the [`EditThread`] builder.
+    ///
+    /// **Note**: Requires the [Manage Threads] permission, unless the current
+    /// user started the thread.
+    ///
+    /// [`EditThread`]: struct.EditThread.html
+    /// [Manage Threads]: permissions/constant.MANAGE_THREADS.html
+    pub fn edit_thread<F>(&self, f: F) -> Result<GuildChannel>
+        where F: FnOnce(EditThread) -> EditThread {
+        let thread = f(EditThread::default());
+        let map = serde_json::to_value(&thread)?;
+
+        http::edit_thread(self.0, &map, thread.reason.as_ref().map(String::as_str))
+    }
+
+    /// Adds the current user to the thread.
+    #[inline]
+    pub fn join_thread(&self) -> Result<()> { http::join_thread_channel(self.0) }
+
+    /// Removes the current user from the thread.
+    #[inline]
+    pub fn leave_thread(&self) -> Result<()> { http::leave_thread_channel(self.0) }
+
+    /// Gets messages from the channel, filtered by the given builder.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidMessageLimit`] if the limit is 0 or
+    /// more than 100.
+    ///
+    /// [`ModelError::InvalidMessageLimit`]: enum.ModelError.html#variant.InvalidMessageLimit
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    pub fn messages<F>(&self, f: F) -> Result<Vec<Message>>
+        where F: FnOnce(GetMessages) -> GetMessages {
+        let query = f(GetMessages::default()).to_query().map_err(Error::Model)?;
+
+        http::get_messages(self.0, &query)
+    }
+
+    /// Pins a message to the channel.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission in guild channels.
+    ///
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    #[inline]
+    pub fn pin<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
+        http::pin_message(self.0, message_id.into().0)
+    }
+
+    /// Removes a member from the thread.
+    ///
+    /// **Note**: Requires the [Manage Threads] permission, unless the current
+    /// user started the private thread.
+    ///
+    /// [Manage Threads]: permissions/constant.MANAGE_THREADS.html
+    #[inline]
+    pub fn remove_thread_member<U: Into<UserId>>(&self, user_id: U) -> Result<()> {
+        http::remove_thread_channel_member(self.0, user_id.into().0)
+    }
+
+    /// Gets the users that sent a reaction of the given kind to a message.
+    ///
+    /// The `limit` defaults to `50`, and is capped at `100`. Use `after` to
+    /// page through users, by passing the last user of the previous page.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    pub fn reaction_users<M, R, U>(&self,
+                                   message_id: M,
+                                   reaction_type: R,
+                                   kind: ReactionKind,
+                                   limit: Option<u8>,
+                                   after: Option<U>)
+                                   -> Result<Vec<User>>
+        where M: Into<MessageId>, R: Into<ReactionType>, U: Into<UserId> {
+        let limit = limit.map_or(50, |limit| if limit > 100 { 100 } else { limit });
+
+        http::get_reaction_users(
+            self.0,
+            message_id.into().0,
+            &reaction_type.into(),
+            kind,
+            limit,
+            after.map(|user_id| user_id.into().0),
+        )
+    }
+
+    /// Sends a message with the given content to the channel.
+    ///
+    /// **Note**: Requires the [Send Messages] permission in guild channels.
+    ///
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    pub fn say(&self, content: &str) -> Result<Message> {
+        let mut map = JsonMap::new();
+        map.insert("content".to_string(), Value::String(content.to_string()));
+
+        http::send_message(self.0, &Value::Object(map))
+    }
+
+    /// Sends files along with a message built by the given builder.
+    ///
+    /// **Note**: Requires the [Attach Files] and [Send Messages] permissions
+    /// in guild channels.
+    ///
+    /// [Attach Files]: permissions/constant.ATTACH_FILES.html
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    pub fn send_files<'a, F, T, It>(&self, files: It, f: F) -> Result<Message>
+        where F: FnOnce(CreateMessage) -> CreateMessage,
+              T: Into<AttachmentType<'a>>,
+              It: IntoIterator<Item = T> {
+        let map = match serde_json::to_value(f(CreateMessage::default()))? {
+            Value::Object(map) => map,
+            _ => JsonMap::new(),
+        };
+
+        http::send_files(self.0, files, map)
+    }
+
+    /// Sends a message built by the given builder to the channel.
+    ///
+    /// **Note**: Requires the [Send Messages] permission in guild channels.
+    ///
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    pub fn send_message<F>(&self, f: F) -> Result<Message>
+        where F: FnOnce(CreateMessage) -> CreateMessage {
+        let map = serde_json::to_value(f(CreateMessage::default()))?;
+
+        http::send_message(self.0, &map)
+    }
+
+    /// Sends content to the channel over as many messages as are needed to
+    /// stay within [`MAX_MESSAGE_LENGTH`], returning the sent messages.
+    ///
+    /// The content is split with [`split_message`], which keeps code blocks
+    /// intact. If a message fails to send, the error is returned and the rest
+    /// of the content is not sent.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// [`MAX_MESSAGE_LENGTH`]: constant.MAX_MESSAGE_LENGTH.html
+    /// [`split_message`]: fn.split_message.html
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    pub fn say_long(&self, content: &str) -> Result<Vec<Message>> {
+        split_message(content, MAX_MESSAGE_LENGTH)
+            .into_iter()
+            .map(|chunk| {
+                let mut map = JsonMap::new();
+                map.insert("content".to_string(), Value::String(chunk));
+
+                http::send_message(self.0, &Value::Object(map))
+            })
+            .collect()
+    }
+
+    /// Sets the status of a voice channel, or clears it if `None` is given.
+    ///
+    /// **Note**: Requires the current user to be connected to the channel,
+    /// and to have the Set Voice Channel Status permission.
+    pub fn set_voice_status(&self, status: Option<&str>) -> Result<()> {
+        let mut map = JsonMap::new();
+        map.insert(
+            "status".to_string(),
+            status.map_or(Value::Null, |status| Value::String(status.to_string())),
+        );
+
+        http::edit_voice_channel_status(self.0, &Value::Object(map))
+    }
+
+    /// Unpins a message from the channel.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission in guild channels.
+    ///
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    #[inline]
+    pub fn unpin<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
+        http::unpin_message(self.0, message_id.into().0)
+    }
+}
+
+#[cfg(all(feature = "cache", feature = "model"))]
+impl ChannelId {
+    /// Retrieves the name of the channel from the cache.
+    ///
+    /// Private channels are named after their recipient. `None` is returned
+    /// if the channel is not cached, or is a group without a name.
+    pub fn name(&self) -> Option<String> {
+        let cache = CACHE.read();
+
+        if let Some(channel) = cache.channels.get(self) {
+            return Some(channel.read().name.clone());
+        }
+
+        if let Some(category) = cache.categories.get(self) {
+            return Some(category.read().name.clone());
+        }
+
+        if let Some(channel) = cache.private_channels.get(self) {
+            return Some(channel.read().recipient.name.clone());
+        }
+
+        cache.groups.get(self).and_then(|group| group.read().name.clone())
+    }
+
+    /// Retrieves a copy of the guild channel from the cache, if it is a
+    /// cached guild channel.
+    pub fn to_guild_channel(&self) -> Option<GuildChannel> {
+        CACHE.read().channels.get(self).map(|channel| channel.read().clone())
+    }
+}
 
 impl From<Channel> for ChannelId {
     /// Gets the Id of a `Channel`.
@@ -28,22 +438,3 @@ impl<'a> From<&'a Channel> for ChannelId {
         }
     }
 }
-
-impl From<PrivateChannel> for ChannelId {
-    /// Gets the Id of a private channel.
-    fn from(private_channel: PrivateChannel) -> ChannelId { private_channel.id }
-}
-
-impl<'a> From<&'a PrivateChannel> for ChannelId {
-    /// Gets the Id of a private channel.
-    fn from(private_channel: &PrivateChannel) -> ChannelId { private_channel.id }
-}
-
-impl From<GuildChannel> for ChannelId {
-    /// Gets the Id of a guild channel.
-    fn from(public_channel: GuildChannel) -> ChannelId { public_channel.id }
-}
-impl<'a> From<&'a GuildChannel> for ChannelId {
-    /// Gets the Id of a guild channel.
-    fn from(public_channel: &GuildChannel) -> ChannelId { public_channel.id }
-}
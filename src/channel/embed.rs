@@ -1,6 +1,24 @@
+use super::super::Timestamp;
+
 #[cfg(feature = "utils")]
 use serenity_utils::Colour;
 
+#[cfg(feature = "model")]
+use builder::CreateEmbed;
+#[cfg(feature = "model")]
+use serenity_common::prelude::Value;
+#[cfg(feature = "model")]
+use std::mem;
+#[cfg(feature = "model")]
+use super::super::RgbColour;
+
+/// The maximum number of fields in an embed.
+pub const MAX_EMBED_FIELDS: usize = 25;
+
+/// The maximum number of characters across the title, description, field
+/// names and values, footer, and author name of an embed.
+pub const MAX_EMBED_LENGTH: usize = 6000;
+
 /// Represents a rich embed which allows using richer markdown, multiple fields
 /// and more. This was heavily inspired by [slack's attachments].
 ///
@@ -11,7 +29,7 @@ use serenity_utils::Colour;
 /// 1024 in a field value, and 2048 in a description.
 ///
 /// [slack's attachments]: https://api.slack.com/docs/message-attachments
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Embed {
     /// Information about the author of the embed.
     pub author: Option<EmbedAuthor>,
@@ -47,8 +65,8 @@ pub struct Embed {
     pub provider: Option<EmbedProvider>,
     /// Thumbnail information of the embed.
     pub thumbnail: Option<EmbedThumbnail>,
-    /// Timestamp information.
-    pub timestamp: Option<String>,
+    /// The time shown in the embed's footer.
+    pub timestamp: Option<Timestamp>,
     /// The title of the embed.
     pub title: Option<String>,
     /// The URL of the embed.
@@ -59,8 +77,45 @@ pub struct Embed {
     pub video: Option<EmbedVideo>,
 }
 
+#[cfg(feature = "model")]
+impl Embed {
+    /// Creates a builder with the contents of the embed, so that it can be
+    /// modified and sent again.
+    #[inline]
+    pub fn to_builder(&self) -> CreateEmbed { CreateEmbed::from(self.clone()) }
+}
+
+#[cfg(feature = "model")]
+impl CreateEmbed {
+    /// Sets the colour of the left-hand side of the embed.
+    ///
+    /// This accepts an [`RgbColour`], a tuple of its components, or a raw RGB
+    /// value.
+    ///
+    /// [`RgbColour`]: struct.RgbColour.html
+    pub fn colour<C: Into<RgbColour>>(mut self, colour: C) -> Self {
+        self.0.insert("color", Value::from(colour.into().0));
+
+        self
+    }
+
+    /// Sets the time shown in the embed's footer.
+    ///
+    /// This accepts a date time, or a [`Message`], in which case the time the
+    /// message was sent is used. A number of seconds since the Unix epoch can
+    /// be given through [`Timestamp::from_unix`].
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [`Timestamp::from_unix`]: struct.Timestamp.html#method.from_unix
+    pub fn timestamp<T: Into<Timestamp>>(mut self, timestamp: T) -> Self {
+        self.0.insert("timestamp", Value::String(timestamp.into().to_string()));
+
+        self
+    }
+}
+
 /// An author object in an embed.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedAuthor {
     /// The URL of the author icon.
     ///
@@ -75,7 +130,7 @@ pub struct EmbedAuthor {
 }
 
 /// A field object in an embed.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedField {
     /// Indicator of whether the field should display as inline.
     pub inline: bool,
@@ -90,7 +145,7 @@ pub struct EmbedField {
 }
 
 /// Footer information for an embed.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedFooter {
     /// The URL of the footer icon.
     ///
@@ -103,7 +158,7 @@ pub struct EmbedFooter {
 }
 
 /// An image object in an embed.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedImage {
     /// The height of the image.
     pub height: u64,
@@ -118,7 +173,7 @@ pub struct EmbedImage {
 }
 
 /// The provider of an embed.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedProvider {
     /// The name of the provider.
     pub name: String,
@@ -127,7 +182,7 @@ pub struct EmbedProvider {
 }
 
 /// The dimensions and URL of an embed thumbnail.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedThumbnail {
     /// The height of the thumbnail in pixels.
     pub height: u64,
@@ -142,12 +197,144 @@ pub struct EmbedThumbnail {
 }
 
 /// Video information for an embed.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedVideo {
     /// The height of the video in pixels.
     pub height: u64,
+    /// A proxied URL of the video.
+    #[serde(default)]
+    pub proxy_url: Option<String>,
     /// The source URL of the video.
     pub url: String,
     /// The width of the video in pixels.
     pub width: u64,
 }
+
+/// Splits content over multiple embeds, for bots that let users page through
+/// it, such as via reactions or buttons.
+///
+/// Each page is numbered in its footer, in the form `Page 1/3`.
+#[cfg(feature = "model")]
+#[derive(Clone, Debug)]
+pub struct EmbedPaginator {
+    pages: Vec<CreateEmbed>,
+}
+
+#[cfg(feature = "model")]
+impl EmbedPaginator {
+    /// Creates a paginator with one page per embed.
+    ///
+    /// The footer of each embed is replaced by its page number.
+    pub fn from_embeds<I: IntoIterator<Item = Embed>>(embeds: I) -> Self {
+        EmbedPaginator {
+            pages: embeds.into_iter().map(CreateEmbed::from).collect(),
+        }
+    }
+
+    /// Creates a paginator that places as many fields on each page as fit
+    /// within [`MAX_EMBED_FIELDS`] and [`MAX_EMBED_LENGTH`], with each page
+    /// having the given title.
+    ///
+    /// Fields are kept in order. A field that is too long to fit on a page by
+    /// itself is still given its own page.
+    ///
+    /// [`MAX_EMBED_FIELDS`]: constant.MAX_EMBED_FIELDS.html
+    /// [`MAX_EMBED_LENGTH`]: constant.MAX_EMBED_LENGTH.html
+    pub fn from_fields<I: IntoIterator<Item = EmbedField>>(title: &str, fields: I) -> Self {
+        let fields = fields.into_iter().collect::<Vec<_>>();
+
+        // Room is kept for the longest footer that any page could have.
+        let footer_len = footer_text(fields.len(), fields.len()).chars().count();
+        let base_len = title.chars().count() + footer_len;
+
+        let mut pages = Vec::new();
+        let mut page = Vec::new();
+        let mut page_len = base_len;
+
+        for field in fields {
+            let field_len = field.name.chars().count() + field.value.chars().count();
+            let full = page.len() == MAX_EMBED_FIELDS || page_len + field_len > MAX_EMBED_LENGTH;
+
+            if full && !page.is_empty() {
+                pages.push(mem::replace(&mut page, Vec::new()));
+                page_len = base_len;
+            }
+
+            page_len += field_len;
+            page.push(field);
+        }
+
+        if !page.is_empty() {
+            pages.push(page);
+        }
+
+        let pages = pages
+            .into_iter()
+            .map(|fields| {
+                fields.into_iter().fold(CreateEmbed::default().title(title), |embed, field| {
+                    embed.field(field.name, field.value, field.inline)
+                })
+            })
+            .collect();
+
+        EmbedPaginator {
+            pages: pages,
+        }
+    }
+
+    /// Whether there are no pages.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.pages.is_empty() }
+
+    /// The number of pages.
+    #[inline]
+    pub fn len(&self) -> usize { self.pages.len() }
+
+    /// Retrieves the page at the given index, starting from `0`, if it exists.
+    pub fn page(&self, index: usize) -> Option<CreateEmbed> {
+        let text = footer_text(index + 1, self.pages.len());
+
+        self.pages
+            .get(index)
+            .map(|page| page.clone().footer(|footer| footer.text(text)))
+    }
+
+    /// Returns an iterator over the pages, in order.
+    pub fn pages(&self) -> EmbedPages {
+        EmbedPages {
+            index: 0,
+            paginator: self,
+        }
+    }
+}
+
+/// An iterator over the pages of an [`EmbedPaginator`].
+///
+/// [`EmbedPaginator`]: struct.EmbedPaginator.html
+#[cfg(feature = "model")]
+#[derive(Clone, Debug)]
+pub struct EmbedPages<'a> {
+    index: usize,
+    paginator: &'a EmbedPaginator,
+}
+
+#[cfg(feature = "model")]
+impl<'a> Iterator for EmbedPages<'a> {
+    type Item = CreateEmbed;
+
+    fn next(&mut self) -> Option<CreateEmbed> {
+        let page = self.paginator.page(self.index)?;
+        self.index += 1;
+
+        Some(page)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.paginator.len().saturating_sub(self.index);
+
+        (remaining, Some(remaining))
+    }
+}
+
+#[cfg(feature = "model")]
+fn footer_text(page: usize, total: usize) -> String { format!("Page {}/{}", page, total) }
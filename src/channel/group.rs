@@ -1,12 +1,21 @@
 use chrono::{DateTime, FixedOffset};
-use ::*;
+use super::super::{ChannelId, IdMap, MessageId, User, UserId};
+
+#[cfg(feature = "model")]
+use http;
+#[cfg(feature = "model")]
+use internal::prelude::*;
+#[cfg(feature = "model")]
+use serenity_common::prelude::{JsonMap, Value};
+#[cfg(feature = "model")]
+use super::Channel;
 
 /// A group channel - potentially including other [`User`]s - separate from a
 /// [`Guild`].
 ///
 /// [`Guild`]: struct.Guild.html
 /// [`User`]: struct.User.html
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Group {
     /// The Id of the group channel.
     #[serde(rename = "id")]
@@ -22,5 +31,48 @@ pub struct Group {
     /// The Id of the group owner.
     pub owner_id: UserId,
     /// A map of the group's recipients.
-    pub recipients: HashMap<UserId, User>,
+    pub recipients: IdMap<UserId, User>,
+}
+
+#[cfg(feature = "model")]
+impl Group {
+    /// Adds a user to the group.
+    ///
+    /// Bots must give an OAuth2 access token of the user, authorized with the
+    /// `gdm.join` scope. If the user is already a recipient, this does
+    /// nothing.
+    pub fn add_recipient<U>(&self, user_id: U, access_token: Option<&str>) -> Result<()>
+        where U: Into<UserId> {
+        let user_id = user_id.into();
+
+        if self.recipients.contains_key(&user_id) {
+            return Ok(());
+        }
+
+        let mut map = JsonMap::new();
+
+        if let Some(access_token) = access_token {
+            map.insert("access_token".to_string(), Value::String(access_token.to_string()));
+        }
+
+        http::add_group_recipient(self.channel_id.0, user_id.0, &Value::Object(map))
+    }
+
+    /// Leaves the group, which is deleted once its last recipient leaves.
+    #[inline]
+    pub fn leave(&self) -> Result<Channel> { self.channel_id.delete(None) }
+
+    /// Removes a user from the group.
+    ///
+    /// **Note**: Only the owner of the group can remove other recipients. If
+    /// the user is not a recipient, this does nothing.
+    pub fn remove_recipient<U: Into<UserId>>(&self, user_id: U) -> Result<()> {
+        let user_id = user_id.into();
+
+        if !self.recipients.contains_key(&user_id) {
+            return Ok(());
+        }
+
+        http::remove_group_recipient(self.channel_id.0, user_id.0)
+    }
 }
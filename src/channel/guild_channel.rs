@@ -1,24 +1,46 @@
 use chrono::{DateTime, FixedOffset};
-use ::*;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_json::{self, Value};
+use std::result::Result as StdResult;
+use super::super::limits::{
+    self,
+    LimitError,
+    MAX_BITRATE,
+    MAX_CHANNEL_NAME_LENGTH,
+    MAX_CHANNEL_TOPIC_LENGTH,
+    MAX_SLOWMODE,
+    MAX_USER_LIMIT,
+    MIN_BITRATE,
+};
+use super::super::{ChannelId, Guild, GuildId, MessageId, UserId};
+use super::{
+    AutoArchiveDuration,
+    ChannelType,
+    PermissionOverwrite,
+    PermissionOverwriteType,
+    ThreadMember,
+    ThreadMetadata,
+};
 
 #[cfg(all(feature = "cache", feature = "model"))]
 use CACHE;
 #[cfg(feature = "model")]
-use builder::{CreateInvite, CreateMessage, EditChannel, GetMessages};
+use builder::{CreateInvite, CreateMessage};
 #[cfg(feature = "model")]
 use http::{self, AttachmentType};
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
 use internal::prelude::*;
 #[cfg(feature = "model")]
 use std::fmt::{Display, Formatter, Result as FmtResult};
 #[cfg(feature = "model")]
-use std::mem;
+use super::{Channel, EditThread};
 #[cfg(all(feature = "model", feature = "utils"))]
 use utils as serenity_utils;
 
 /// Represents a guild's text or voice channel. Some methods are available only
 /// for voice channels and some are only available for text channels.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildChannel {
     /// The unique Id of the channel.
     ///
@@ -49,8 +71,26 @@ pub struct GuildChannel {
     ///
     /// **Note**: This is only available for text channels.
     pub last_pin_timestamp: Option<DateTime<FixedOffset>>,
+    /// The current user's membership of the thread, if they have joined it.
+    ///
+    /// **Note**: This is only available for threads.
+    pub member: Option<ThreadMember>,
+    /// An approximate count of the users in the thread, which stops counting
+    /// at 50.
+    ///
+    /// **Note**: This is only available for threads.
+    pub member_count: Option<u64>,
+    /// An approximate count of the messages in the thread, which stops
+    /// counting at 50.
+    ///
+    /// **Note**: This is only available for threads.
+    pub message_count: Option<u64>,
     /// The name of the channel.
     pub name: String,
+    /// The Id of the user that started the thread.
+    ///
+    /// **Note**: This is only available for threads.
+    pub owner_id: Option<UserId>,
     /// Permission overwrites for [`Member`]s and for [`Role`]s.
     ///
     /// [`Member`]: struct.Member.html
@@ -61,6 +101,27 @@ pub struct GuildChannel {
     /// The default text channel will _almost always_ have a position of `-1` or
     /// `0`.
     pub position: i64,
+    /// The number of seconds a user must wait between sending messages, also
+    /// known as slowmode. Users with the [Manage Messages] or [Manage Channels]
+    /// permission are unaffected.
+    ///
+    /// **Note**: This is only available for text channels.
+    ///
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    pub rate_limit_per_user: Option<u64>,
+    /// The voice region of the channel.
+    ///
+    /// **Note**: This is only available for voice channels.
+    #[serde(default)]
+    pub rtc_region: AutoOrRegion,
+    /// The status of a voice channel, shown to members of the guild.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Thread-specific information, such as whether the thread is archived.
+    ///
+    /// **Note**: This is only available for threads.
+    pub thread_metadata: Option<ThreadMetadata>,
     /// The topic of the channel.
     ///
     /// **Note**: This is only available for text channels.
@@ -77,4 +138,644 @@ pub struct GuildChannel {
     // default to `false`.
     #[serde(default)]
     pub nsfw: bool,
-}
\ No newline at end of file
+}
+
+impl GuildChannel {
+    /// Retrieves the category that the channel is in from the given guild.
+    ///
+    /// Categories are stored alongside the guild's other channels, and so the
+    /// category is returned as a [`GuildChannel`] of kind
+    /// [`ChannelType::Category`].
+    ///
+    /// [`ChannelType::Category`]: enum.ChannelType.html#variant.Category
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    pub fn category<'a>(&self, guild: &'a Guild) -> Option<&'a GuildChannel> {
+        self.category_id
+            .and_then(|id| guild.channels.get(&id))
+            .and_then(|channel| if channel.kind == ChannelType::Category {
+                Some(channel)
+            } else {
+                None
+            })
+    }
+
+    /// Whether the channel is a thread.
+    #[inline]
+    pub fn is_thread(&self) -> bool { self.kind.is_thread() }
+
+    /// Retrieves the channel's permission overwrite for a member or role, if
+    /// it has one.
+    pub fn overwrite_for(&self, kind: PermissionOverwriteType) -> Option<&PermissionOverwrite> {
+        self.permission_overwrites.iter().find(|overwrite| overwrite.kind == kind)
+    }
+}
+
+/// The voice region of a channel, which is either picked automatically by
+/// Discord or set to a specific region.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum AutoOrRegion {
+    /// Discord picks the region with the best connection for whoever joins
+    /// first.
+    Automatic,
+    /// The Id of the region, such as `"us-east"`.
+    Region(String),
+}
+
+impl AutoOrRegion {
+    /// Whether the region is picked automatically.
+    #[inline]
+    pub fn is_automatic(&self) -> bool { *self == AutoOrRegion::Automatic }
+
+    /// Retrieves the Id of the region, if it is not picked automatically.
+    pub fn region(&self) -> Option<&str> {
+        match *self {
+            AutoOrRegion::Automatic => None,
+            AutoOrRegion::Region(ref region) => Some(region),
+        }
+    }
+}
+
+impl Default for AutoOrRegion {
+    fn default() -> Self { AutoOrRegion::Automatic }
+}
+
+impl From<Option<String>> for AutoOrRegion {
+    fn from(region: Option<String>) -> Self {
+        region.map_or(AutoOrRegion::Automatic, AutoOrRegion::Region)
+    }
+}
+
+impl From<AutoOrRegion> for Option<String> {
+    fn from(region: AutoOrRegion) -> Self {
+        match region {
+            AutoOrRegion::Automatic => None,
+            AutoOrRegion::Region(region) => Some(region),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AutoOrRegion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Option::<String>::deserialize(deserializer).map(AutoOrRegion::from)
+    }
+}
+
+impl Serialize for AutoOrRegion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        self.region().serialize(serializer)
+    }
+}
+
+/// A new position for a channel, as used when reordering the guild's
+/// channels.
+///
+/// Refer to [`Guild::reorder_channels`] for more information.
+///
+/// [`Guild::reorder_channels`]: struct.Guild.html#method.reorder_channels
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ChannelPosition {
+    /// The Id of the channel to move.
+    pub id: ChannelId,
+    /// The new position of the channel.
+    pub position: i64,
+    /// Whether to sync the channel's permission overwrites with its new
+    /// category, if it is being moved to one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lock_permissions: Option<bool>,
+    /// The new category of the channel.
+    ///
+    /// `Some(None)` moves the channel out of its category, while `None`
+    /// leaves its category unchanged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Option<ChannelId>>,
+}
+
+impl ChannelPosition {
+    /// Creates a position for a channel, without changing its category.
+    pub fn new<C: Into<ChannelId>>(channel_id: C, position: i64) -> Self {
+        ChannelPosition {
+            id: channel_id.into(),
+            position: position,
+            lock_permissions: None,
+            parent_id: None,
+        }
+    }
+
+    /// Sets whether to sync the channel's permission overwrites with its new
+    /// category.
+    pub fn lock_permissions(mut self, lock_permissions: bool) -> Self {
+        self.lock_permissions = Some(lock_permissions);
+
+        self
+    }
+
+    /// Moves the channel into a category, or out of its category if `None`
+    /// is given.
+    pub fn parent_id<C: Into<ChannelId>>(mut self, parent_id: Option<C>) -> Self {
+        self.parent_id = Some(parent_id.map(Into::into));
+
+        self
+    }
+}
+
+impl<C: Into<ChannelId>> From<(C, i64)> for ChannelPosition {
+    fn from((channel_id, position): (C, i64)) -> ChannelPosition {
+        ChannelPosition::new(channel_id, position)
+    }
+}
+
+#[cfg(feature = "model")]
+impl GuildChannel {
+    /// Archives the thread, updating the local channel with the result.
+    ///
+    /// **Note**: Requires the [Manage Threads] permission, unless the current
+    /// user started the thread.
+    ///
+    /// [Manage Threads]: permissions/constant.MANAGE_THREADS.html
+    #[inline]
+    pub fn archive(&mut self) -> Result<()> { self.edit_thread(|t| t.archived(true)) }
+
+    /// Deletes the channel.
+    ///
+    /// Refer to [`ChannelId::delete`] for more information.
+    ///
+    /// [`ChannelId::delete`]: struct.ChannelId.html#method.delete
+    #[inline]
+    pub fn delete(&self, audit_log_reason: Option<&str>) -> Result<Channel> {
+        self.id.delete(audit_log_reason)
+    }
+
+    /// Edits the thread, updating the local channel with the result.
+    ///
+    /// Refer to [`ChannelId::edit_thread`] for more information.
+    ///
+    /// [`ChannelId::edit_thread`]: struct.ChannelId.html#method.edit_thread
+    pub fn edit_thread<F>(&mut self, f: F) -> Result<()>
+        where F: FnOnce(EditThread) -> EditThread {
+        match self.id.edit_thread(f) {
+            Ok(edited) => {
+                *self = edited;
+
+                Ok(())
+            },
+            Err(why) => Err(why),
+        }
+    }
+}
+
+/// The payload used to create a channel in a guild.
+///
+/// By default this creates a text channel. A [`name`] must always be set.
+///
+/// [`name`]: #method.name
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateChannel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bitrate: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_auto_archive_duration: Option<AutoArchiveDuration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_thread_rate_limit_per_user: Option<u64>,
+    #[serde(rename = "type")]
+    kind: ChannelType,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nsfw: Option<bool>,
+    #[serde(rename = "parent_id", skip_serializing_if = "Option::is_none")]
+    parent: Option<ChannelId>,
+    #[serde(rename = "permission_overwrites", skip_serializing_if = "Vec::is_empty")]
+    overwrites: Vec<PermissionOverwrite>,
+    #[serde(skip)]
+    pub(crate) reason: Option<String>,
+    #[serde(rename = "rate_limit_per_user", skip_serializing_if = "Option::is_none")]
+    slowmode: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_limit: Option<u64>,
+}
+
+impl CreateChannel {
+    /// Sets the bitrate of the channel, in bits per second.
+    ///
+    /// **Note**: This is only applicable to voice channels.
+    pub fn bitrate(mut self, bitrate: u64) -> Self {
+        self.bitrate = Some(bitrate);
+
+        self
+    }
+
+    /// Sets the duration of inactivity after which threads created in the
+    /// channel are automatically archived, unless set when creating a thread.
+    ///
+    /// **Note**: This is only applicable to text and news channels.
+    pub fn default_auto_archive_duration(mut self, duration: AutoArchiveDuration) -> Self {
+        self.default_auto_archive_duration = Some(duration);
+
+        self
+    }
+
+    /// Sets the number of seconds a user must wait between sending messages
+    /// in threads created in the channel.
+    ///
+    /// **Note**: This is only applicable to text and news channels.
+    pub fn default_thread_rate_limit_per_user(mut self, seconds: u64) -> Self {
+        self.default_thread_rate_limit_per_user = Some(seconds);
+
+        self
+    }
+
+    /// Sets the type of the channel.
+    pub fn kind(mut self, kind: ChannelType) -> Self {
+        self.kind = kind;
+
+        self
+    }
+
+    /// Sets the name of the channel.
+    pub fn name<D: ToString>(mut self, name: D) -> Self {
+        self.name = name.to_string();
+
+        self
+    }
+
+    /// Sets whether the channel is marked as not safe for work.
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = Some(nsfw);
+
+        self
+    }
+
+    /// Sets the permission overwrites of the channel.
+    pub fn overwrites(mut self, overwrites: Vec<PermissionOverwrite>) -> Self {
+        self.overwrites = overwrites;
+
+        self
+    }
+
+    /// Sets the category that the channel is placed in.
+    pub fn parent<C: Into<ChannelId>>(mut self, parent: C) -> Self {
+        self.parent = Some(parent.into());
+
+        self
+    }
+
+    /// Sets the reason for creating the channel, which is shown in the guild's
+    /// audit log.
+    pub fn reason<S: ToString>(mut self, reason: S) -> Self {
+        self.reason = Some(reason.to_string());
+
+        self
+    }
+
+    /// Sets the number of seconds a user must wait between sending messages.
+    ///
+    /// **Note**: This is only applicable to text channels.
+    pub fn slowmode(mut self, seconds: u64) -> Self {
+        self.slowmode = Some(seconds);
+
+        self
+    }
+
+    /// Sets the topic of the channel.
+    ///
+    /// **Note**: This is only applicable to text channels.
+    pub fn topic<D: ToString>(mut self, topic: D) -> Self {
+        self.topic = Some(topic.to_string());
+
+        self
+    }
+
+    /// Sets the maximum number of members allowed in the channel.
+    ///
+    /// **Note**: This is only applicable to voice channels.
+    pub fn user_limit(mut self, user_limit: u64) -> Self {
+        self.user_limit = Some(user_limit);
+
+        self
+    }
+
+    /// Validates the channel against Discord's [limits] and converts it into
+    /// a value suitable for creating it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LimitError`] if the name, topic, bitrate, slowmode of the
+    /// channel or its threads, or user limit is outside of Discord's limits.
+    ///
+    /// [`LimitError`]: limits/enum.LimitError.html
+    /// [limits]: limits/index.html
+    pub fn to_value(&self) -> StdResult<Value, LimitError> {
+        check_channel_limits(
+            Some(&self.name),
+            self.topic.as_ref().map(String::as_str),
+            self.bitrate,
+            &[self.slowmode, self.default_thread_rate_limit_per_user],
+            self.user_limit,
+        )?;
+
+        serde_json::to_value(self).map_err(|why| LimitError::Serialization(why.to_string()))
+    }
+}
+
+impl Default for CreateChannel {
+    fn default() -> Self {
+        CreateChannel {
+            bitrate: None,
+            default_auto_archive_duration: None,
+            default_thread_rate_limit_per_user: None,
+            kind: ChannelType::Text,
+            name: String::new(),
+            nsfw: None,
+            parent: None,
+            overwrites: Vec::new(),
+            reason: None,
+            slowmode: None,
+            topic: None,
+            user_limit: None,
+        }
+    }
+}
+
+/// The payload used to edit a channel in a guild.
+///
+/// Fields that are not set are left unchanged.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EditChannel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bitrate: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_auto_archive_duration: Option<AutoArchiveDuration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_thread_rate_limit_per_user: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nsfw: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_id: Option<Option<ChannelId>>,
+    #[serde(rename = "permission_overwrites", skip_serializing_if = "Option::is_none")]
+    overwrites: Option<Vec<PermissionOverwrite>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<i64>,
+    #[serde(skip)]
+    pub(crate) reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rtc_region: Option<AutoOrRegion>,
+    #[serde(rename = "rate_limit_per_user", skip_serializing_if = "Option::is_none")]
+    slowmode: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_limit: Option<u64>,
+}
+
+impl EditChannel {
+    /// Sets the bitrate of the channel, in bits per second.
+    ///
+    /// The bitrate must be between [`MIN_BITRATE`] and [`MAX_BITRATE`].
+    ///
+    /// **Note**: This is only applicable to voice channels.
+    ///
+    /// [`MAX_BITRATE`]: limits/constant.MAX_BITRATE.html
+    /// [`MIN_BITRATE`]: limits/constant.MIN_BITRATE.html
+    pub fn bitrate(mut self, bitrate: u64) -> Self {
+        self.bitrate = Some(bitrate);
+
+        self
+    }
+
+    /// Sets the duration of inactivity after which threads created in the
+    /// channel are automatically archived, unless set when creating a thread.
+    ///
+    /// **Note**: This is only applicable to text and news channels.
+    pub fn default_auto_archive_duration(mut self, duration: AutoArchiveDuration) -> Self {
+        self.default_auto_archive_duration = Some(duration);
+
+        self
+    }
+
+    /// Sets the number of seconds a user must wait between sending messages
+    /// in threads created in the channel, up to [`MAX_SLOWMODE`].
+    ///
+    /// **Note**: This is only applicable to text and news channels.
+    ///
+    /// [`MAX_SLOWMODE`]: limits/constant.MAX_SLOWMODE.html
+    pub fn default_thread_rate_limit_per_user(mut self, seconds: u64) -> Self {
+        self.default_thread_rate_limit_per_user = Some(seconds);
+
+        self
+    }
+
+    /// Sets the name of the channel.
+    ///
+    /// The name must be between 1 and [`MAX_CHANNEL_NAME_LENGTH`] characters
+    /// long.
+    ///
+    /// [`MAX_CHANNEL_NAME_LENGTH`]: limits/constant.MAX_CHANNEL_NAME_LENGTH.html
+    pub fn name<D: ToString>(mut self, name: D) -> Self {
+        self.name = Some(name.to_string());
+
+        self
+    }
+
+    /// Sets whether the channel is marked as not safe for work.
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = Some(nsfw);
+
+        self
+    }
+
+    /// Sets the permission overwrites of the channel, replacing the existing
+    /// ones.
+    pub fn overwrites(mut self, overwrites: Vec<PermissionOverwrite>) -> Self {
+        self.overwrites = Some(overwrites);
+
+        self
+    }
+
+    /// Sets the category that the channel is placed in.
+    ///
+    /// Passing `None` moves the channel out of its category.
+    pub fn parent_id<C: Into<ChannelId>>(mut self, parent_id: Option<C>) -> Self {
+        self.parent_id = Some(parent_id.map(Into::into));
+
+        self
+    }
+
+    /// Sets the position of the channel in the channel list.
+    pub fn position(mut self, position: i64) -> Self {
+        self.position = Some(position);
+
+        self
+    }
+
+    /// Sets the reason for the change, which is shown in the guild's audit log.
+    pub fn reason<S: ToString>(mut self, reason: S) -> Self {
+        self.reason = Some(reason.to_string());
+
+        self
+    }
+
+    /// Sets the voice region of the channel.
+    ///
+    /// **Note**: This is only applicable to voice channels.
+    pub fn rtc_region(mut self, region: AutoOrRegion) -> Self {
+        self.rtc_region = Some(region);
+
+        self
+    }
+
+    /// Sets the number of seconds a user must wait between sending messages,
+    /// up to [`MAX_SLOWMODE`].
+    ///
+    /// **Note**: This is only applicable to text channels.
+    ///
+    /// [`MAX_SLOWMODE`]: limits/constant.MAX_SLOWMODE.html
+    pub fn slowmode(mut self, seconds: u64) -> Self {
+        self.slowmode = Some(seconds);
+
+        self
+    }
+
+    /// Sets the topic of the channel, which can be at most
+    /// [`MAX_CHANNEL_TOPIC_LENGTH`] characters long.
+    ///
+    /// **Note**: This is only applicable to text channels.
+    ///
+    /// [`MAX_CHANNEL_TOPIC_LENGTH`]: limits/constant.MAX_CHANNEL_TOPIC_LENGTH.html
+    pub fn topic<D: ToString>(mut self, topic: D) -> Self {
+        self.topic = Some(topic.to_string());
+
+        self
+    }
+
+    /// Sets the maximum number of members allowed in the channel, up to
+    /// [`MAX_USER_LIMIT`]. A limit of 0 allows any number of members.
+    ///
+    /// **Note**: This is only applicable to voice channels.
+    ///
+    /// [`MAX_USER_LIMIT`]: limits/constant.MAX_USER_LIMIT.html
+    pub fn user_limit(mut self, user_limit: u64) -> Self {
+        self.user_limit = Some(user_limit);
+
+        self
+    }
+
+    /// Validates the channel against Discord's [limits] and converts it into
+    /// a value suitable for editing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LimitError`] if the name, topic, bitrate, slowmode of the
+    /// channel or its threads, or user limit is outside of Discord's limits.
+    ///
+    /// [`LimitError`]: limits/enum.LimitError.html
+    /// [limits]: limits/index.html
+    pub fn to_value(&self) -> StdResult<Value, LimitError> {
+        check_channel_limits(
+            self.name.as_ref().map(String::as_str),
+            self.topic.as_ref().map(String::as_str),
+            self.bitrate,
+            &[self.slowmode, self.default_thread_rate_limit_per_user],
+            self.user_limit,
+        )?;
+
+        serde_json::to_value(self).map_err(|why| LimitError::Serialization(why.to_string()))
+    }
+}
+
+/// Checks the values shared by [`CreateChannel`] and [`EditChannel`] against
+/// Discord's limits.
+///
+/// [`CreateChannel`]: struct.CreateChannel.html
+/// [`EditChannel`]: struct.EditChannel.html
+fn check_channel_limits(
+    name: Option<&str>,
+    topic: Option<&str>,
+    bitrate: Option<u64>,
+    slowmodes: &[Option<u64>],
+    user_limit: Option<u64>,
+) -> StdResult<(), LimitError> {
+    if let Some(name) = name {
+        limits::check_length(name, 1, MAX_CHANNEL_NAME_LENGTH, LimitError::ChannelNameLength)?;
+    }
+
+    if let Some(topic) = topic {
+        limits::check_length(topic, 0, MAX_CHANNEL_TOPIC_LENGTH, LimitError::ChannelTopicLength)?;
+    }
+
+    if let Some(bitrate) = bitrate {
+        if bitrate < MIN_BITRATE || bitrate > MAX_BITRATE {
+            return Err(LimitError::Bitrate(bitrate));
+        }
+    }
+
+    for slowmode in slowmodes.iter().filter_map(|slowmode| *slowmode) {
+        if slowmode > MAX_SLOWMODE {
+            return Err(LimitError::Slowmode(slowmode));
+        }
+    }
+
+    if let Some(user_limit) = user_limit {
+        if user_limit > MAX_USER_LIMIT {
+            return Err(LimitError::UserLimit(user_limit));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::limits::{
+        LimitError,
+        MAX_CHANNEL_NAME_LENGTH,
+        MAX_CHANNEL_TOPIC_LENGTH,
+    };
+    use super::{CreateChannel, EditChannel};
+
+    #[test]
+    fn create_channel_checks_limits() {
+        let channel = CreateChannel::default().name("general");
+        assert_eq!(channel.to_value().unwrap()["name"], "general");
+
+        let unnamed = CreateChannel::default();
+        assert_eq!(unnamed.to_value().unwrap_err(), LimitError::ChannelNameLength(0));
+
+        let topic = "a".repeat(MAX_CHANNEL_TOPIC_LENGTH + 1);
+        let channel = CreateChannel::default().name("general").topic(&topic);
+        assert_eq!(
+            channel.to_value().unwrap_err(),
+            LimitError::ChannelTopicLength(MAX_CHANNEL_TOPIC_LENGTH + 1),
+        );
+
+        let channel = CreateChannel::default().name("general").user_limit(100);
+        assert_eq!(channel.to_value().unwrap_err(), LimitError::UserLimit(100));
+    }
+
+    #[test]
+    fn edit_channel_name_length() {
+        let name = |length| EditChannel::default().name("é".repeat(length)).to_value();
+
+        assert_eq!(name(0).unwrap_err(), LimitError::ChannelNameLength(0));
+        assert!(name(1).is_ok());
+        assert!(name(MAX_CHANNEL_NAME_LENGTH).is_ok());
+        assert_eq!(
+            name(MAX_CHANNEL_NAME_LENGTH + 1).unwrap_err(),
+            LimitError::ChannelNameLength(MAX_CHANNEL_NAME_LENGTH + 1),
+        );
+    }
+
+    #[test]
+    fn edit_channel_topic_length() {
+        let topic = |length| EditChannel::default().topic("é".repeat(length)).to_value();
+
+        assert!(topic(0).is_ok());
+        assert!(topic(MAX_CHANNEL_TOPIC_LENGTH).is_ok());
+        assert_eq!(
+            topic(MAX_CHANNEL_TOPIC_LENGTH + 1).unwrap_err(),
+            LimitError::ChannelTopicLength(MAX_CHANNEL_TOPIC_LENGTH + 1),
+        );
+    }
+}
@@ -1,10 +1,25 @@
 use chrono::{DateTime, FixedOffset};
+use serde::ser::{Serialize, Serializer};
 use serde_json::Value;
-use serenity_common::constants;
-use ::*;
+use serde::de::{Deserialize, Deserializer, Error as DeError, Visitor};
+use std::fmt::{Formatter, Result as FmtResult, Write as FmtWrite};
+use std::result::Result as StdResult;
+use super::super::misc::Mentionable;
+use super::super::utils::{deserialize_u64, U64Visitor};
+use super::super::{ChannelId, GuildId, MessageId, ModelError, RoleId, User, UserId, WebhookId};
+use super::{Attachment, Embed, ReactionType, StickerItem};
+
+pub use serenity_common::constants::JOIN_MESSAGES;
+
+/// The maximum number of messages that can be retrieved in one request.
+pub const MAX_MESSAGES_PER_REQUEST: u64 = 100;
 
 #[cfg(feature = "model")]
 use builder::{CreateEmbed, CreateMessage};
+#[cfg(feature = "model")]
+use internal::prelude::*;
+#[cfg(feature = "model")]
+use serde_json;
 #[cfg(all(feature = "cache", feature = "model"))]
 use CACHE;
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -12,7 +27,7 @@ use std::fmt::Write;
 #[cfg(feature = "model")]
 use std::mem;
 #[cfg(feature = "model")]
-use {constants, http, utils as serenity_utils};
+use {http, utils as serenity_utils};
 
 /// A representation of a message over a guild's text channel, a group, or a
 /// private channel.
@@ -22,6 +37,7 @@ pub struct Message {
     /// of the message.
     pub id: MessageId,
     /// An vector of the files attached to a message.
+    #[serde(default)]
     pub attachments: Vec<Attachment>,
     /// The user that sent the message.
     pub author: User,
@@ -34,7 +50,19 @@ pub struct Message {
     /// The timestamp of the last time the message was updated, if it was.
     pub edited_timestamp: Option<DateTime<FixedOffset>>,
     /// Array of embeds sent with the message.
+    #[serde(default)]
     pub embeds: Vec<Embed>,
+    /// The flags of the message, such as whether it has been crossposted or
+    /// has had its embeds suppressed.
+    pub flags: Option<MessageFlags>,
+    /// The Id of the [`Guild`] that the message was sent in, if it was sent
+    /// in a guild.
+    ///
+    /// **Note**: This is only present on messages received over the gateway.
+    /// Messages retrieved via REST will not contain it.
+    ///
+    /// [`Guild`]: struct.Guild.html
+    pub guild_id: Option<GuildId>,
     /// Indicator of the type of message this is, i.e. whether it is a regular
     /// message or a system message.
     #[serde(rename = "type")]
@@ -44,17 +72,30 @@ pub struct Message {
     /// Array of [`Role`]s' Ids mentioned in the message.
     ///
     /// [`Role`]: struct.Role.html
+    #[serde(default)]
     pub mention_roles: Vec<RoleId>,
     /// Array of users mentioned in the message.
+    #[serde(default)]
     pub mentions: Vec<User>,
-    /// Non-repeating number used for ensuring message order.
+    /// Reference data of the message that this message replies to or was
+    /// crossposted from, if any.
+    pub message_reference: Option<MessageReference>,
+    /// Non-repeating value used for ensuring message order.
     #[serde(default)]
-    pub nonce: Value,
+    pub nonce: Nonce,
     /// Indicator of whether the message is pinned.
     pub pinned: bool,
     /// Array of reactions performed on the message.
     #[serde(default)]
     pub reactions: Vec<MessageReaction>,
+    /// The details of the role subscription that was purchased, if the
+    /// message is of the [`RoleSubscriptionPurchase`] type.
+    ///
+    /// [`RoleSubscriptionPurchase`]: enum.MessageType.html#variant.RoleSubscriptionPurchase
+    pub role_subscription_data: Option<RoleSubscriptionData>,
+    /// The stickers sent with the message.
+    #[serde(default)]
+    pub sticker_items: Vec<StickerItem>,
     /// Initial message creation timestamp, calculated from its Id.
     pub timestamp: DateTime<FixedOffset>,
     /// Indicator of whether the command is to be played back via
@@ -67,37 +108,183 @@ pub struct Message {
 }
 
 impl Message {
-    pub fn transform_content(&mut self) {
+    /// Whether the message was sent outside of a [`Guild`], such as in a
+    /// [`PrivateChannel`] or [`Group`].
+    ///
+    /// **Note**: This relies on [`guild_id`], which is only present on
+    /// messages received over the gateway.
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`Guild`]: struct.Guild.html
+    /// [`PrivateChannel`]: struct.PrivateChannel.html
+    /// [`guild_id`]: #structfield.guild_id
+    pub fn is_private(&self) -> bool { self.guild_id.is_none() }
+
+    /// Generates a link to the message, which clients can click on to jump to
+    /// it.
+    ///
+    /// **Note**: This relies on [`guild_id`] to tell whether the message was
+    /// sent in a guild, which is only present on messages received over the
+    /// gateway.
+    ///
+    /// [`guild_id`]: #structfield.guild_id
+    #[inline]
+    pub fn link(&self) -> String { self.channel_id.message_link(self.id, self.guild_id) }
+
+    /// Checks whether the message mentions the current user, either directly
+    /// or through an `@everyone`/`@here` mention.
+    ///
+    /// The Id of the current user must be given, as the model has no access
+    /// to it.
+    pub fn mentions_me<U: Into<UserId>>(&self, current_user_id: U) -> bool {
+        self.mention_everyone || self.mentions_user_id(current_user_id)
+    }
+
+    /// Checks whether the message mentions the passed [`RoleId`].
+    ///
+    /// [`RoleId`]: struct.RoleId.html
+    pub fn mentions_role<R: Into<RoleId>>(&self, role_id: R) -> bool {
+        let role_id = role_id.into();
+
+        self.mention_roles.contains(&role_id)
+    }
+
+    /// Checks whether the message directly mentions the passed [`UserId`].
+    ///
+    /// [`UserId`]: struct.UserId.html
+    pub fn mentions_user_id<U: Into<UserId>>(&self, user_id: U) -> bool {
+        let user_id = user_id.into();
+
+        self.mentions.iter().any(|mentioned_user| mentioned_user.id == user_id)
+    }
+
+    /// Retrieves the URLs to the images of the stickers sent with the message.
+    ///
+    /// Refer to [`StickerItem::image_url`] for more information.
+    ///
+    /// [`StickerItem::image_url`]: struct.StickerItem.html#method.image_url
+    pub fn sticker_urls(&self) -> Vec<String> {
+        self.sticker_items.iter().map(StickerItem::image_url).collect()
+    }
+
+    /// Computes the text that the official client displays for a system
+    /// message, without modifying the message.
+    ///
+    /// System messages, such as pins and member joins, are sent with empty or
+    /// placeholder content. Returns `None` for messages whose content is
+    /// displayed as-is.
+    pub fn synthesized_content(&self) -> Option<String> {
         match self.kind {
-            MessageType::PinsAdd => {
-                self.content = format!(
-                    "{} pinned a message to this channel. See all the pins.",
-                    self.author
-                );
-            },
-            MessageType::MemberJoin => {
-                let sec = self.timestamp.timestamp() as usize;
-                let chosen = constants::JOIN_MESSAGES[sec % constants::JOIN_MESSAGES.len()];
-
-                self.content = if chosen.contains("$user") {
-                    chosen.replace("$user", &self.author.mention())
-                } else {
-                    chosen.to_string()
-                };
-            },
-            _ => {},
+            MessageType::PinsAdd => Some(format!(
+                "{} pinned a message to this channel. See all the pins.",
+                self.author
+            )),
+            MessageType::MemberJoin => Some(welcome_message(&self.author, &self.timestamp)),
+            _ => None,
+        }
+    }
+
+    /// Replaces the content of a system message with its
+    /// [`synthesized_content`].
+    ///
+    /// [`synthesized_content`]: #method.synthesized_content
+    #[deprecated(since = "0.1.0", note = "use `synthesized_content`, which keeps the original content")]
+    pub fn transform_content(&mut self) {
+        if let Some(content) = self.synthesized_content() {
+            self.content = content;
         }
     }
 }
 
-impl From<Message> for MessageId {
-    /// Gets the Id of a `Message`.
-    fn from(message: Message) -> MessageId { message.id }
+/// Renders the welcome message that the official client displays when a user
+/// joins a guild.
+///
+/// The message is picked from [`JOIN_MESSAGES`] by the timestamp of the
+/// join, so the same join always renders the same message.
+///
+/// [`JOIN_MESSAGES`]: constant.JOIN_MESSAGES.html
+pub fn welcome_message(user: &User, timestamp: &DateTime<FixedOffset>) -> String {
+    let sec = timestamp.timestamp() as usize;
+    let chosen = JOIN_MESSAGES[sec % JOIN_MESSAGES.len()];
+
+    if chosen.contains("$user") {
+        chosen.replace("$user", &user.mention())
+    } else {
+        chosen.to_string()
+    }
 }
 
-impl<'a> From<&'a Message> for MessageId {
-    /// Gets the Id of a `Message`.
-    fn from(message: &Message) -> MessageId { message.id }
+#[cfg(feature = "model")]
+impl Message {
+    /// Replies to the message, referencing it without notifying its author.
+    ///
+    /// Other mentions within the content are parsed as usual.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    pub fn reply(&self, content: &str) -> Result<Message> { self._reply(content, false) }
+
+    /// Replies to the message, referencing it and notifying its author.
+    ///
+    /// Refer to [`reply`] for more information.
+    ///
+    /// [`reply`]: #method.reply
+    pub fn reply_ping(&self, content: &str) -> Result<Message> { self._reply(content, true) }
+
+    /// Crossposts the message from an announcement channel to all of the
+    /// channels following it.
+    ///
+    /// **Note**: Requires the [Send Messages] permission if the current user
+    /// sent the message, and the [Manage Messages] permission otherwise.
+    ///
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    pub fn crosspost(&self) -> Result<Message> {
+        http::crosspost_message(self.channel_id.0, self.id.0)
+    }
+
+    /// Suppresses or unsuppresses the embeds of the message, updating the
+    /// message with the result.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission if the message was
+    /// not sent by the current user.
+    ///
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    pub fn suppress_embeds(&mut self, suppress: bool) -> Result<()> {
+        let mut flags = self.flags.unwrap_or_else(MessageFlags::empty);
+        flags.set(MessageFlags::SUPPRESS_EMBEDS, suppress);
+
+        let mut map = JsonMap::new();
+        map.insert("flags".to_string(), Value::Number(Number::from(flags.bits())));
+
+        match http::edit_message(self.channel_id.0, self.id.0, &Value::Object(map)) {
+            Ok(edited) => {
+                *self = edited;
+
+                Ok(())
+            },
+            Err(why) => Err(why),
+        }
+    }
+
+    fn _reply(&self, content: &str, ping: bool) -> Result<Message> {
+        let allowed_mentions = AllowedMentions {
+            parse: vec![ParseValue::Everyone, ParseValue::Roles, ParseValue::Users],
+            replied_user: ping,
+            ..Default::default()
+        };
+
+        let mut map = JsonMap::new();
+        map.insert("content".to_string(), Value::String(content.to_string()));
+        map.insert("allowed_mentions".to_string(), serde_json::to_value(allowed_mentions)?);
+        map.insert(
+            "message_reference".to_string(),
+            serde_json::to_value(MessageReference::from(self))?,
+        );
+
+        http::send_message(self.channel_id.0, &Value::Object(map))
+    }
 }
 
 /// A representation of a reaction to a message.
@@ -109,16 +296,74 @@ impl<'a> From<&'a Message> for MessageId {
 /// [reaction type]: enum.ReactionType.html
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessageReaction {
+    /// The colours used for super reactions of the type, as hex strings.
+    #[serde(default)]
+    pub burst_colours: Vec<String>,
     /// The amount of the type of reaction that have been sent for the
-    /// associated message.
+    /// associated message, including super reactions.
     pub count: u64,
+    /// The breakdown of the [`count`] into normal and super reactions.
+    ///
+    /// [`count`]: #structfield.count
+    #[serde(default)]
+    pub count_details: ReactionCountDetails,
     /// Indicator of whether the current user has sent the type of reaction.
     pub me: bool,
+    /// Indicator of whether the current user has sent the type of reaction as
+    /// a super reaction.
+    #[serde(default)]
+    pub me_burst: bool,
     /// The type of reaction.
     #[serde(rename = "emoji")]
     pub reaction_type: ReactionType,
 }
 
+/// The number of normal and super reactions within a [`MessageReaction`].
+///
+/// [`MessageReaction`]: struct.MessageReaction.html
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct ReactionCountDetails {
+    /// The number of super reactions.
+    pub burst: u64,
+    /// The number of normal reactions.
+    pub normal: u64,
+}
+
+bitflags! {
+    /// Describes extra features of a [`Message`].
+    ///
+    /// [`Message`]: struct.Message.html
+    pub struct MessageFlags: u64 {
+        /// The message has been published to the channels following its
+        /// announcement channel.
+        const CROSSPOSTED = 1;
+        /// The message originated from a message in an announcement channel
+        /// of another guild.
+        const IS_CROSSPOST = 1 << 1;
+        /// The embeds of the message are not shown.
+        const SUPPRESS_EMBEDS = 1 << 2;
+        /// The message that this crosspost originated from has been deleted.
+        const SOURCE_MESSAGE_DELETED = 1 << 3;
+        /// The message came from the urgent message system.
+        const URGENT = 1 << 4;
+        /// The message is only visible to the user that used the interaction
+        /// it responds to.
+        const EPHEMERAL = 1 << 6;
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(MessageFlags::from_bits_truncate(deserializer.deserialize_u64(U64Visitor)?))
+    }
+}
+
+impl Serialize for MessageFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
 enum_number!(
     /// Differentiates between regular and different types of system messages.
     MessageType {
@@ -138,5 +383,295 @@ enum_number!(
         PinsAdd = 6,
         /// An indicator that a member joined the guild.
         MemberJoin = 7,
+        /// An indicator that a member purchased or renewed a role subscription.
+        RoleSubscriptionPurchase = 25,
     }
 );
+
+/// The details of a role subscription purchase, sent with messages of the
+/// [`RoleSubscriptionPurchase`] type.
+///
+/// [`RoleSubscriptionPurchase`]: enum.MessageType.html#variant.RoleSubscriptionPurchase
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RoleSubscriptionData {
+    /// Whether the purchase is a renewal of an existing subscription.
+    pub is_renewal: bool,
+    /// The Id of the role subscription listing that was purchased.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub role_subscription_listing_id: u64,
+    /// The name of the tier that was purchased.
+    pub tier_name: String,
+    /// The total number of months that the user has been subscribed for.
+    pub total_months_subscribed: u64,
+}
+
+/// A value sent alongside a message to verify that it was sent, which clients
+/// may send as either a number or a string.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Nonce {
+    /// No nonce was sent.
+    None,
+    /// A numeric nonce.
+    Number(u64),
+    /// A string nonce.
+    String(String),
+}
+
+impl Default for Nonce {
+    fn default() -> Self { Nonce::None }
+}
+
+impl From<Value> for Nonce {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Number(number) => match number.as_u64() {
+                Some(number) => Nonce::Number(number),
+                None => Nonce::String(number.to_string()),
+            },
+            Value::String(string) => Nonce::String(string),
+            _ => Nonce::None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Nonce {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        deserializer.deserialize_any(NonceVisitor)
+    }
+}
+
+impl Serialize for Nonce {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        match *self {
+            Nonce::None => serializer.serialize_none(),
+            Nonce::Number(number) => serializer.serialize_u64(number),
+            Nonce::String(ref string) => serializer.serialize_str(string),
+        }
+    }
+}
+
+struct NonceVisitor;
+
+impl<'de> Visitor<'de> for NonceVisitor {
+    type Value = Nonce;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a nonce as a number, string, or null")
+    }
+
+    fn visit_i64<E: DeError>(self, v: i64) -> StdResult<Self::Value, E> {
+        if v < 0 {
+            Ok(Nonce::String(v.to_string()))
+        } else {
+            Ok(Nonce::Number(v as u64))
+        }
+    }
+
+    fn visit_u64<E: DeError>(self, v: u64) -> StdResult<Self::Value, E> { Ok(Nonce::Number(v)) }
+
+    fn visit_str<E: DeError>(self, v: &str) -> StdResult<Self::Value, E> {
+        Ok(Nonce::String(v.to_string()))
+    }
+
+    fn visit_string<E: DeError>(self, v: String) -> StdResult<Self::Value, E> {
+        Ok(Nonce::String(v))
+    }
+
+    fn visit_none<E: DeError>(self) -> StdResult<Self::Value, E> { Ok(Nonce::None) }
+
+    fn visit_unit<E: DeError>(self) -> StdResult<Self::Value, E> { Ok(Nonce::None) }
+}
+
+/// Reference data sent with replies and crossposted messages, pointing to the
+/// originating message.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageReference {
+    /// The Id of the originating message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageId>,
+    /// The Id of the [`Channel`] of the originating message.
+    ///
+    /// [`Channel`]: enum.Channel.html
+    pub channel_id: ChannelId,
+    /// The Id of the [`Guild`] of the originating message, if any.
+    ///
+    /// [`Guild`]: struct.Guild.html
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+}
+
+impl<'a> From<&'a Message> for MessageReference {
+    /// Creates a reference pointing to a `Message`.
+    fn from(message: &Message) -> MessageReference {
+        MessageReference {
+            message_id: Some(message.id),
+            channel_id: message.channel_id,
+            guild_id: message.guild_id,
+        }
+    }
+}
+
+/// The mentions within a message's content that are allowed to notify their
+/// targets when the message is sent.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AllowedMentions {
+    /// The types of mentions that are parsed from the content.
+    #[serde(default)]
+    pub parse: Vec<ParseValue>,
+    /// Indicator of whether the author of the replied-to message is
+    /// notified.
+    #[serde(default)]
+    pub replied_user: bool,
+    /// The Ids of [`Role`]s that may be notified.
+    ///
+    /// [`Role`]: struct.Role.html
+    #[serde(default)]
+    pub roles: Vec<RoleId>,
+    /// The Ids of [`User`]s that may be notified.
+    ///
+    /// [`User`]: struct.User.html
+    #[serde(default)]
+    pub users: Vec<UserId>,
+}
+
+/// A type of mention that can be parsed from a message's content.
+#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum ParseValue {
+    #[serde(rename = "everyone")] Everyone,
+    #[serde(rename = "roles")] Roles,
+    #[serde(rename = "users")] Users,
+}
+
+/// The anchor to retrieve a channel's messages relative to.
+///
+/// Only one anchor can be used per request, so this replaces separately
+/// setting `around`, `before`, and `after`.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum MessagePagination {
+    /// Retrieve messages sent after the message.
+    After(MessageId),
+    /// Retrieve messages sent around the message.
+    Around(MessageId),
+    /// Retrieve messages sent before the message.
+    Before(MessageId),
+}
+
+impl MessagePagination {
+    /// The Id of the message that the pagination is anchored to.
+    pub fn id(&self) -> MessageId {
+        match *self {
+            MessagePagination::After(id)
+            | MessagePagination::Around(id)
+            | MessagePagination::Before(id) => id,
+        }
+    }
+
+    /// The name of the query parameter that the anchor is sent as.
+    pub fn key(&self) -> &'static str {
+        match *self {
+            MessagePagination::After(_) => "after",
+            MessagePagination::Around(_) => "around",
+            MessagePagination::Before(_) => "before",
+        }
+    }
+}
+
+impl From<MessageId> for MessagePagination {
+    /// Anchors the pagination before the message, the usual way of walking
+    /// back through a channel's history.
+    fn from(message_id: MessageId) -> MessagePagination {
+        MessagePagination::Before(message_id)
+    }
+}
+
+/// The query used to retrieve a channel's messages.
+///
+/// This can be built from a [`MessageId`] directly, to retrieve the messages
+/// sent before it with the default limit, or from a [`MessagePagination`].
+///
+/// [`MessageId`]: struct.MessageId.html
+/// [`MessagePagination`]: enum.MessagePagination.html
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct GetMessages {
+    anchor: Option<MessagePagination>,
+    limit: Option<u64>,
+}
+
+impl GetMessages {
+    /// Retrieves messages sent after the message.
+    pub fn after<M: Into<MessageId>>(self, message_id: M) -> Self {
+        self.anchor(MessagePagination::After(message_id.into()))
+    }
+
+    /// Sets the anchor to retrieve messages relative to, replacing any
+    /// already set.
+    pub fn anchor(mut self, anchor: MessagePagination) -> Self {
+        self.anchor = Some(anchor);
+
+        self
+    }
+
+    /// Retrieves messages sent around the message.
+    pub fn around<M: Into<MessageId>>(self, message_id: M) -> Self {
+        self.anchor(MessagePagination::Around(message_id.into()))
+    }
+
+    /// Retrieves messages sent before the message.
+    pub fn before<M: Into<MessageId>>(self, message_id: M) -> Self {
+        self.anchor(MessagePagination::Before(message_id.into()))
+    }
+
+    /// Sets the maximum number of messages to retrieve, between 1 and
+    /// [`MAX_MESSAGES_PER_REQUEST`].
+    ///
+    /// Defaults to 50.
+    ///
+    /// [`MAX_MESSAGES_PER_REQUEST`]: constant.MAX_MESSAGES_PER_REQUEST.html
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    /// Validates the limit and builds the query string of the request,
+    /// including its leading `?` if not empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidMessageLimit`] if the limit is 0 or more
+    /// than [`MAX_MESSAGES_PER_REQUEST`].
+    ///
+    /// [`MAX_MESSAGES_PER_REQUEST`]: constant.MAX_MESSAGES_PER_REQUEST.html
+    /// [`ModelError::InvalidMessageLimit`]: enum.ModelError.html#variant.InvalidMessageLimit
+    pub fn to_query(&self) -> StdResult<String, ModelError> {
+        let mut query = String::new();
+
+        if let Some(anchor) = self.anchor {
+            let _ = write!(query, "?{}={}", anchor.key(), anchor.id().0);
+        }
+
+        if let Some(limit) = self.limit {
+            if limit == 0 || limit > MAX_MESSAGES_PER_REQUEST {
+                return Err(ModelError::InvalidMessageLimit(limit));
+            }
+
+            let separator = if query.is_empty() { '?' } else { '&' };
+            let _ = write!(query, "{}limit={}", separator, limit);
+        }
+
+        Ok(query)
+    }
+}
+
+impl From<MessageId> for GetMessages {
+    /// Retrieves the messages sent before the message.
+    fn from(message_id: MessageId) -> GetMessages {
+        GetMessages::from(MessagePagination::from(message_id))
+    }
+}
+
+impl From<MessagePagination> for GetMessages {
+    fn from(anchor: MessagePagination) -> GetMessages {
+        GetMessages::default().anchor(anchor)
+    }
+}
@@ -4,8 +4,11 @@ mod embed;
 mod group;
 mod guild_channel;
 mod message;
+mod pin;
 mod private_channel;
 mod reaction;
+mod sticker;
+mod thread;
 mod channel_category;
 
 pub use self::attachment::*;
@@ -14,20 +17,29 @@ pub use self::embed::*;
 pub use self::group::*;
 pub use self::guild_channel::*;
 pub use self::message::*;
+pub use self::pin::*;
 pub use self::private_channel::*;
 pub use self::reaction::*;
+pub use self::sticker::*;
+pub use self::thread::*;
 pub use self::channel_category::*;
 
-use ::*;
-use serde::de::Error as DeError;
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde::ser::{Error as SerError, Serialize, Serializer};
 use serde_json;
+use serenity_common::prelude::{JsonMap, Value};
+use std::result::Result as StdResult;
+use super::permissions::Permissions;
 use super::utils::deserialize_u64;
+use super::{RoleId, UserId};
 
 #[cfg(feature = "model")]
-use builder::{CreateMessage, GetMessages};
+use builder::CreateMessage;
 #[cfg(feature = "model")]
 use http::AttachmentType;
 #[cfg(feature = "model")]
+use super::misc::Mentionable;
+#[cfg(feature = "model")]
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 /// A container for any channel.
@@ -62,7 +74,7 @@ impl<'de> Deserialize<'de> for Channel {
         };
 
         match kind {
-            0 | 2 => serde_json::from_value::<GuildChannel>(Value::Object(v))
+            0 | 2 | 5 | 10 | 11 | 12 => serde_json::from_value::<GuildChannel>(Value::Object(v))
                 .map(Channel::Guild)
                 .map_err(DeError::custom),
             1 => serde_json::from_value::<PrivateChannel>(Value::Object(v))
@@ -79,6 +91,27 @@ impl<'de> Deserialize<'de> for Channel {
     }
 }
 
+impl Serialize for Channel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        match *self {
+            Channel::Category(ref category) => category.serialize(serializer),
+            Channel::Guild(ref channel) => channel.serialize(serializer),
+            Channel::Private(ref channel) => channel.serialize(serializer),
+            Channel::Group(ref group) => {
+                // Groups do not store their type, which is needed to tell
+                // them apart when deserializing.
+                let mut value = serde_json::to_value(group).map_err(SerError::custom)?;
+
+                if let Value::Object(ref mut map) = value {
+                    map.insert("type".to_string(), Value::from(3));
+                }
+
+                value.serialize(serializer)
+            },
+        }
+    }
+}
+
 #[cfg(feature = "model")]
 impl Display for Channel {
     /// Formats the channel into a "mentioned" string.
@@ -132,6 +165,27 @@ enum_number!(
 
 [`ChannelCategory`]: struct.ChannelCategory.html"]
         Category = 4,
+        #[doc="An indicator that the channel is an announcement [`GuildChannel`],
+whose messages can be crossposted to following channels.
+
+[`GuildChannel`]: struct.GuildChannel.html"]
+        News = 5,
+        #[doc="An indicator that the channel is a thread [`GuildChannel`] within an
+announcement channel.
+
+[`GuildChannel`]: struct.GuildChannel.html"]
+        NewsThread = 10,
+        #[doc="An indicator that the channel is a thread [`GuildChannel`] which can
+be viewed by anyone who can view its parent channel.
+
+[`GuildChannel`]: struct.GuildChannel.html"]
+        PublicThread = 11,
+        #[doc="An indicator that the channel is a thread [`GuildChannel`] which can
+only be viewed by those invited and those with the [Manage Threads] permission.
+
+[`GuildChannel`]: struct.GuildChannel.html
+[Manage Threads]: permissions/constant.MANAGE_THREADS.html"]
+        PrivateThread = 12,
     }
 );
 
@@ -143,11 +197,23 @@ impl ChannelType {
             ChannelType::Text => "text",
             ChannelType::Voice => "voice",
             ChannelType::Category => "category",
+            ChannelType::News => "news",
+            ChannelType::NewsThread => "news_thread",
+            ChannelType::PublicThread => "public_thread",
+            ChannelType::PrivateThread => "private_thread",
+        }
+    }
+
+    /// Whether the channel type is one of the thread types.
+    pub fn is_thread(&self) -> bool {
+        match *self {
+            ChannelType::NewsThread | ChannelType::PublicThread | ChannelType::PrivateThread => true,
+            _ => false,
         }
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 struct PermissionOverwriteData {
     allow: Permissions,
     deny: Permissions,
@@ -182,6 +248,62 @@ impl<'de> Deserialize<'de> for PermissionOverwrite {
     }
 }
 
+impl PermissionOverwrite {
+    /// Compares the overwrite to a newer version of itself, such as from
+    /// before and after a channel update.
+    pub fn diff(&self, new: &PermissionOverwrite) -> PermissionOverwriteDiff {
+        PermissionOverwriteDiff {
+            added_allow: new.allow - self.allow,
+            removed_allow: self.allow - new.allow,
+            added_deny: new.deny - self.deny,
+            removed_deny: self.deny - new.deny,
+        }
+    }
+}
+
+/// The permissions that changed between two versions of a
+/// [`PermissionOverwrite`], as produced by [`PermissionOverwrite::diff`].
+///
+/// [`PermissionOverwrite`]: struct.PermissionOverwrite.html
+/// [`PermissionOverwrite::diff`]: struct.PermissionOverwrite.html#method.diff
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PermissionOverwriteDiff {
+    /// The permissions that are newly allowed.
+    pub added_allow: Permissions,
+    /// The permissions that are no longer allowed.
+    pub removed_allow: Permissions,
+    /// The permissions that are newly denied.
+    pub added_deny: Permissions,
+    /// The permissions that are no longer denied.
+    pub removed_deny: Permissions,
+}
+
+impl PermissionOverwriteDiff {
+    /// Whether no permissions changed.
+    pub fn is_empty(&self) -> bool {
+        self.added_allow.is_empty()
+            && self.removed_allow.is_empty()
+            && self.added_deny.is_empty()
+            && self.removed_deny.is_empty()
+    }
+}
+
+impl Serialize for PermissionOverwrite {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        let (id, kind) = match self.kind {
+            PermissionOverwriteType::Member(id) => (id.0, "member"),
+            PermissionOverwriteType::Role(id) => (id.0, "role"),
+        };
+
+        PermissionOverwriteData {
+            allow: self.allow,
+            deny: self.deny,
+            id: id,
+            kind: kind.to_string(),
+        }.serialize(serializer)
+    }
+}
+
 /// The type of edit being made to a Channel's permissions.
 ///
 /// This is for use with methods such as `GuildChannel::create_permission`.
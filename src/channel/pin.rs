@@ -0,0 +1,96 @@
+use chrono::{DateTime, FixedOffset};
+use super::Message;
+
+#[cfg(feature = "model")]
+use http;
+#[cfg(feature = "model")]
+use internal::prelude::*;
+#[cfg(feature = "model")]
+use super::super::ChannelId;
+
+/// The maximum number of pins that are retrieved per request.
+#[cfg(feature = "model")]
+const PINS_PAGE_LIMIT: u64 = 50;
+
+/// A pinned [`Message`], along with when it was pinned.
+///
+/// [`Message`]: struct.Message.html
+#[derive(Clone, Debug, Deserialize)]
+pub struct ChannelPin {
+    /// The message that was pinned.
+    pub message: Message,
+    /// When the message was pinned.
+    pub pinned_at: DateTime<FixedOffset>,
+}
+
+/// A page of a channel's pinned messages, ordered from most to least recently
+/// pinned.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ChannelPins {
+    /// Indicator of whether there are older pins after this page.
+    pub has_more: bool,
+    /// The pins within the page.
+    pub items: Vec<ChannelPin>,
+}
+
+/// An iterator over the pins of a channel, from most to least recently pinned.
+///
+/// Pages are retrieved lazily as the iterator is advanced. If retrieving a page
+/// fails, then the error is yielded and the iterator ends.
+///
+/// This is created via [`ChannelId::pins_iter`].
+///
+/// [`ChannelId::pins_iter`]: struct.ChannelId.html#method.pins_iter
+#[cfg(feature = "model")]
+#[derive(Clone, Debug)]
+pub struct PinsIter {
+    before: Option<DateTime<FixedOffset>>,
+    // Stored from least to most recently pinned, so pins can be popped off.
+    buffer: Vec<ChannelPin>,
+    channel_id: ChannelId,
+    has_more: bool,
+}
+
+#[cfg(feature = "model")]
+impl PinsIter {
+    pub(crate) fn new(channel_id: ChannelId) -> PinsIter {
+        PinsIter {
+            before: None,
+            buffer: vec![],
+            channel_id: channel_id,
+            has_more: true,
+        }
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        let page = http::get_channel_pins(
+            self.channel_id.0,
+            self.before.as_ref(),
+            PINS_PAGE_LIMIT,
+        )?;
+
+        self.has_more = page.has_more && !page.items.is_empty();
+        self.buffer = page.items;
+        self.buffer.reverse();
+        self.before = self.buffer.first().map(|pin| pin.pinned_at);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "model")]
+impl Iterator for PinsIter {
+    type Item = Result<ChannelPin>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_empty() && self.has_more {
+            if let Err(why) = self.refresh() {
+                self.has_more = false;
+
+                return Some(Err(why));
+            }
+        }
+
+        self.buffer.pop().map(Ok)
+    }
+}
@@ -1,17 +1,22 @@
 use chrono::{DateTime, FixedOffset};
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use ::utils::deserialize_single_recipient;
-use ::*;
+use ::utils::{deserialize_single_recipient, serialize_single_recipient};
+use super::super::{ChannelId, MessageId, User};
+use super::ChannelType;
 
 #[cfg(feature = "model")]
-use builder::{CreateMessage, GetMessages};
+use builder::CreateMessage;
 #[cfg(feature = "model")]
 use http::AttachmentType;
 #[cfg(feature = "model")]
 use internal::RwLockExt;
+#[cfg(feature = "model")]
+use internal::prelude::*;
+#[cfg(feature = "model")]
+use super::{GetMessages, Message, PinsIter};
 
 /// A Direct Message text channel with another user.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PrivateChannel {
     /// The unique Id of the private channel.
     ///
@@ -31,7 +36,11 @@ pub struct PrivateChannel {
     #[serde(rename = "type")]
     pub kind: ChannelType,
     /// The recipient to the private channel.
-    #[serde(deserialize_with = "deserialize_single_recipient", rename = "recipients")]
+    #[serde(
+        deserialize_with = "deserialize_single_recipient",
+        rename = "recipients",
+        serialize_with = "serialize_single_recipient"
+    )]
     pub recipient: User,
 }
 
@@ -41,3 +50,71 @@ impl Display for PrivateChannel {
         f.write_str(&self.recipient.name)
     }
 }
+
+#[cfg(feature = "model")]
+impl PrivateChannel {
+    /// Gets messages from the channel.
+    ///
+    /// Refer to [`ChannelId::messages`] for more information.
+    ///
+    /// [`ChannelId::messages`]: struct.ChannelId.html#method.messages
+    #[inline]
+    pub fn messages<F>(&self, f: F) -> Result<Vec<Message>>
+        where F: FnOnce(GetMessages) -> GetMessages {
+        self.id.messages(f)
+    }
+
+    /// Pins a message to the channel.
+    #[inline]
+    pub fn pin<M: Into<MessageId>>(&self, message_id: M) -> Result<()> { self.id.pin(message_id) }
+
+    /// Gets the list of [`Message`]s which are pinned to the channel.
+    ///
+    /// [`Message`]: struct.Message.html
+    #[inline]
+    pub fn pins(&self) -> Result<Vec<Message>> { self.id.pins() }
+
+    /// Returns an iterator over the channel's [`ChannelPin`]s.
+    ///
+    /// [`ChannelPin`]: struct.ChannelPin.html
+    #[inline]
+    pub fn pins_iter(&self) -> PinsIter { self.id.pins_iter() }
+
+    /// Sends a message with the given content to the channel.
+    #[inline]
+    pub fn say(&self, content: &str) -> Result<Message> { self.id.say(content) }
+
+    /// Sends content to the channel over as many messages as are needed.
+    ///
+    /// Refer to [`ChannelId::say_long`] for more information.
+    ///
+    /// [`ChannelId::say_long`]: struct.ChannelId.html#method.say_long
+    #[inline]
+    pub fn say_long(&self, content: &str) -> Result<Vec<Message>> { self.id.say_long(content) }
+
+    /// Sends files along with a message built by the given builder.
+    ///
+    /// Refer to [`ChannelId::send_files`] for more information.
+    ///
+    /// [`ChannelId::send_files`]: struct.ChannelId.html#method.send_files
+    #[inline]
+    pub fn send_files<'a, F, T, It>(&self, files: It, f: F) -> Result<Message>
+        where F: FnOnce(CreateMessage) -> CreateMessage,
+              T: Into<AttachmentType<'a>>,
+              It: IntoIterator<Item = T> {
+        self.id.send_files(files, f)
+    }
+
+    /// Sends a message built by the given builder to the channel.
+    #[inline]
+    pub fn send_message<F>(&self, f: F) -> Result<Message>
+        where F: FnOnce(CreateMessage) -> CreateMessage {
+        self.id.send_message(f)
+    }
+
+    /// Unpins a message from the channel.
+    #[inline]
+    pub fn unpin<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
+        self.id.unpin(message_id)
+    }
+}
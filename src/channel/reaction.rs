@@ -1,5 +1,6 @@
-use ::*;
-use serde::de::{Deserialize, Error as DeError, MapAccess, Visitor};
+use serde::de::{Deserialize, Deserializer, Error as DeError, MapAccess, Visitor};
+use std::result::Result as StdResult;
+use super::super::{ChannelId, Emoji, EmojiId, EmojiIdentifier, GuildId, Member, MessageId, UserId};
 use std::error::Error as StdError;
 use std::fmt::{Display, Formatter, Result as FmtResult, Write as FmtWrite};
 use std::str::FromStr;
@@ -20,6 +21,17 @@ pub struct Reaction {
     pub channel_id: ChannelId,
     /// The reactive emoji used.
     pub emoji: ReactionType,
+    /// The Id of the [`Guild`] that the reaction was made in, if it was made
+    /// in a guild.
+    ///
+    /// [`Guild`]: struct.Guild.html
+    pub guild_id: Option<GuildId>,
+    /// The member that sent the reaction.
+    ///
+    /// **Note**: This is only present for reactions added in a [`Guild`].
+    ///
+    /// [`Guild`]: struct.Guild.html
+    pub member: Option<Member>,
     /// The Id of the [`Message`] that was reacted to.
     ///
     /// [`Message`]: struct.Message.html
@@ -30,6 +42,21 @@ pub struct Reaction {
     pub user_id: UserId,
 }
 
+enum_number!(
+    #[doc="Whether a reaction is a normal reaction or a super reaction, such as
+    when retrieving the users that sent a reaction."]
+    ReactionKind {
+        /// A normal reaction.
+        Normal = 0,
+        /// A super reaction, sent with Nitro.
+        Burst = 1,
+    }
+);
+
+impl Default for ReactionKind {
+    fn default() -> Self { ReactionKind::Normal }
+}
+
 /// The type of a [`Reaction`] sent.
 ///
 /// [`Reaction`]: struct.Reaction.html
@@ -132,6 +159,59 @@ impl ReactionType {
     }
 }
 
+impl ReactionType {
+    /// Whether the reaction is the given unicode emoji.
+    ///
+    /// Variation selectors are ignored, as Discord and keyboards do not agree
+    /// on whether to include them, and so `"❤"` and `"❤\u{FE0F}"` are
+    /// considered the same emoji. Custom emojis never match.
+    pub fn unicode_eq(&self, other: &str) -> bool {
+        match *self {
+            ReactionType::Custom { .. } => false,
+            ReactionType::Unicode(ref unicode) => {
+                unicode.chars().filter(|c| !is_variation_selector(*c))
+                    .eq(other.chars().filter(|c| !is_variation_selector(*c)))
+            },
+        }
+    }
+}
+
+fn is_variation_selector(c: char) -> bool { c == '\u{FE0E}' || c == '\u{FE0F}' }
+
+impl PartialEq<Emoji> for ReactionType {
+    /// Whether the reaction is the custom emoji, comparing only their Ids.
+    fn eq(&self, emoji: &Emoji) -> bool {
+        match *self {
+            ReactionType::Custom { id, .. } => id == emoji.id,
+            ReactionType::Unicode(_) => false,
+        }
+    }
+}
+
+impl PartialEq<EmojiIdentifier> for ReactionType {
+    /// Whether the reaction is the custom emoji, comparing only their Ids.
+    fn eq(&self, emoji: &EmojiIdentifier) -> bool {
+        match *self {
+            ReactionType::Custom { id, .. } => id == emoji.id,
+            ReactionType::Unicode(_) => false,
+        }
+    }
+}
+
+impl PartialEq<str> for ReactionType {
+    /// Whether the reaction is the unicode emoji, ignoring variation
+    /// selectors.
+    ///
+    /// Refer to [`unicode_eq`] for more information.
+    ///
+    /// [`unicode_eq`]: #method.unicode_eq
+    fn eq(&self, unicode: &str) -> bool { self.unicode_eq(unicode) }
+}
+
+impl<'a> PartialEq<&'a str> for ReactionType {
+    fn eq(&self, unicode: &&'a str) -> bool { self.unicode_eq(unicode) }
+}
+
 #[cfg(feature = "model")]
 impl From<char> for ReactionType {
     /// Creates a `ReactionType` from a `char`.
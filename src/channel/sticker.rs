@@ -0,0 +1,57 @@
+use super::super::StickerId;
+
+enum_number!(
+    /// The file format of a sticker's image.
+    StickerFormatType {
+        /// A static PNG image.
+        Png = 1,
+        /// An animated PNG image.
+        Apng = 2,
+        /// A Lottie animation, stored as JSON.
+        Lottie = 3,
+        /// An animated GIF image.
+        Gif = 4,
+    }
+);
+
+impl StickerFormatType {
+    /// The file extension of stickers in the format.
+    pub fn extension(&self) -> &'static str {
+        match *self {
+            StickerFormatType::Png | StickerFormatType::Apng => "png",
+            StickerFormatType::Lottie => "json",
+            StickerFormatType::Gif => "gif",
+        }
+    }
+}
+
+/// The minimal information about a sticker sent in a [`Message`].
+///
+/// [`Message`]: struct.Message.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StickerItem {
+    /// The Id of the sticker.
+    pub id: StickerId,
+    /// The name of the sticker.
+    pub name: String,
+    /// The file format of the sticker's image.
+    pub format_type: StickerFormatType,
+}
+
+impl StickerItem {
+    /// Retrieves the URL to the sticker's image.
+    ///
+    /// The extension is chosen by the sticker's [`format_type`], so Lottie
+    /// stickers link to their JSON animation rather than an image.
+    ///
+    /// [`format_type`]: #structfield.format_type
+    pub fn image_url(&self) -> String {
+        // GIF stickers are only served from the media proxy.
+        let host = match self.format_type {
+            StickerFormatType::Gif => "media.discordapp.net",
+            _ => "cdn.discordapp.com",
+        };
+
+        format!("https://{}/stickers/{}.{}", host, self.id, self.format_type.extension())
+    }
+}
@@ -0,0 +1,359 @@
+use chrono::{DateTime, FixedOffset};
+use super::super::{ChannelId, UserId};
+use super::{ChannelType, GuildChannel};
+
+#[cfg(feature = "model")]
+use http;
+#[cfg(feature = "model")]
+use internal::prelude::*;
+
+/// The maximum number of archived threads that are retrieved per request.
+#[cfg(feature = "model")]
+const ARCHIVED_THREADS_PAGE_LIMIT: u64 = 100;
+
+enum_number!(
+    #[doc="The number of minutes of inactivity after which a thread is
+    automatically archived."]
+    AutoArchiveDuration {
+        /// Archive the thread after an hour of inactivity.
+        OneHour = 60,
+        /// Archive the thread after a day of inactivity.
+        OneDay = 1440,
+        /// Archive the thread after three days of inactivity.
+        ThreeDays = 4320,
+        /// Archive the thread after a week of inactivity.
+        OneWeek = 10080,
+    }
+);
+
+impl AutoArchiveDuration {
+    /// Retrieves the duration matching a number of minutes, if it is one that
+    /// Discord accepts.
+    pub fn from_minutes(minutes: u64) -> Option<AutoArchiveDuration> {
+        match minutes {
+            60 => Some(AutoArchiveDuration::OneHour),
+            1440 => Some(AutoArchiveDuration::OneDay),
+            4320 => Some(AutoArchiveDuration::ThreeDays),
+            10080 => Some(AutoArchiveDuration::OneWeek),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the duration in minutes.
+    #[inline]
+    pub fn minutes(&self) -> u64 { *self as u64 }
+}
+
+/// Thread-specific information about a thread [`GuildChannel`].
+///
+/// [`GuildChannel`]: struct.GuildChannel.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadMetadata {
+    /// The time at which the thread's archive status was last changed.
+    pub archive_timestamp: DateTime<FixedOffset>,
+    /// Indicator of whether the thread is archived.
+    pub archived: bool,
+    /// The number of minutes of inactivity after which the thread is
+    /// automatically archived.
+    pub auto_archive_duration: u64,
+    /// Indicator of whether non-moderators can add other non-moderators to the
+    /// thread.
+    ///
+    /// **Note**: This is only available for private threads.
+    #[serde(default)]
+    pub invitable: Option<bool>,
+    /// Indicator of whether the thread is locked, in which case only members
+    /// with the [Manage Threads] permission can unarchive it.
+    ///
+    /// [Manage Threads]: permissions/constant.MANAGE_THREADS.html
+    #[serde(default)]
+    pub locked: bool,
+}
+
+/// A member of a thread.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadMember {
+    /// Any user-thread settings, currently only used for notifications.
+    pub flags: u64,
+    /// The Id of the thread.
+    ///
+    /// **Note**: This is omitted on the member sent within a [`GuildChannel`].
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    pub id: Option<ChannelId>,
+    /// The time at which the user last joined the thread.
+    pub join_timestamp: DateTime<FixedOffset>,
+    /// The Id of the user.
+    ///
+    /// **Note**: This is omitted on the member sent within a [`GuildChannel`].
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    pub user_id: Option<UserId>,
+}
+
+/// A listing of threads, along with the current user's membership of them.
+///
+/// This is returned when retrieving a guild's active threads, or a page of a
+/// channel's archived threads.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ThreadsData {
+    /// Indicator of whether there are older threads after this page.
+    ///
+    /// This is always `false` for active threads, which are retrieved all at
+    /// once.
+    #[serde(default)]
+    pub has_more: bool,
+    /// A thread member for each of the [`threads`] that the current user has
+    /// joined.
+    ///
+    /// [`threads`]: #structfield.threads
+    pub members: Vec<ThreadMember>,
+    /// The threads within the listing.
+    pub threads: Vec<GuildChannel>,
+}
+
+impl ThreadsData {
+    /// Consumes the listing, attaching each of the current user's thread
+    /// members to the [`member`] of the thread it belongs to.
+    ///
+    /// [`member`]: struct.GuildChannel.html#structfield.member
+    pub fn into_threads(self) -> Vec<GuildChannel> {
+        let mut members = self.members;
+
+        self.threads
+            .into_iter()
+            .map(|mut thread| {
+                if let Some(index) = members.iter().position(|m| m.id == Some(thread.id)) {
+                    thread.member = Some(members.swap_remove(index));
+                }
+
+                thread
+            })
+            .collect()
+    }
+}
+
+/// An iterator over the archived threads of a channel, from most to least
+/// recently archived.
+///
+/// Each thread that the current user has joined has its [`member`] set.
+///
+/// Pages are retrieved lazily as the iterator is advanced. If retrieving a page
+/// fails, then the error is yielded and the iterator ends.
+///
+/// This is created via [`ChannelId::archived_public_threads_iter`] or
+/// [`ChannelId::archived_private_threads_iter`].
+///
+/// [`ChannelId::archived_private_threads_iter`]: struct.ChannelId.html#method.archived_private_threads_iter
+/// [`ChannelId::archived_public_threads_iter`]: struct.ChannelId.html#method.archived_public_threads_iter
+/// [`member`]: struct.GuildChannel.html#structfield.member
+#[cfg(feature = "model")]
+#[derive(Clone, Debug)]
+pub struct ArchivedThreadsIter {
+    before: Option<DateTime<FixedOffset>>,
+    // Stored from least to most recently archived, so threads can be popped
+    // off.
+    buffer: Vec<GuildChannel>,
+    channel_id: ChannelId,
+    has_more: bool,
+    private: bool,
+}
+
+#[cfg(feature = "model")]
+impl ArchivedThreadsIter {
+    pub(crate) fn new(channel_id: ChannelId, private: bool) -> ArchivedThreadsIter {
+        ArchivedThreadsIter {
+            before: None,
+            buffer: vec![],
+            channel_id: channel_id,
+            has_more: true,
+            private: private,
+        }
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        let page = if self.private {
+            http::get_archived_private_threads(
+                self.channel_id.0,
+                self.before.as_ref(),
+                ARCHIVED_THREADS_PAGE_LIMIT,
+            )?
+        } else {
+            http::get_archived_public_threads(
+                self.channel_id.0,
+                self.before.as_ref(),
+                ARCHIVED_THREADS_PAGE_LIMIT,
+            )?
+        };
+
+        self.has_more = page.has_more && !page.threads.is_empty();
+        self.buffer = page.into_threads();
+        self.buffer.reverse();
+        self.before = self.buffer
+            .first()
+            .and_then(|thread| thread.thread_metadata.as_ref())
+            .map(|metadata| metadata.archive_timestamp);
+
+        // Without a cursor the same page would be retrieved again.
+        if self.before.is_none() {
+            self.has_more = false;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "model")]
+impl Iterator for ArchivedThreadsIter {
+    type Item = Result<GuildChannel>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_empty() && self.has_more {
+            if let Err(why) = self.refresh() {
+                self.has_more = false;
+
+                return Some(Err(why));
+            }
+        }
+
+        self.buffer.pop().map(Ok)
+    }
+}
+
+/// The payload used to start a thread.
+///
+/// A [`name`] must always be set.
+///
+/// [`name`]: #method.name
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateThread {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_archive_duration: Option<AutoArchiveDuration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invitable: Option<bool>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    kind: Option<ChannelType>,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate_limit_per_user: Option<u64>,
+}
+
+impl CreateThread {
+    /// Sets the duration of inactivity after which the thread is
+    /// automatically archived.
+    pub fn auto_archive_duration(mut self, duration: AutoArchiveDuration) -> Self {
+        self.auto_archive_duration = Some(duration);
+
+        self
+    }
+
+    /// Sets whether non-moderators can add other non-moderators to the thread.
+    ///
+    /// **Note**: This is only applicable to private threads.
+    pub fn invitable(mut self, invitable: bool) -> Self {
+        self.invitable = Some(invitable);
+
+        self
+    }
+
+    /// Sets the type of thread to create.
+    ///
+    /// Threads started from a message are always public, so this is only
+    /// needed when creating a thread without one.
+    pub fn kind(mut self, kind: ChannelType) -> Self {
+        self.kind = Some(kind);
+
+        self
+    }
+
+    /// Sets the name of the thread.
+    pub fn name<D: ToString>(mut self, name: D) -> Self {
+        self.name = name.to_string();
+
+        self
+    }
+
+    /// Sets the number of seconds a user must wait between sending messages.
+    pub fn rate_limit_per_user(mut self, seconds: u64) -> Self {
+        self.rate_limit_per_user = Some(seconds);
+
+        self
+    }
+}
+
+/// The payload used to edit a thread.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EditThread {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_archive_duration: Option<AutoArchiveDuration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invitable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate_limit_per_user: Option<u64>,
+    #[serde(skip)]
+    pub(crate) reason: Option<String>,
+}
+
+impl EditThread {
+    /// Sets whether the thread is archived.
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+
+        self
+    }
+
+    /// Sets the duration of inactivity after which the thread is
+    /// automatically archived.
+    pub fn auto_archive_duration(mut self, duration: AutoArchiveDuration) -> Self {
+        self.auto_archive_duration = Some(duration);
+
+        self
+    }
+
+    /// Sets whether non-moderators can add other non-moderators to the thread.
+    ///
+    /// **Note**: This is only applicable to private threads.
+    pub fn invitable(mut self, invitable: bool) -> Self {
+        self.invitable = Some(invitable);
+
+        self
+    }
+
+    /// Sets whether the thread is locked, in which case only members with the
+    /// [Manage Threads] permission can unarchive it.
+    ///
+    /// [Manage Threads]: permissions/constant.MANAGE_THREADS.html
+    pub fn locked(mut self, locked: bool) -> Self {
+        self.locked = Some(locked);
+
+        self
+    }
+
+    /// Sets the name of the thread.
+    pub fn name<D: ToString>(mut self, name: D) -> Self {
+        self.name = Some(name.to_string());
+
+        self
+    }
+
+    /// Sets the number of seconds a user must wait between sending messages.
+    pub fn rate_limit_per_user(mut self, seconds: u64) -> Self {
+        self.rate_limit_per_user = Some(seconds);
+
+        self
+    }
+
+    /// Sets the reason for editing the thread, which is shown in the guild's
+    /// audit log.
+    pub fn reason<S: ToString>(mut self, reason: S) -> Self {
+        self.reason = Some(reason.to_string());
+
+        self
+    }
+}
@@ -0,0 +1,767 @@
+//! Models for message components, and the data sent when a user interacts
+//! with them.
+
+use serde_json::{self, Value};
+use std::error::Error as StdError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::result::Result as StdResult;
+use super::utils::serialize_emoji;
+use super::{
+    ChannelId,
+    ChannelType,
+    ReactionType,
+    ResolvedChannel,
+    ResolvedData,
+    Role,
+    RoleId,
+    User,
+    UserId,
+};
+
+/// The maximum length of a component's custom Id, in characters.
+pub const MAX_CUSTOM_ID_LENGTH: usize = 100;
+
+/// The maximum number of buttons within a single action row.
+pub const MAX_ROW_BUTTONS: usize = 5;
+
+/// The maximum number of action rows within a message or modal.
+pub const MAX_ACTION_ROWS: usize = 5;
+
+enum_number!(
+    #[doc="The type of a message component."]
+    ComponentType {
+        /// A container for other components.
+        ActionRow = 1,
+        /// A clickable button.
+        Button = 2,
+        /// A select menu of options defined by the bot.
+        StringSelect = 3,
+        /// A text input, only available within modals.
+        TextInput = 4,
+        /// A select menu of users, populated by Discord.
+        UserSelect = 5,
+        /// A select menu of roles, populated by Discord.
+        RoleSelect = 6,
+        /// A select menu of users and roles, populated by Discord.
+        MentionableSelect = 7,
+        /// A select menu of channels, populated by Discord.
+        ChannelSelect = 8,
+    }
+);
+
+impl ComponentType {
+    /// Whether the component is a select menu of any kind.
+    pub fn is_select_menu(&self) -> bool {
+        match *self {
+            ComponentType::StringSelect
+            | ComponentType::UserSelect
+            | ComponentType::RoleSelect
+            | ComponentType::MentionableSelect
+            | ComponentType::ChannelSelect => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the component is a select menu whose options are populated by
+    /// Discord, rather than given by the bot.
+    pub fn is_auto_populated_select(&self) -> bool {
+        self.is_select_menu() && *self != ComponentType::StringSelect
+    }
+}
+
+/// An option within a [`StringSelect`] menu.
+///
+/// [`StringSelect`]: enum.ComponentType.html#variant.StringSelect
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SelectMenuOption {
+    /// Whether the option is selected by default.
+    #[serde(default)]
+    pub default: bool,
+    /// An additional description of the option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The name of the option shown to users.
+    pub label: String,
+    /// The value sent back when the option is selected.
+    pub value: String,
+}
+
+/// An entity selected by default in a select menu populated by Discord.
+///
+/// Only [`User`] and [`Role`] values may be used in a [`MentionableSelect`],
+/// and only values of the matching kind in the other select menus.
+///
+/// [`MentionableSelect`]: enum.ComponentType.html#variant.MentionableSelect
+/// [`Role`]: #variant.Role
+/// [`User`]: #variant.User
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(tag = "type", content = "id")]
+pub enum SelectDefaultValue {
+    /// A channel selected by default.
+    #[serde(rename = "channel")] Channel(ChannelId),
+    /// A role selected by default.
+    #[serde(rename = "role")] Role(RoleId),
+    /// A user selected by default.
+    #[serde(rename = "user")] User(UserId),
+}
+
+/// The payload used to create a select menu.
+///
+/// By default this creates a [`StringSelect`] menu. A [`custom_id`] must
+/// always be set.
+///
+/// [`StringSelect`]: enum.ComponentType.html#variant.StringSelect
+/// [`custom_id`]: #method.custom_id
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateSelectMenu {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    channel_types: Vec<ChannelType>,
+    custom_id: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    default_values: Vec<SelectDefaultValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    disabled: Option<bool>,
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_values: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_values: Option<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    options: Vec<SelectMenuOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placeholder: Option<String>,
+}
+
+impl CreateSelectMenu {
+    /// Sets the types of channels that can be selected.
+    ///
+    /// **Note**: This is only applicable to [`ChannelSelect`] menus.
+    ///
+    /// [`ChannelSelect`]: enum.ComponentType.html#variant.ChannelSelect
+    pub fn channel_types(mut self, channel_types: Vec<ChannelType>) -> Self {
+        self.channel_types = channel_types;
+
+        self
+    }
+
+    /// Sets the developer-defined Id sent back when the menu is used.
+    pub fn custom_id<D: ToString>(mut self, custom_id: D) -> Self {
+        self.custom_id = custom_id.to_string();
+
+        self
+    }
+
+    /// Sets the entities that are selected by default.
+    ///
+    /// **Note**: This is only applicable to select menus populated by Discord.
+    /// For [`StringSelect`] menus, set the [`default`] of an option instead.
+    ///
+    /// [`StringSelect`]: enum.ComponentType.html#variant.StringSelect
+    /// [`default`]: struct.SelectMenuOption.html#structfield.default
+    pub fn default_values(mut self, default_values: Vec<SelectDefaultValue>) -> Self {
+        self.default_values = default_values;
+
+        self
+    }
+
+    /// Sets whether the menu is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+
+        self
+    }
+
+    /// Sets the type of select menu.
+    pub fn kind(mut self, kind: ComponentType) -> Self {
+        self.kind = kind;
+
+        self
+    }
+
+    /// Sets the maximum number of values that can be selected.
+    pub fn max_values(mut self, max_values: u64) -> Self {
+        self.max_values = Some(max_values);
+
+        self
+    }
+
+    /// Sets the minimum number of values that must be selected.
+    pub fn min_values(mut self, min_values: u64) -> Self {
+        self.min_values = Some(min_values);
+
+        self
+    }
+
+    /// Sets the options of the menu.
+    ///
+    /// **Note**: This is only applicable to [`StringSelect`] menus, for which
+    /// it is required.
+    ///
+    /// [`StringSelect`]: enum.ComponentType.html#variant.StringSelect
+    pub fn options(mut self, options: Vec<SelectMenuOption>) -> Self {
+        self.options = options;
+
+        self
+    }
+
+    /// Sets the text shown when nothing is selected.
+    pub fn placeholder<D: ToString>(mut self, placeholder: D) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+
+        self
+    }
+}
+
+impl Default for CreateSelectMenu {
+    fn default() -> Self {
+        CreateSelectMenu {
+            channel_types: Vec::new(),
+            custom_id: String::new(),
+            default_values: Vec::new(),
+            disabled: None,
+            kind: ComponentType::StringSelect,
+            max_values: None,
+            min_values: None,
+            options: Vec::new(),
+            placeholder: None,
+        }
+    }
+}
+
+/// An error returned when validating components before they are sent.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ComponentError {
+    /// A custom Id was longer than [`MAX_CUSTOM_ID_LENGTH`] characters. The
+    /// length of the custom Id is given.
+    ///
+    /// [`MAX_CUSTOM_ID_LENGTH`]: constant.MAX_CUSTOM_ID_LENGTH.html
+    CustomIdTooLong(usize),
+    /// An action row contained no components.
+    EmptyActionRow,
+    /// A link button had a custom Id, or another button had a URL.
+    InvalidButton,
+    /// A select menu or text input shared its action row with other
+    /// components.
+    MixedActionRow,
+    /// A component other than a link button had no custom Id.
+    MissingCustomId,
+    /// A modal contained a component other than a text input.
+    NonTextInputInModal,
+    /// The components could not be serialized. The error message is given.
+    Serialization(String),
+    /// More than [`MAX_ACTION_ROWS`] action rows were given. The number of
+    /// rows is given.
+    ///
+    /// [`MAX_ACTION_ROWS`]: constant.MAX_ACTION_ROWS.html
+    TooManyActionRows(usize),
+    /// An action row contained more than [`MAX_ROW_BUTTONS`] buttons. The
+    /// number of buttons is given.
+    ///
+    /// [`MAX_ROW_BUTTONS`]: constant.MAX_ROW_BUTTONS.html
+    TooManyButtons(usize),
+}
+
+impl Display for ComponentError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(self.description()) }
+}
+
+impl StdError for ComponentError {
+    fn description(&self) -> &str {
+        match *self {
+            ComponentError::CustomIdTooLong(_) => "Custom Id is longer than 100 characters",
+            ComponentError::EmptyActionRow => "Action row has no components",
+            ComponentError::InvalidButton => "Only link buttons may have a URL",
+            ComponentError::MixedActionRow => "Select menus and text inputs must be alone in a row",
+            ComponentError::MissingCustomId => "Component requires a custom Id",
+            ComponentError::NonTextInputInModal => "Modals may only contain text inputs",
+            ComponentError::Serialization(_) => "Components could not be serialized",
+            ComponentError::TooManyActionRows(_) => "More than 5 action rows were given",
+            ComponentError::TooManyButtons(_) => "Action row has more than 5 buttons",
+        }
+    }
+}
+
+enum_number!(
+    #[doc="The style of a [`CreateButton`].
+
+[`CreateButton`]: struct.CreateButton.html"]
+    ButtonStyle {
+        /// A blurple button.
+        Primary = 1,
+        /// A grey button.
+        Secondary = 2,
+        /// A green button.
+        Success = 3,
+        /// A red button.
+        Danger = 4,
+        /// A grey button which opens a URL instead of sending an interaction.
+        Link = 5,
+    }
+);
+
+enum_number!(
+    #[doc="The style of a [`CreateInputText`].
+
+[`CreateInputText`]: struct.CreateInputText.html"]
+    InputTextStyle {
+        /// A single-line input.
+        Short = 1,
+        /// A multi-line input.
+        Paragraph = 2,
+    }
+);
+
+/// The payload used to create an action row, which holds up to
+/// [`MAX_ROW_BUTTONS`] buttons, or a single select menu or text input.
+///
+/// Use [`to_value`] to validate the row and convert it into the form taken by
+/// message builders.
+///
+/// [`MAX_ROW_BUTTONS`]: constant.MAX_ROW_BUTTONS.html
+/// [`to_value`]: #method.to_value
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateActionRow {
+    components: Vec<RowComponent>,
+    #[serde(rename = "type")]
+    kind: ComponentType,
+}
+
+impl CreateActionRow {
+    /// Adds a button to the row.
+    pub fn button(mut self, button: CreateButton) -> Self {
+        self.components.push(RowComponent::Button(button));
+
+        self
+    }
+
+    /// Adds a text input to the row.
+    ///
+    /// **Note**: Text inputs are only available within modals.
+    pub fn input_text(mut self, input_text: CreateInputText) -> Self {
+        self.components.push(RowComponent::InputText(input_text));
+
+        self
+    }
+
+    /// Adds a select menu to the row.
+    pub fn select_menu(mut self, select_menu: CreateSelectMenu) -> Self {
+        self.components.push(RowComponent::SelectMenu(select_menu));
+
+        self
+    }
+
+    /// Validates the row and converts it into a value suitable for the
+    /// `components` of a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ComponentError`] if the row is empty, has too many
+    /// buttons, mixes a select menu or text input with other components, or
+    /// contains an invalid component.
+    ///
+    /// [`ComponentError`]: enum.ComponentError.html
+    pub fn to_value(&self) -> StdResult<Value, ComponentError> {
+        self.validate()?;
+
+        serde_json::to_value(self).map_err(|why| ComponentError::Serialization(why.to_string()))
+    }
+
+    fn validate(&self) -> StdResult<(), ComponentError> {
+        if self.components.is_empty() {
+            return Err(ComponentError::EmptyActionRow);
+        }
+
+        let buttons = self.components
+            .iter()
+            .filter(|component| match **component {
+                RowComponent::Button(_) => true,
+                _ => false,
+            })
+            .count();
+
+        if buttons != self.components.len() && self.components.len() > 1 {
+            return Err(ComponentError::MixedActionRow);
+        }
+
+        if buttons > MAX_ROW_BUTTONS {
+            return Err(ComponentError::TooManyButtons(buttons));
+        }
+
+        for component in &self.components {
+            match *component {
+                RowComponent::Button(ref button) => button.validate()?,
+                RowComponent::InputText(ref input_text) => {
+                    validate_custom_id(&input_text.custom_id)?
+                },
+                RowComponent::SelectMenu(ref select_menu) => {
+                    validate_custom_id(&select_menu.custom_id)?
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for CreateActionRow {
+    fn default() -> Self {
+        CreateActionRow {
+            components: Vec::new(),
+            kind: ComponentType::ActionRow,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+enum RowComponent {
+    Button(CreateButton),
+    InputText(CreateInputText),
+    SelectMenu(CreateSelectMenu),
+}
+
+/// The payload used to create a button.
+///
+/// By default this creates a [`Primary`] button. Link buttons must have a
+/// [`url`] and no [`custom_id`], while all other buttons must have a custom
+/// Id.
+///
+/// [`Primary`]: enum.ButtonStyle.html#variant.Primary
+/// [`custom_id`]: #method.custom_id
+/// [`url`]: #method.url
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateButton {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    disabled: Option<bool>,
+    #[serde(serialize_with = "serialize_emoji", skip_serializing_if = "Option::is_none")]
+    emoji: Option<ReactionType>,
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    style: ButtonStyle,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+impl CreateButton {
+    /// Sets the developer-defined Id sent back when the button is clicked.
+    pub fn custom_id<D: ToString>(mut self, custom_id: D) -> Self {
+        self.custom_id = Some(custom_id.to_string());
+
+        self
+    }
+
+    /// Sets whether the button is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+
+        self
+    }
+
+    /// Sets the emoji shown on the button.
+    pub fn emoji(mut self, emoji: ReactionType) -> Self {
+        self.emoji = Some(emoji);
+
+        self
+    }
+
+    /// Sets the text shown on the button.
+    pub fn label<D: ToString>(mut self, label: D) -> Self {
+        self.label = Some(label.to_string());
+
+        self
+    }
+
+    /// Sets the style of the button.
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Sets the URL opened by a [`Link`] button.
+    ///
+    /// [`Link`]: enum.ButtonStyle.html#variant.Link
+    pub fn url<D: ToString>(mut self, url: D) -> Self {
+        self.url = Some(url.to_string());
+
+        self
+    }
+
+    fn validate(&self) -> StdResult<(), ComponentError> {
+        match (self.style, self.custom_id.as_ref(), self.url.is_some()) {
+            (ButtonStyle::Link, None, true) => Ok(()),
+            (ButtonStyle::Link, _, _) | (_, _, true) => Err(ComponentError::InvalidButton),
+            (_, Some(custom_id), false) => validate_custom_id(custom_id),
+            (_, None, false) => Err(ComponentError::MissingCustomId),
+        }
+    }
+}
+
+impl Default for CreateButton {
+    fn default() -> Self {
+        CreateButton {
+            custom_id: None,
+            disabled: None,
+            emoji: None,
+            kind: ComponentType::Button,
+            label: None,
+            style: ButtonStyle::Primary,
+            url: None,
+        }
+    }
+}
+
+/// The payload used to create a text input within a [`CreateModal`].
+///
+/// By default this creates a [`Short`] input. A [`custom_id`] and [`label`]
+/// must always be set.
+///
+/// [`CreateModal`]: struct.CreateModal.html
+/// [`Short`]: enum.InputTextStyle.html#variant.Short
+/// [`custom_id`]: #method.custom_id
+/// [`label`]: #method.label
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateInputText {
+    custom_id: String,
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_length: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_length: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placeholder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required: Option<bool>,
+    style: InputTextStyle,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+}
+
+impl CreateInputText {
+    /// Sets the developer-defined Id sent back with the input's value.
+    pub fn custom_id<D: ToString>(mut self, custom_id: D) -> Self {
+        self.custom_id = custom_id.to_string();
+
+        self
+    }
+
+    /// Sets the label shown above the input.
+    pub fn label<D: ToString>(mut self, label: D) -> Self {
+        self.label = label.to_string();
+
+        self
+    }
+
+    /// Sets the maximum length of the input, in characters.
+    pub fn max_length(mut self, max_length: u64) -> Self {
+        self.max_length = Some(max_length);
+
+        self
+    }
+
+    /// Sets the minimum length of the input, in characters.
+    pub fn min_length(mut self, min_length: u64) -> Self {
+        self.min_length = Some(min_length);
+
+        self
+    }
+
+    /// Sets the text shown when the input is empty.
+    pub fn placeholder<D: ToString>(mut self, placeholder: D) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+
+        self
+    }
+
+    /// Sets whether the input must be filled in.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+
+        self
+    }
+
+    /// Sets the style of the input.
+    pub fn style(mut self, style: InputTextStyle) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Sets the text that the input is pre-filled with.
+    pub fn value<D: ToString>(mut self, value: D) -> Self {
+        self.value = Some(value.to_string());
+
+        self
+    }
+}
+
+impl Default for CreateInputText {
+    fn default() -> Self {
+        CreateInputText {
+            custom_id: String::new(),
+            kind: ComponentType::TextInput,
+            label: String::new(),
+            max_length: None,
+            min_length: None,
+            placeholder: None,
+            required: None,
+            style: InputTextStyle::Short,
+            value: None,
+        }
+    }
+}
+
+/// The payload used to show a modal in response to an interaction.
+///
+/// Each action row of a modal holds a single [`CreateInputText`]. A
+/// [`custom_id`] and [`title`] must always be set.
+///
+/// [`CreateInputText`]: struct.CreateInputText.html
+/// [`custom_id`]: #method.custom_id
+/// [`title`]: #method.title
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateModal {
+    components: Vec<CreateActionRow>,
+    custom_id: String,
+    title: String,
+}
+
+impl CreateModal {
+    /// Sets the developer-defined Id sent back when the modal is submitted.
+    pub fn custom_id<D: ToString>(mut self, custom_id: D) -> Self {
+        self.custom_id = custom_id.to_string();
+
+        self
+    }
+
+    /// Adds a text input to the modal, in its own action row.
+    pub fn input_text(mut self, input_text: CreateInputText) -> Self {
+        self.components.push(CreateActionRow::default().input_text(input_text));
+
+        self
+    }
+
+    /// Sets the title shown at the top of the modal.
+    pub fn title<D: ToString>(mut self, title: D) -> Self {
+        self.title = title.to_string();
+
+        self
+    }
+
+    /// Validates the modal and converts it into a value suitable for the
+    /// data of a modal interaction response.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ComponentError`] if the modal has too many text inputs, or
+    /// an invalid custom Id.
+    ///
+    /// [`ComponentError`]: enum.ComponentError.html
+    pub fn to_value(&self) -> StdResult<Value, ComponentError> {
+        validate_custom_id(&self.custom_id)?;
+
+        if self.components.len() > MAX_ACTION_ROWS {
+            return Err(ComponentError::TooManyActionRows(self.components.len()));
+        }
+
+        for row in &self.components {
+            let only_text_inputs = row.components.iter().all(|component| match *component {
+                RowComponent::InputText(_) => true,
+                _ => false,
+            });
+
+            if !only_text_inputs {
+                return Err(ComponentError::NonTextInputInModal);
+            }
+
+            row.validate()?;
+        }
+
+        serde_json::to_value(self).map_err(|why| ComponentError::Serialization(why.to_string()))
+    }
+}
+
+/// Validates a set of action rows and converts them into the `components` of
+/// a message.
+///
+/// # Errors
+///
+/// Returns a [`ComponentError`] if more than [`MAX_ACTION_ROWS`] rows are
+/// given, or if any row is invalid.
+///
+/// [`ComponentError`]: enum.ComponentError.html
+/// [`MAX_ACTION_ROWS`]: constant.MAX_ACTION_ROWS.html
+pub fn action_rows_to_values(rows: &[CreateActionRow]) -> StdResult<Vec<Value>, ComponentError> {
+    if rows.len() > MAX_ACTION_ROWS {
+        return Err(ComponentError::TooManyActionRows(rows.len()));
+    }
+
+    rows.iter().map(CreateActionRow::to_value).collect()
+}
+
+fn validate_custom_id(custom_id: &str) -> StdResult<(), ComponentError> {
+    let length = custom_id.chars().count();
+
+    if length == 0 {
+        Err(ComponentError::MissingCustomId)
+    } else if length > MAX_CUSTOM_ID_LENGTH {
+        Err(ComponentError::CustomIdTooLong(length))
+    } else {
+        Ok(())
+    }
+}
+
+/// The data sent when a user interacts with a message component.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ComponentInteractionData {
+    /// The developer-defined Id of the component.
+    pub custom_id: String,
+    /// The type of the component.
+    #[serde(rename = "component_type")]
+    pub kind: ComponentType,
+    /// The entities selected in a select menu populated by Discord.
+    #[serde(default)]
+    pub resolved: ResolvedData,
+    /// The values selected in a select menu.
+    ///
+    /// For select menus populated by Discord, these are the Ids of the
+    /// selected entities.
+    #[serde(default)]
+    pub values: Vec<String>,
+}
+
+impl ComponentInteractionData {
+    /// Retrieves the selected channels, in the order they were selected.
+    pub fn selected_channels(&self) -> Vec<&ResolvedChannel> {
+        self.values
+            .iter()
+            .filter_map(|value| value.parse::<ChannelId>().ok())
+            .filter_map(|id| self.resolved.channels.get(&id))
+            .collect()
+    }
+
+    /// Retrieves the selected roles, in the order they were selected.
+    pub fn selected_roles(&self) -> Vec<&Role> {
+        self.values
+            .iter()
+            .filter_map(|value| value.parse::<RoleId>().ok())
+            .filter_map(|id| self.resolved.roles.get(&id))
+            .collect()
+    }
+
+    /// Retrieves the selected users, in the order they were selected.
+    pub fn selected_users(&self) -> Vec<&User> {
+        self.values
+            .iter()
+            .filter_map(|value| value.parse::<UserId>().ok())
+            .filter_map(|id| self.resolved.users.get(&id))
+            .collect()
+    }
+}
@@ -1,6 +1,7 @@
 use std::error::Error as StdError;
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use super::Permissions;
+use super::limits::LimitError;
+use super::{CommandError, OnboardingError, Permissions, RolePositionError};
 
 /// An error returned from the [`model`] module.
 ///
@@ -77,6 +78,13 @@ pub enum Error {
     /// Indicates that the textual content of an embed exceeds the maximum
     /// length.
     EmbedTooLarge(u64),
+    /// Indicates that an image to be uploaded as an [`Emoji`] exceeds the
+    /// maximum size of 256KB.
+    ///
+    /// The number of bytes larger than the limit is provided.
+    ///
+    /// [`Emoji`]: ../model/struct.Emoji.html
+    EmojiTooLarge(u64),
     /// An indication that a [guild][`Guild`] could not be found by
     /// [Id][`GuildId`] in the [`Cache`].
     ///
@@ -84,6 +92,32 @@ pub enum Error {
     /// [`GuildId`]: ../model/struct.GuildId.html
     /// [`Cache`]: ../cache/struct.Cache.html
     GuildNotFound,
+    /// Indicates that the acting member's highest role is not above the role
+    /// being managed, and so the action would be rejected by Discord.
+    Hierarchy,
+    /// Indicates that a [`CreateApplicationCommand`] does not meet Discord's
+    /// requirements, and so would be rejected.
+    ///
+    /// [`CreateApplicationCommand`]: ../model/struct.CreateApplicationCommand.html
+    InvalidCommand(CommandError),
+    /// Indicates that the name of an [`Emoji`] is invalid, as it is not
+    /// between 2 and 32 characters long or contains characters other than
+    /// ASCII alphanumerics and underscores.
+    ///
+    /// [`Emoji`]: ../model/struct.Emoji.html
+    InvalidEmojiName,
+    /// Indicates that the limit of a [`GetMessages`] query is 0 or over 100,
+    /// and so would be rejected by Discord.
+    ///
+    /// The invalid limit is provided.
+    ///
+    /// [`GetMessages`]: ../model/struct.GetMessages.html
+    InvalidMessageLimit(u64),
+    /// Indicates that [`Onboarding`] does not meet Discord's requirements, and
+    /// so would be rejected.
+    ///
+    /// [`Onboarding`]: ../model/struct.Onboarding.html
+    InvalidOnboarding(OnboardingError),
     /// Indicates that you do not have the required permissions to perform an
     /// operation.
     ///
@@ -92,15 +126,30 @@ pub enum Error {
     ///
     /// [`Permission`]: ../model/permissions/struct.Permissions.html
     InvalidPermissions(Permissions),
+    /// Indicates that a set of [`RolePosition`]s is inconsistent, and so would
+    /// be rejected by Discord.
+    ///
+    /// [`RolePosition`]: ../model/struct.RolePosition.html
+    InvalidRolePositions(RolePositionError),
     /// An indicator that the [current user] can not perform an action.
     ///
     /// [current user]: ../model/struct.CurrentUser.html
     InvalidUser,
+    /// Indicates that the name of a [`Webhook`] is invalid, as it is not
+    /// between 1 and 80 characters long or contains "clyde".
+    ///
+    /// [`Webhook`]: ../model/struct.Webhook.html
+    InvalidWebhookName,
     /// An indicator that an item is missing from the [`Cache`], and the action
     /// can not be continued.
     ///
     /// [`Cache`]: ../cache/struct.Cache.html
     ItemMissing,
+    /// Indicates that a [`Role`] is managed by an integration, and so can not
+    /// be manually given to or removed from members.
+    ///
+    /// [`Role`]: ../model/struct.Role.html
+    ManagedRole,
     /// Indicates that a [`Message`]s content was too long and will not
     /// successfully send, as the length is over 2000 codepoints, or 4000 bytes.
     ///
@@ -111,6 +160,35 @@ pub enum Error {
     /// Indicates that the current user is attempting to Direct Message another
     /// bot user, which is disallowed by the API.
     MessagingBot,
+    /// Indicates that a model does not know the [`Guild`] it belongs to, such
+    /// as an [`Emoji`] received outside of a guild's payload.
+    ///
+    /// [`Emoji`]: ../model/struct.Emoji.html
+    /// [`Guild`]: ../model/struct.Guild.html
+    MissingGuildId,
+    /// Indicates that the current user is not the owner of the [`Guild`], and
+    /// so can not perform an action restricted to its owner.
+    ///
+    /// [`Guild`]: ../model/struct.Guild.html
+    NotGuildOwner,
+    /// Indicates that a value given to an edit builder is outside of the
+    /// [limits] that Discord allows, and so would be rejected.
+    ///
+    /// [limits]: ../model/limits/index.html
+    OutOfLimits(LimitError),
+    /// Indicates that more than 25 choices were given in response to an
+    /// autocomplete interaction.
+    ///
+    /// The number of choices over the limit is provided.
+    TooManyChoices(u64),
+    /// Indicates that an image to be uploaded is not in a supported format,
+    /// being one of PNG, JPEG, GIF, or WebP.
+    UnsupportedImageFormat,
+    /// Indicates that an [`Integration`] is not of a kind that supports the
+    /// action, such as syncing a bot integration.
+    ///
+    /// [`Integration`]: ../model/struct.Integration.html
+    UnsupportedIntegration,
 }
 
 impl Display for Error {
@@ -123,12 +201,27 @@ impl StdError for Error {
             Error::BulkDeleteAmount => "Too few/many messages to bulk delete",
             Error::DeleteMessageDaysAmount(_) => "Invalid delete message days",
             Error::EmbedTooLarge(_) => "Embed too large",
+            Error::EmojiTooLarge(_) => "Emoji image too large",
             Error::GuildNotFound => "Guild not found in the cache",
+            Error::Hierarchy => "Role hierarchy prevents this action",
+            Error::InvalidCommand(_) => "Invalid application command",
+            Error::InvalidEmojiName => "Invalid emoji name",
+            Error::InvalidMessageLimit(_) => "Invalid message limit",
+            Error::InvalidOnboarding(_) => "Invalid onboarding",
             Error::InvalidPermissions(_) => "Invalid permissions",
+            Error::InvalidRolePositions(_) => "Invalid role positions",
             Error::InvalidUser => "The current user can not perform the action",
+            Error::InvalidWebhookName => "Invalid webhook name",
             Error::ItemMissing => "The required item is missing from the cache",
+            Error::ManagedRole => "The role is managed by an integration",
             Error::MessageTooLong(_) => "Message too large",
             Error::MessagingBot => "Attempted to message another bot user",
+            Error::MissingGuildId => "The guild of the model is not known",
+            Error::NotGuildOwner => "The current user does not own the guild",
+            Error::OutOfLimits(_) => "Value is outside of Discord's limits",
+            Error::TooManyChoices(_) => "Too many autocomplete choices",
+            Error::UnsupportedImageFormat => "Unsupported image format",
+            Error::UnsupportedIntegration => "Unsupported integration kind for this action",
         }
     }
 }
@@ -0,0 +1,560 @@
+//! Encoding and decoding of models and events to Discord's flavour of the
+//! [External Term Format], as used by gateway connections opened with
+//! `encoding=etf`.
+//!
+//! Payloads are converted to and from the same shape as their JSON
+//! counterparts, and so every model that can be deserialized from JSON can be
+//! decoded from ETF:
+//!
+//! ```rust,ignore
+//! use serenity_model::etf;
+//! use serenity_model::event::GatewayEvent;
+//!
+//! let event = etf::from_slice::<GatewayEvent>(&bytes)?;
+//! ```
+//!
+//! Discord's encoding differs from Erlang's in a few ways, which are handled
+//! here:
+//!
+//! - strings are sent as binaries, and map keys as either atoms or binaries;
+//! - `null` is sent as the atom `nil`, and booleans as the atoms `true` and
+//!   `false`;
+//! - integers too large for 32 bits, such as snowflakes, are sent as big
+//!   integers.
+//!
+//! Models are deserialized directly from the decoded [`Term`], through
+//! [`TermDeserializer`], without first being converted to JSON.
+//!
+//! This module is only available with the `etf` feature enabled.
+//!
+//! [External Term Format]: http://erlang.org/doc/apps/erts/erl_ext_dist.html
+//! [`Term`]: ../../eetf/enum.Term.html
+//! [`TermDeserializer`]: struct.TermDeserializer.html
+
+use eetf::{Atom, BigInteger, Binary, FixInteger, Float, List, Map, Term};
+use eetf::{DecodeError, EncodeError};
+use serde::de::{
+    Deserialize,
+    DeserializeOwned,
+    DeserializeSeed,
+    Deserializer,
+    EnumAccess,
+    Error as DeError,
+    IntoDeserializer,
+    MapAccess,
+    SeqAccess,
+    VariantAccess,
+    Visitor,
+};
+use serde::ser::Serialize;
+use serde_json::{self, Number, Value};
+use serenity_common::prelude::JsonMap;
+use std::error::Error as StdError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::result::Result as StdResult;
+use std::vec::IntoIter;
+
+/// An error returned when encoding or decoding ETF.
+#[derive(Debug)]
+pub enum EtfError {
+    /// The payload was not valid ETF.
+    Decode(DecodeError),
+    /// The term could not be written.
+    Encode(EncodeError),
+    /// The payload did not match the shape of the model. The error message is
+    /// given.
+    Deserialize(String),
+    /// The model could not be serialized.
+    Model(serde_json::Error),
+    /// The payload contained a term that Discord does not send, such as a
+    /// pid or a function.
+    UnsupportedTerm,
+}
+
+impl Display for EtfError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            EtfError::Decode(ref inner) => Display::fmt(inner, f),
+            EtfError::Deserialize(ref message) => f.write_str(message),
+            EtfError::Encode(ref inner) => Display::fmt(inner, f),
+            EtfError::Model(ref inner) => Display::fmt(inner, f),
+            EtfError::UnsupportedTerm => f.write_str(self.description()),
+        }
+    }
+}
+
+impl StdError for EtfError {
+    fn description(&self) -> &str {
+        match *self {
+            EtfError::Decode(ref inner) => inner.description(),
+            EtfError::Deserialize(_) => "ETF payload did not match the model",
+            EtfError::Encode(ref inner) => inner.description(),
+            EtfError::Model(ref inner) => inner.description(),
+            EtfError::UnsupportedTerm => "unsupported ETF term",
+        }
+    }
+}
+
+impl DeError for EtfError {
+    fn custom<T: Display>(message: T) -> Self { EtfError::Deserialize(message.to_string()) }
+}
+
+/// Decodes a model or event from ETF.
+///
+/// The model is deserialized directly from the decoded [`Term`], through a
+/// [`TermDeserializer`].
+///
+/// # Errors
+///
+/// Returns [`EtfError::Decode`] if the payload is not valid ETF,
+/// [`EtfError::Deserialize`] if it does not match the shape of the model, or
+/// [`EtfError::UnsupportedTerm`] if it contains a type that Discord does not
+/// send.
+///
+/// [`EtfError::Decode`]: enum.EtfError.html#variant.Decode
+/// [`EtfError::Deserialize`]: enum.EtfError.html#variant.Deserialize
+/// [`EtfError::UnsupportedTerm`]: enum.EtfError.html#variant.UnsupportedTerm
+/// [`Term`]: ../../eetf/enum.Term.html
+/// [`TermDeserializer`]: struct.TermDeserializer.html
+pub fn from_slice<T: DeserializeOwned>(mut bytes: &[u8]) -> StdResult<T, EtfError> {
+    let term = Term::decode(&mut bytes).map_err(EtfError::Decode)?;
+
+    T::deserialize(TermDeserializer::new(term))
+}
+
+/// Encodes a model or payload to ETF, such as to send over the gateway.
+///
+/// # Errors
+///
+/// Returns [`EtfError::Model`] if the value could not be serialized.
+///
+/// [`EtfError::Model`]: enum.EtfError.html#variant.Model
+pub fn to_vec<T: Serialize>(value: &T) -> StdResult<Vec<u8>, EtfError> {
+    let value = serde_json::to_value(value).map_err(EtfError::Model)?;
+    let mut bytes = Vec::new();
+
+    value_to_term(value).encode(&mut bytes).map_err(EtfError::Encode)?;
+
+    Ok(bytes)
+}
+
+/// Converts a term received from Discord into its JSON equivalent.
+///
+/// # Errors
+///
+/// Returns [`EtfError::UnsupportedTerm`] if the term contains a type that
+/// Discord does not send.
+///
+/// [`EtfError::UnsupportedTerm`]: enum.EtfError.html#variant.UnsupportedTerm
+pub fn term_to_value(term: Term) -> StdResult<Value, EtfError> {
+    Ok(match term {
+        Term::Atom(atom) => match &*atom.name {
+            "nil" => Value::Null,
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => Value::String(atom.name),
+        },
+        Term::FixInteger(int) => Value::from(int.value),
+        Term::BigInteger(int) => {
+            let digits = int.value.to_string();
+
+            match digits.parse::<u64>() {
+                Ok(n) => Value::from(n),
+                Err(_) => match digits.parse::<i64>() {
+                    Ok(n) => Value::from(n),
+                    Err(_) => return Err(EtfError::UnsupportedTerm),
+                },
+            }
+        },
+        Term::Float(float) => Number::from_f64(float.value)
+            .map(Value::Number)
+            .unwrap_or(Value::Null),
+        Term::Binary(binary) => Value::String(binary_to_string(binary)),
+        Term::ByteList(list) => {
+            Value::Array(list.bytes.into_iter().map(Value::from).collect())
+        },
+        Term::List(list) => Value::Array(terms_to_values(list.elements)?),
+        Term::Tuple(tuple) => Value::Array(terms_to_values(tuple.elements)?),
+        Term::Map(map) => {
+            let mut object = JsonMap::with_capacity(map.entries.len());
+
+            for (key, value) in map.entries {
+                object.insert(map_key(key)?, term_to_value(value)?);
+            }
+
+            Value::Object(object)
+        },
+        _ => return Err(EtfError::UnsupportedTerm),
+    })
+}
+
+fn terms_to_values(terms: Vec<Term>) -> StdResult<Vec<Value>, EtfError> {
+    terms.into_iter().map(term_to_value).collect()
+}
+
+/// Converts a JSON value into the term that Discord expects in its place.
+pub fn value_to_term(value: Value) -> Term {
+    match value {
+        Value::Null => Term::from(Atom::from("nil")),
+        Value::Bool(true) => Term::from(Atom::from("true")),
+        Value::Bool(false) => Term::from(Atom::from("false")),
+        Value::Number(number) => number_to_term(&number),
+        Value::String(string) => Term::from(Binary::from(string.into_bytes())),
+        Value::Array(values) => {
+            Term::from(List::from(values.into_iter().map(value_to_term).collect::<Vec<_>>()))
+        },
+        Value::Object(object) => {
+            let entries = object
+                .into_iter()
+                .map(|(key, value)| {
+                    (Term::from(Binary::from(key.into_bytes())), value_to_term(value))
+                })
+                .collect::<Vec<_>>();
+
+            Term::from(Map::from(entries))
+        },
+    }
+}
+
+fn number_to_term(number: &Number) -> Term {
+    if let Some(n) = number.as_i64() {
+        if n >= i64::from(i32::min_value()) && n <= i64::from(i32::max_value()) {
+            return Term::from(FixInteger::from(n as i32));
+        }
+
+        return Term::from(BigInteger::from(n));
+    }
+
+    if let Some(n) = number.as_u64() {
+        return Term::from(BigInteger::from(n));
+    }
+
+    Term::from(Float::from(number.as_f64().unwrap_or(0.0)))
+}
+
+/// A deserializer of models from a term received from Discord, reading the
+/// term in the same shape as its JSON counterpart.
+///
+/// Atoms other than `nil`, `true`, and `false`, and binaries, are read as
+/// strings, and big integers as 64-bit integers.
+pub struct TermDeserializer {
+    term: Term,
+}
+
+impl TermDeserializer {
+    /// Creates a deserializer of the given term.
+    pub fn new(term: Term) -> Self {
+        TermDeserializer {
+            term: term,
+        }
+    }
+}
+
+impl<'de> Deserializer<'de> for TermDeserializer {
+    type Error = EtfError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, EtfError> {
+        match self.term {
+            Term::Atom(atom) => match &*atom.name {
+                "nil" => visitor.visit_unit(),
+                "true" => visitor.visit_bool(true),
+                "false" => visitor.visit_bool(false),
+                _ => visitor.visit_string(atom.name),
+            },
+            Term::FixInteger(int) => if int.value < 0 {
+                visitor.visit_i64(i64::from(int.value))
+            } else {
+                visitor.visit_u64(int.value as u64)
+            },
+            Term::BigInteger(int) => {
+                let digits = int.value.to_string();
+
+                match digits.parse::<u64>() {
+                    Ok(n) => visitor.visit_u64(n),
+                    Err(_) => match digits.parse::<i64>() {
+                        Ok(n) => visitor.visit_i64(n),
+                        Err(_) => Err(EtfError::UnsupportedTerm),
+                    },
+                }
+            },
+            Term::Float(float) => visitor.visit_f64(float.value),
+            Term::Binary(binary) => visitor.visit_string(binary_to_string(binary)),
+            Term::ByteList(list) => {
+                let terms = list.bytes
+                    .into_iter()
+                    .map(|byte| Term::from(FixInteger::from(i32::from(byte))))
+                    .collect::<Vec<_>>();
+
+                visitor.visit_seq(TermSeq::new(terms))
+            },
+            Term::List(list) => visitor.visit_seq(TermSeq::new(list.elements)),
+            Term::Tuple(tuple) => visitor.visit_seq(TermSeq::new(tuple.elements)),
+            Term::Map(map) => visitor.visit_map(TermMap::new(map.entries)),
+            _ => Err(EtfError::UnsupportedTerm),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, EtfError> {
+        match self.term {
+            Term::Atom(ref atom) if atom.name == "nil" => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V)
+        -> StdResult<V::Value, EtfError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, EtfError> {
+        match self.term {
+            Term::Binary(binary) => visitor.visit_byte_buf(binary.bytes),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, EtfError> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> StdResult<V::Value, EtfError> {
+        match self.term {
+            // Unit variants are sent as strings, and other variants as a map
+            // of the variant's name to its value.
+            Term::Atom(atom) => visitor.visit_enum(atom.name.into_deserializer()),
+            Term::Binary(binary) => visitor.visit_enum(binary_to_string(binary).into_deserializer()),
+            Term::Map(map) => {
+                let mut entries = map.entries.into_iter();
+
+                match (entries.next(), entries.next()) {
+                    (Some((variant, value)), None) => visitor.visit_enum(TermEnum {
+                        value: value,
+                        variant: map_key(variant)?,
+                    }),
+                    _ => Err(EtfError::custom("expected a map with a single key")),
+                }
+            },
+            _ => Err(EtfError::custom("expected an enum")),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V)
+        -> StdResult<V::Value, EtfError> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string
+        unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+struct TermSeq {
+    terms: IntoIter<Term>,
+}
+
+impl TermSeq {
+    fn new(terms: Vec<Term>) -> Self {
+        TermSeq {
+            terms: terms.into_iter(),
+        }
+    }
+}
+
+impl<'de> SeqAccess<'de> for TermSeq {
+    type Error = EtfError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T)
+        -> StdResult<Option<T::Value>, EtfError> {
+        match self.terms.next() {
+            Some(term) => seed.deserialize(TermDeserializer::new(term)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> { Some(self.terms.len()) }
+}
+
+struct TermMap {
+    entries: IntoIter<(Term, Term)>,
+    value: Option<Term>,
+}
+
+impl TermMap {
+    fn new(entries: Vec<(Term, Term)>) -> Self {
+        TermMap {
+            entries: entries.into_iter(),
+            value: None,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for TermMap {
+    type Error = EtfError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K)
+        -> StdResult<Option<K::Value>, EtfError> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+
+                seed.deserialize(map_key(key)?.into_deserializer()).map(Some)
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V)
+        -> StdResult<V::Value, EtfError> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(TermDeserializer::new(value)),
+            None => Err(EtfError::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> { Some(self.entries.len()) }
+}
+
+struct TermEnum {
+    value: Term,
+    variant: String,
+}
+
+impl<'de> EnumAccess<'de> for TermEnum {
+    type Error = EtfError;
+    type Variant = TermDeserializer;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V)
+        -> StdResult<(V::Value, TermDeserializer), EtfError> {
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+
+        Ok((variant, TermDeserializer::new(self.value)))
+    }
+}
+
+impl<'de> VariantAccess<'de> for TermDeserializer {
+    type Error = EtfError;
+
+    fn unit_variant(self) -> StdResult<(), EtfError> { Deserialize::deserialize(self) }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T)
+        -> StdResult<T::Value, EtfError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V)
+        -> StdResult<V::Value, EtfError> {
+        self.deserialize_any(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(self, _: &'static [&'static str], visitor: V)
+        -> StdResult<V::Value, EtfError> {
+        self.deserialize_any(visitor)
+    }
+}
+
+fn binary_to_string(binary: Binary) -> String {
+    String::from_utf8(binary.bytes)
+        .unwrap_or_else(|why| String::from_utf8_lossy(why.as_bytes()).into_owned())
+}
+
+/// Reads the key of a map as a string, as Discord sends keys as either atoms
+/// or binaries.
+fn map_key(key: Term) -> StdResult<String, EtfError> {
+    Ok(match key {
+        Term::Atom(atom) => atom.name,
+        Term::Binary(binary) => binary_to_string(binary),
+        Term::FixInteger(int) => int.value.to_string(),
+        Term::BigInteger(int) => int.value.to_string(),
+        _ => return Err(EtfError::UnsupportedTerm),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use eetf::{Atom, BigInteger, Binary, FixInteger, Map, Term};
+    use serde_json::{self, Value};
+    use super::super::fixtures::support::{assert_round_trip, guild};
+    use super::super::fixtures::GUILD_CREATE;
+    use super::super::UserId;
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Sample {
+        big: i64,
+        flag: bool,
+        id: UserId,
+        missing: Option<String>,
+        name: String,
+        small: u64,
+        unset: bool,
+    }
+
+    fn atom(name: &str) -> Term { Term::from(Atom::from(name)) }
+
+    fn binary(text: &str) -> Term { Term::from(Binary::from(text.as_bytes().to_vec())) }
+
+    fn encode(term: &Term) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        term.encode(&mut bytes).unwrap();
+
+        bytes
+    }
+
+    #[test]
+    fn decode_discord_terms() {
+        // Keys are sent as both atoms and binaries.
+        let term = Term::from(Map::from(vec![
+            (atom("big"), Term::from(BigInteger::from(-4_000_000_000i64))),
+            (binary("flag"), atom("true")),
+            (atom("id"), Term::from(BigInteger::from(381880458150920192u64))),
+            (atom("missing"), atom("nil")),
+            (atom("name"), binary("zeyla")),
+            (binary("small"), Term::from(FixInteger::from(42))),
+            (atom("unset"), atom("false")),
+        ]));
+
+        assert_eq!(from_slice::<Sample>(&encode(&term)).unwrap(), Sample {
+            big: -4_000_000_000,
+            flag: true,
+            id: UserId(381880458150920192),
+            missing: None,
+            name: "zeyla".to_string(),
+            small: 42,
+            unset: false,
+        });
+    }
+
+    #[test]
+    fn sample_round_trip() {
+        let sample = Sample {
+            big: i64::min_value(),
+            flag: false,
+            id: UserId(u64::max_value()),
+            missing: Some("here".to_string()),
+            name: "ünïcödé".to_string(),
+            small: 0,
+            unset: true,
+        };
+
+        assert_eq!(from_slice::<Sample>(&to_vec(&sample).unwrap()).unwrap(), sample);
+    }
+
+    #[test]
+    fn guild_round_trip() {
+        assert_round_trip(&guild(), |guild| from_slice(&to_vec(guild).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn matches_term_to_value() {
+        let value = serde_json::from_str::<Value>(GUILD_CREATE).unwrap();
+        let bytes = encode(&value_to_term(value.clone()));
+
+        assert_eq!(from_slice::<Value>(&bytes).unwrap(), value);
+    }
+}
@@ -1,11 +1,36 @@
 //! All the events this library handles.
 
 use chrono::{DateTime, FixedOffset};
-use serde::de::Error as DeError;
+use serde::de::{Deserialize, Deserializer, Error as DeError, Visitor};
 use serde_json::{self, Error as JsonError, Value};
-use std::collections::HashMap;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::result::Result as StdResult;
 use super::utils::deserialize_emojis;
-use super::*;
+use super::{
+    Attachment,
+    Channel,
+    ChannelId,
+    CurrentUser,
+    Emoji,
+    EmojiId,
+    Guild,
+    GuildId,
+    IdMap,
+    Member,
+    MemberFlags,
+    Message,
+    MessageId,
+    MessageType,
+    PartialGuild,
+    Presence,
+    Reaction,
+    Ready,
+    Role,
+    RoleId,
+    User,
+    UserId,
+    VoiceState,
+};
 use serenity_common::constants::{OpCode, VoiceOpCode};
 use serenity_common::prelude::*;
 
@@ -15,6 +40,8 @@ use cache::{Cache, CacheUpdate};
 use std::collections::hash_map::Entry;
 #[cfg(feature = "cache")]
 use std::mem;
+#[cfg(feature = "cache")]
+use super::{GuildStatus, OnlineStatus};
 
 /// Event data for the channel creation event.
 ///
@@ -258,7 +285,7 @@ impl CacheUpdate for ChannelUpdateEvent {
                         let mut dest = e.get_mut().write();
 
                         if no_recipients {
-                            let recipients = mem::replace(&mut dest.recipients, HashMap::new());
+                            let recipients = mem::replace(&mut dest.recipients, IdMap::default());
 
                             dest.clone_from(&group.read());
 
@@ -384,9 +411,9 @@ impl<'de> Deserialize<'de> for GuildDeleteEvent {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug)]
 pub struct GuildEmojisUpdateEvent {
-    #[serde(deserialize_with = "deserialize_emojis")] pub emojis: HashMap<EmojiId, Emoji>,
+    pub emojis: IdMap<EmojiId, Emoji>,
     pub guild_id: GuildId,
 }
 
@@ -405,6 +432,35 @@ impl CacheUpdate for GuildEmojisUpdateEvent {
     }
 }
 
+impl<'de> Deserialize<'de> for GuildEmojisUpdateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = JsonMap::deserialize(deserializer)?;
+
+        let guild_id = map.get("guild_id")
+            .ok_or_else(|| DeError::custom("missing emojis update guild id"))
+            .and_then(|v| GuildId::deserialize(v.clone()))
+            .map_err(DeError::custom)?;
+
+        let mut emojis = map.remove("emojis")
+            .ok_or_else(|| DeError::custom("missing emojis update emojis"))?;
+
+        if let Some(emojis) = emojis.as_array_mut() {
+            let num = Value::Number(Number::from(guild_id.0));
+
+            for emoji in emojis {
+                if let Some(map) = emoji.as_object_mut() {
+                    map.insert("guild_id".to_string(), num.clone());
+                }
+            }
+        }
+
+        Ok(GuildEmojisUpdateEvent {
+            emojis: deserialize_emojis(emojis).map_err(DeError::custom)?,
+            guild_id: guild_id,
+        })
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct GuildIntegrationsUpdateEvent {
     pub guild_id: GuildId,
@@ -478,8 +534,15 @@ impl CacheUpdate for GuildMemberRemoveEvent {
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct GuildMemberUpdateEvent {
+    #[serde(default)]
+    pub communication_disabled_until: Option<DateTime<FixedOffset>>,
+    #[serde(default)]
+    pub flags: MemberFlags,
     pub guild_id: GuildId,
     pub nick: Option<String>,
+    #[serde(default)]
+    pub pending: bool,
+    pub premium_since: Option<DateTime<FixedOffset>>,
     pub roles: Vec<RoleId>,
     pub user: User,
 }
@@ -499,7 +562,11 @@ impl CacheUpdate for GuildMemberUpdateEvent {
             let item = if let Some(member) = guild.members.get_mut(&self.user.id) {
                 let item = Some(member.clone());
 
+                member.communication_disabled_until = self.communication_disabled_until;
+                member.flags = self.flags;
                 member.nick.clone_from(&self.nick);
+                member.pending = self.pending;
+                member.premium_since = self.premium_since;
                 member.roles.clone_from(&self.roles);
                 member.user.write().clone_from(&self.user);
 
@@ -514,11 +581,15 @@ impl CacheUpdate for GuildMemberUpdateEvent {
                 guild.members.insert(
                     self.user.id,
                     Member {
+                        communication_disabled_until: self.communication_disabled_until,
                         deaf: false,
+                        flags: self.flags,
                         guild_id: self.guild_id,
                         joined_at: None,
                         mute: false,
                         nick: self.nick.clone(),
+                        pending: self.pending,
+                        premium_since: self.premium_since,
                         roles: self.roles.clone(),
                         user: Arc::new(RwLock::new(self.user.clone())),
                     },
@@ -535,7 +606,7 @@ impl CacheUpdate for GuildMemberUpdateEvent {
 #[derive(Clone, Debug)]
 pub struct GuildMembersChunkEvent {
     pub guild_id: GuildId,
-    pub members: HashMap<UserId, Member>,
+    pub members: IdMap<UserId, Member>,
 }
 
 #[cfg(feature = "cache")]
@@ -577,7 +648,7 @@ impl<'de> Deserialize<'de> for GuildMembersChunkEvent {
             }
         }
 
-        let members: HashMap<UserId, Member> =
+        let members: IdMap<UserId, Member> =
             Deserialize::deserialize(members).map_err(DeError::custom)?;
 
         Ok(GuildMembersChunkEvent {
@@ -675,18 +746,7 @@ impl CacheUpdate for GuildUpdateEvent {
     type Output = ();
 
     fn update(&mut self, cache: &mut Cache) -> Option<()> {
-        cache.guilds.get_mut(&self.guild.id).map(|guild| {
-            let mut guild = guild.write();
-
-            guild.afk_timeout = self.guild.afk_timeout;
-            guild.afk_channel_id.clone_from(&self.guild.afk_channel_id);
-            guild.icon.clone_from(&self.guild.icon);
-            guild.name.clone_from(&self.guild.name);
-            guild.owner_id.clone_from(&self.guild.owner_id);
-            guild.region.clone_from(&self.guild.region);
-            guild.roles.clone_from(&self.guild.roles);
-            guild.verification_level = self.guild.verification_level;
-        });
+        cache.guilds.get_mut(&self.guild.id).map(|guild| guild.write().apply_update(self));
 
         None
     }
@@ -729,6 +789,7 @@ pub struct MessageDeleteEvent {
 pub struct MessageUpdateEvent {
     pub id: MessageId,
     pub channel_id: ChannelId,
+    pub guild_id: Option<GuildId>,
     pub kind: Option<MessageType>,
     pub content: Option<String>,
     pub nonce: Option<String>,
@@ -824,7 +885,7 @@ impl CacheUpdate for PresencesReplaceEvent {
 
     fn update(&mut self, cache: &mut Cache) -> Option<()> {
         cache.presences.extend({
-            let mut p: HashMap<UserId, Presence> = HashMap::default();
+            let mut p: IdMap<UserId, Presence> = IdMap::default();
 
             for presence in &self.presences {
                 p.insert(presence.user_id, presence.clone());
@@ -854,8 +915,19 @@ pub struct ReactionAddEvent {
 
 impl<'de> Deserialize<'de> for ReactionAddEvent {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = JsonMap::deserialize(deserializer)?;
+
+        // The member is sent without its guild's Id, so insert it for the
+        // member to be deserializable.
+        if let Some(guild_id) = map.get("guild_id").cloned() {
+            if let Some(member) = map.get_mut("member").and_then(|x| x.as_object_mut()) {
+                member.insert("guild_id".to_string(), guild_id);
+            }
+        }
+
         Ok(Self {
-            reaction: Reaction::deserialize(deserializer)?,
+            reaction: Reaction::deserialize(Value::Object(map))
+                .map_err(DeError::custom)?,
         })
     }
 }
@@ -876,6 +948,7 @@ impl<'de> Deserialize<'de> for ReactionRemoveEvent {
 #[derive(Clone, Copy, Debug, Deserialize)]
 pub struct ReactionRemoveAllEvent {
     pub channel_id: ChannelId,
+    pub guild_id: Option<GuildId>,
     pub message_id: MessageId,
 }
 
@@ -973,7 +1046,10 @@ impl<'de> Deserialize<'de> for UserUpdateEvent {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+/// The voice [`token`] is redacted from the `Debug` output.
+///
+/// [`token`]: #structfield.token
+#[derive(Clone, Deserialize)]
 pub struct VoiceServerUpdateEvent {
     pub channel_id: Option<ChannelId>,
     pub endpoint: Option<String>,
@@ -981,6 +1057,38 @@ pub struct VoiceServerUpdateEvent {
     pub token: String,
 }
 
+impl Debug for VoiceServerUpdateEvent {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("VoiceServerUpdateEvent")
+            .field("channel_id", &self.channel_id)
+            .field("endpoint", &self.endpoint)
+            .field("guild_id", &self.guild_id)
+            .field("token", &"[redacted]")
+            .finish()
+    }
+}
+
+/// Event data for a voice channel's status being set or cleared.
+#[derive(Clone, Debug, Deserialize)]
+pub struct VoiceChannelStatusUpdateEvent {
+    pub guild_id: GuildId,
+    pub id: ChannelId,
+    pub status: Option<String>,
+}
+
+#[cfg(feature = "cache")]
+impl CacheUpdate for VoiceChannelStatusUpdateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &mut Cache) -> Option<()> {
+        if let Some(channel) = cache.channels.get_mut(&self.id) {
+            channel.write().status = self.status.clone();
+        }
+
+        None
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct VoiceStateUpdateEvent {
     pub guild_id: Option<GuildId>,
@@ -1123,6 +1231,79 @@ impl<'de> Deserialize<'de> for GatewayEvent {
     }
 }
 
+/// A raw payload received over a gateway connection, with its data left as
+/// the given type.
+///
+/// This is the frame that every [`GatewayEvent`] is received within. Use a
+/// [`Value`] as the data type to inspect the payload before deserializing it,
+/// such as to record its sequence before converting it into a
+/// [`DispatchEnvelope`].
+///
+/// [`DispatchEnvelope`]: struct.DispatchEnvelope.html
+/// [`GatewayEvent`]: enum.GatewayEvent.html
+/// [`Value`]: https://docs.rs/serde_json/*/serde_json/enum.Value.html
+#[derive(Clone, Debug, Deserialize)]
+pub struct GatewayPayload<T> {
+    /// The opcode of the payload.
+    pub op: OpCode,
+    /// The data of the payload.
+    pub d: T,
+    /// The sequence number of the payload, only present for dispatches.
+    #[serde(default)]
+    pub s: Option<u64>,
+    /// The type of event dispatched, only present for dispatches.
+    #[serde(default)]
+    pub t: Option<EventType>,
+}
+
+/// A dispatched [`Event`] alongside the metadata needed to resume the session
+/// it was received on.
+///
+/// Resuming a session requires the last sequence number received on each
+/// shard, which can be tracked by recording the [`seq`] of each envelope by
+/// its [`shard`].
+///
+/// [`Event`]: enum.Event.html
+/// [`seq`]: #structfield.seq
+/// [`shard`]: #structfield.shard
+#[derive(Clone, Debug)]
+pub struct DispatchEnvelope {
+    /// The sequence number of the dispatch.
+    pub seq: u64,
+    /// The Id of the shard that the dispatch was received on.
+    pub shard: u64,
+    /// The event that was dispatched.
+    pub event: Event,
+}
+
+impl DispatchEnvelope {
+    /// Deserializes the event of a dispatch payload received on the given
+    /// shard.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is not a dispatch, is missing its
+    /// sequence or event type, or its data does not match the event type.
+    pub fn from_payload(shard: u64, payload: GatewayPayload<Value>)
+        -> StdResult<Self, JsonError> {
+        match payload.op {
+            OpCode::Event => {},
+            _ => return Err(JsonError::custom("expected a dispatch payload")),
+        }
+
+        let seq = payload.s
+            .ok_or_else(|| JsonError::custom("expected gateway event sequence"))?;
+        let kind = payload.t
+            .ok_or_else(|| JsonError::custom("expected gateway event type"))?;
+
+        Ok(DispatchEnvelope {
+            seq: seq,
+            shard: shard,
+            event: deserialize_event_with_type(kind, payload.d)?,
+        })
+    }
+}
+
 /// Event received over a websocket connection
 #[cfg_attr(feature = "cargo-clippy", allow(large_enum_variant))]
 #[derive(Clone, Debug, Deserialize)]
@@ -1226,6 +1407,8 @@ pub enum Event {
     TypingStart(TypingStartEvent),
     /// Update to the logged-in user's information
     UserUpdate(UserUpdateEvent),
+    /// A voice channel's status was set or cleared
+    VoiceChannelStatusUpdate(VoiceChannelStatusUpdateEvent),
     /// A member's voice state has changed
     VoiceStateUpdate(VoiceStateUpdateEvent),
     /// Voice server information is available
@@ -1351,6 +1534,9 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value)
         EventType::Resumed => Event::Resumed(serde_json::from_value(v)?),
         EventType::TypingStart => Event::TypingStart(serde_json::from_value(v)?),
         EventType::UserUpdate => Event::UserUpdate(serde_json::from_value(v)?),
+        EventType::VoiceChannelStatusUpdate => {
+            Event::VoiceChannelStatusUpdate(serde_json::from_value(v)?)
+        },
         EventType::VoiceServerUpdate => {
             Event::VoiceServerUpdate(serde_json::from_value(v)?)
         },
@@ -1580,6 +1766,12 @@ pub enum EventType {
     ///
     /// [`UserUpdateEvent`]: struct.UserUpdateEvent.html
     UserUpdate,
+    /// Indicator that a voice channel status update payload was received.
+    ///
+    /// This maps to [`VoiceChannelStatusUpdateEvent`].
+    ///
+    /// [`VoiceChannelStatusUpdateEvent`]: struct.VoiceChannelStatusUpdateEvent.html
+    VoiceChannelStatusUpdate,
     /// Indicator that a voice state payload was received.
     ///
     /// This maps to [`VoiceStateUpdateEvent`].
@@ -1653,6 +1845,7 @@ impl<'de> Deserialize<'de> for EventType {
                     "RESUMED" => EventType::Resumed,
                     "TYPING_START" => EventType::TypingStart,
                     "USER_UPDATE" => EventType::UserUpdate,
+                    "VOICE_CHANNEL_STATUS_UPDATE" => EventType::VoiceChannelStatusUpdate,
                     "VOICE_SERVER_UPDATE" => EventType::VoiceServerUpdate,
                     "VOICE_STATE_UPDATE" => EventType::VoiceStateUpdate,
                     "WEBHOOKS_UPDATE" => EventType::WebhookUpdate,
@@ -0,0 +1,231 @@
+//! Recorded API payloads, for use in the deserialization tests of downstream
+//! crates.
+//!
+//! Each constant is the JSON body of a real gateway event or REST response,
+//! with its Ids, names, and tokens scrubbed. The payloads mix the encodings
+//! Discord has used over time, such as Ids and permissions given both as
+//! strings and as integers, so that models are checked against both:
+//!
+//! ```rust,ignore
+//! use serenity_model::fixtures;
+//! use serenity_model::Guild;
+//!
+//! let guild = serde_json::from_str::<Guild>(fixtures::GUILD_CREATE)?;
+//! assert_eq!(guild.members.len(), 2);
+//! ```
+//!
+//! Payloads of arbitrary size can be generated for benchmarks, such as with
+//! [`guild_create`].
+//!
+//! This module is only available with the `fixtures` feature enabled.
+//!
+//! [`guild_create`]: fn.guild_create.html
+
+use serde_json::{self, Value};
+use serenity_common::prelude::JsonMap;
+
+/// The data of a `GUILD_CREATE` gateway event, which deserializes into a
+/// [`Guild`].
+///
+/// [`Guild`]: ../struct.Guild.html
+pub const GUILD_CREATE: &'static str = include_str!("../fixtures/guild_create.json");
+
+/// A message from a guild channel mentioning a bot, which deserializes into a
+/// [`Message`].
+///
+/// [`Message`]: ../struct.Message.html
+pub const MESSAGE: &'static str = include_str!("../fixtures/message.json");
+
+/// A guild's audit log with a ban, a channel update, and a voice member move,
+/// which deserializes into [`AuditLogs`].
+///
+/// [`AuditLogs`]: ../struct.AuditLogs.html
+pub const AUDIT_LOG: &'static str = include_str!("../fixtures/audit_log.json");
+
+/// An autocomplete interaction for a chat input command, which deserializes
+/// into a [`CommandAutocompleteInteraction`].
+///
+/// [`CommandAutocompleteInteraction`]: ../struct.CommandAutocompleteInteraction.html
+pub const COMMAND_AUTOCOMPLETE_INTERACTION: &'static str =
+    include_str!("../fixtures/command_autocomplete_interaction.json");
+
+/// The data of a `PRESENCE_UPDATE` gateway event for a member playing a game,
+/// which deserializes into a [`PresenceUpdateEvent`].
+///
+/// [`PresenceUpdateEvent`]: ../event/struct.PresenceUpdateEvent.html
+pub const PRESENCE_UPDATE: &'static str = include_str!("../fixtures/presence_update.json");
+
+/// Generates the data of a `GUILD_CREATE` gateway event for a guild with the
+/// given number of members, each with an online presence.
+///
+/// The rest of the guild is that of [`GUILD_CREATE`]. This is intended for
+/// benchmarking the deserialization of large guilds, and so is not cheap.
+///
+/// [`GUILD_CREATE`]: constant.GUILD_CREATE.html
+pub fn guild_create(member_count: u64) -> String {
+    let mut guild = match serde_json::from_str::<Value>(GUILD_CREATE) {
+        Ok(Value::Object(guild)) => guild,
+        _ => unreachable!("the GUILD_CREATE fixture is an object"),
+    };
+
+    let mut members = Vec::with_capacity(member_count as usize);
+    let mut presences = Vec::with_capacity(member_count as usize);
+
+    for i in 0..member_count {
+        let user_id = (SYNTHETIC_USER_ID_BASE + i).to_string();
+
+        let mut user = JsonMap::new();
+        user.insert("avatar".to_string(), Value::Null);
+        user.insert("discriminator".to_string(), Value::from(format!("{:04}", i % 10_000)));
+        user.insert("id".to_string(), Value::from(user_id.clone()));
+        user.insert("username".to_string(), Value::from(format!("member{}", i)));
+
+        let mut member = JsonMap::new();
+        member.insert("deaf".to_string(), Value::from(false));
+        member.insert("joined_at".to_string(), Value::from("2017-11-18T20:31:52.456000+00:00"));
+        member.insert("mute".to_string(), Value::from(false));
+        member.insert("nick".to_string(), Value::Null);
+        member.insert("premium_since".to_string(), Value::Null);
+        member.insert("roles".to_string(), Value::Array(vec![]));
+        member.insert("user".to_string(), Value::Object(user));
+        members.push(Value::Object(member));
+
+        let mut presence_user = JsonMap::new();
+        presence_user.insert("id".to_string(), Value::from(user_id));
+
+        let mut presence = JsonMap::new();
+        presence.insert("game".to_string(), Value::Null);
+        presence.insert("status".to_string(), Value::from("online"));
+        presence.insert("user".to_string(), Value::Object(presence_user));
+        presences.push(Value::Object(presence));
+    }
+
+    guild.insert("large".to_string(), Value::from(member_count > 250));
+    guild.insert("member_count".to_string(), Value::from(member_count));
+    guild.insert("members".to_string(), Value::Array(members));
+    guild.insert("presences".to_string(), Value::Array(presences));
+
+    Value::Object(guild).to_string()
+}
+
+/// The first user Id given to the members of [`guild_create`], chosen so as
+/// not to collide with the Ids in the fixtures.
+///
+/// [`guild_create`]: fn.guild_create.html
+const SYNTHETIC_USER_ID_BASE: u64 = 500_000_000_000_000_000;
+
+/// Helpers shared by the tests of models that are converted to a format and
+/// back.
+#[cfg(test)]
+pub(crate) mod support {
+    use serde::ser::Serialize;
+    use serde_json::{self, Value};
+    use super::super::Guild;
+    use super::GUILD_CREATE;
+
+    /// The guild of the [`GUILD_CREATE`] fixture.
+    ///
+    /// [`GUILD_CREATE`]: ../constant.GUILD_CREATE.html
+    pub fn guild() -> Guild { serde_json::from_str(GUILD_CREATE).unwrap() }
+
+    /// Serializes a model to JSON, so that models which do not implement
+    /// `PartialEq` can be compared.
+    pub fn to_json<T: Serialize>(model: &T) -> Value { serde_json::to_value(model).unwrap() }
+
+    /// Converts a model to a format and back with `round_trip`, checking that
+    /// nothing changes along the way.
+    pub fn assert_round_trip<T, F>(model: &T, round_trip: F)
+        where T: Serialize, F: FnOnce(&T) -> T {
+        assert_eq!(to_json(&round_trip(model)), to_json(model));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use super::super::event::PresenceUpdateEvent;
+    use super::super::{
+        AuditLogs,
+        ChannelId,
+        CommandAutocompleteInteraction,
+        Guild,
+        GuildId,
+        Message,
+        OnlineStatus,
+        UserId,
+    };
+    use super::support::{assert_round_trip, guild, to_json};
+    use super::*;
+
+    fn assert_json_round_trip(guild: &Guild) {
+        assert_round_trip(guild, |guild| serde_json::from_value(to_json(guild)).unwrap());
+    }
+
+    #[test]
+    fn guild_create() {
+        let guild = guild();
+
+        assert_eq!(guild.id, GuildId(381880193251409931));
+        assert_eq!(guild.channels.len(), 2);
+        assert_eq!(guild.emojis.len(), 1);
+        assert_eq!(guild.members.len(), 2);
+        assert_eq!(guild.presences.len(), 1);
+        assert_eq!(guild.roles.len(), 2);
+        assert_eq!(guild.voice_states.len(), 1);
+        assert_eq!(guild.system_channel_id, Some(ChannelId(381880193700069377)));
+    }
+
+    #[test]
+    fn guild_create_round_trip() {
+        assert_json_round_trip(&guild());
+    }
+
+    #[test]
+    fn generated_guild_create_round_trip() {
+        let guild = serde_json::from_str::<Guild>(&super::guild_create(300)).unwrap();
+
+        assert!(guild.large);
+        assert_eq!(guild.members.len(), 300);
+        assert_eq!(guild.presences.len(), 300);
+        assert_json_round_trip(&guild);
+    }
+
+    #[test]
+    fn message() {
+        let message = serde_json::from_str::<Message>(MESSAGE).unwrap();
+
+        assert_eq!(message.channel_id, ChannelId(381880193700069377));
+        assert_eq!(message.guild_id, Some(GuildId(381880193251409931)));
+        assert_eq!(message.mentions.len(), 1);
+    }
+
+    #[test]
+    fn audit_log() {
+        let audit_log = serde_json::from_str::<AuditLogs>(AUDIT_LOG).unwrap();
+
+        assert_eq!(audit_log.entries.len(), 3);
+        assert_eq!(audit_log.users.len(), 1);
+    }
+
+    #[test]
+    fn command_autocomplete_interaction() {
+        let interaction = serde_json::from_str::<CommandAutocompleteInteraction>(
+            COMMAND_AUTOCOMPLETE_INTERACTION,
+        ).unwrap();
+
+        assert_eq!(interaction.guild_id, Some(GuildId(381880193251409931)));
+        assert!(interaction.member.is_some());
+    }
+
+    #[test]
+    fn presence_update() {
+        let value = serde_json::from_str::<Value>(PRESENCE_UPDATE).unwrap();
+        let event = serde_json::from_value::<PresenceUpdateEvent>(value).unwrap();
+
+        assert_eq!(event.guild_id, Some(GuildId(381880193251409931)));
+        assert_eq!(event.presence.status, OnlineStatus::DoNotDisturb);
+        assert!(event.presence.game.is_some());
+        assert!(event.presence.user.is_none());
+        assert_eq!(event.presence.user_id, UserId(381880458150920192));
+    }
+}
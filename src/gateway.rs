@@ -1,7 +1,21 @@
 use serde::de::Error as DeError;
 use serde_json;
 use super::utils::*;
-use super::*;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use serenity_common::prelude::{JsonMap, Value};
+use std::result::Result as StdResult;
+use std::slice::Iter;
+use super::{Channel, ChannelId, CurrentUser, GuildStatus, IdMap, OnlineStatus, User, UserId};
+
+#[cfg(feature = "compression")]
+use flate2::{Decompress, DecompressError, FlushDecompress, Status};
+#[cfg(feature = "compression")]
+use std::error::Error as StdError;
+#[cfg(feature = "compression")]
+use std::fmt::{Display, Formatter, Result as FmtResult};
+#[cfg(feature = "compression")]
+use super::event::GatewayEvent;
 
 /// A representation of the data retrieved from the bot gateway endpoint.
 ///
@@ -53,6 +67,17 @@ impl<'de> Deserialize<'de> for Game {
     }
 }
 
+impl Serialize for Game {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Game", 3)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("type", &self.kind)?;
+        state.serialize_field("url", &self.url)?;
+
+        state.end()
+    }
+}
+
 enum_number!(
     /// The type of activity that is being performed when playing a game.
     GameType {
@@ -133,7 +158,7 @@ impl<'de> Deserialize<'de> for Presence {
             None => None,
         };
         let last_modified = match map.remove("last_modified") {
-            Some(v) => Some(u64::deserialize(v).map_err(DeError::custom)?),
+            Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?,
             None => None,
         };
         let nick = match map.remove("nick") {
@@ -157,17 +182,333 @@ impl<'de> Deserialize<'de> for Presence {
     }
 }
 
+impl Serialize for Presence {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct PartialUser {
+            id: UserId,
+        }
+
+        // MessagePack writes the number of fields up front, so it must not
+        // count the skipped `last_modified`.
+        let len = if self.last_modified.is_some() { 5 } else { 4 };
+
+        let mut state = serializer.serialize_struct("Presence", len)?;
+        state.serialize_field("game", &self.game)?;
+
+        if let Some(ref last_modified) = self.last_modified {
+            state.serialize_field("last_modified", last_modified)?;
+        }
+
+        state.serialize_field("nick", &self.nick)?;
+        state.serialize_field("status", &self.status)?;
+
+        match self.user {
+            Some(ref user) => state.serialize_field("user", user)?,
+            None => state.serialize_field("user", &PartialUser { id: self.user_id })?,
+        }
+
+        state.end()
+    }
+}
+
 /// An initial set of information given after IDENTIFYing to the gateway.
 #[derive(Clone, Debug, Deserialize)]
 pub struct Ready {
     pub guilds: Vec<GuildStatus>,
-    #[serde(deserialize_with = "deserialize_presences")] pub presences: HashMap<UserId, Presence>,
+    #[serde(deserialize_with = "deserialize_presences")] pub presences: IdMap<UserId, Presence>,
     #[serde(deserialize_with = "deserialize_private_channels")]
     pub private_channels:
-        HashMap<ChannelId, Channel>,
+        IdMap<ChannelId, Channel>,
     pub session_id: String,
     pub shard: Option<[u64; 2]>,
     #[serde(default, rename = "_trace")] pub trace: Vec<String>,
     pub user: CurrentUser,
     #[serde(rename = "v")] pub version: u64,
 }
+
+impl Ready {
+    /// Returns an iterator over the guilds that belong to a shard, out of the
+    /// given total number of shards.
+    ///
+    /// This is useful for handing the guilds of a single shard to the process
+    /// that owns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics when advanced if `total_shards` is `0`.
+    pub fn guilds_for_shard(&self, shard_id: u64, total_shards: u64) -> ShardGuilds {
+        ShardGuilds {
+            guilds: self.guilds.iter(),
+            shard_id: shard_id,
+            total_shards: total_shards,
+        }
+    }
+
+    /// Splits the guilds by the shard that they belong to, out of the given
+    /// total number of shards.
+    ///
+    /// The guilds of each shard are found at the index of the shard's Id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `total_shards` is `0`.
+    pub fn partition_guilds(&self, total_shards: u64) -> Vec<Vec<&GuildStatus>> {
+        let mut shards = vec![Vec::new(); total_shards as usize];
+
+        for guild in &self.guilds {
+            shards[guild.shard_id(total_shards) as usize].push(guild);
+        }
+
+        shards
+    }
+}
+
+/// An iterator over the guilds of a [`Ready`] that belong to a single shard.
+///
+/// This is returned by [`Ready::guilds_for_shard`].
+///
+/// [`Ready`]: struct.Ready.html
+/// [`Ready::guilds_for_shard`]: struct.Ready.html#method.guilds_for_shard
+#[derive(Clone, Debug)]
+pub struct ShardGuilds<'a> {
+    guilds: Iter<'a, GuildStatus>,
+    shard_id: u64,
+    total_shards: u64,
+}
+
+impl<'a> Iterator for ShardGuilds<'a> {
+    type Item = &'a GuildStatus;
+
+    fn next(&mut self) -> Option<&'a GuildStatus> {
+        let (shard_id, total_shards) = (self.shard_id, self.total_shards);
+
+        self.guilds.find(|guild| guild.shard_id(total_shards) == shard_id)
+    }
+}
+
+/// The suffix that ends every complete message of a `zlib-stream` compressed
+/// gateway connection.
+#[cfg(feature = "compression")]
+pub const ZLIB_SUFFIX: [u8; 4] = [0x00, 0x00, 0xFF, 0xFF];
+
+/// The decompression state of a gateway connection opened with
+/// `compress=zlib-stream`.
+///
+/// A single zlib context is shared by every message of the connection, and
+/// messages may be split across multiple websocket frames. Frames are pushed
+/// in as they are received, and each complete message is inflated and
+/// deserialized:
+///
+/// ```rust,ignore
+/// use serenity_model::Compression;
+///
+/// let mut compression = Compression::new();
+///
+/// for frame in frames {
+///     if let Some(event) = compression.push(&frame)? {
+///         // handle the event
+///     }
+/// }
+/// ```
+///
+/// A new `Compression` must be used for each connection, including after
+/// resuming, as the zlib context is not shared between connections.
+#[cfg(feature = "compression")]
+pub struct Compression {
+    buffer: Vec<u8>,
+    inflater: Decompress,
+    output: Vec<u8>,
+}
+
+#[cfg(feature = "compression")]
+impl Compression {
+    /// Creates the decompression state for a new connection.
+    pub fn new() -> Self {
+        Compression {
+            buffer: Vec::new(),
+            inflater: Decompress::new(true),
+            output: Vec::new(),
+        }
+    }
+
+    /// Whether a buffer ends with the [`ZLIB_SUFFIX`], and so contains the
+    /// end of a message.
+    ///
+    /// [`ZLIB_SUFFIX`]: constant.ZLIB_SUFFIX.html
+    pub fn is_complete(buffer: &[u8]) -> bool { buffer.ends_with(&ZLIB_SUFFIX) }
+
+    /// Pushes a frame received from the gateway, returning the event it
+    /// completes, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompressionError::Inflate`] if the message could not be
+    /// inflated, after which the connection must be closed, or
+    /// [`CompressionError::Json`] if the inflated message is not a valid
+    /// event.
+    ///
+    /// [`CompressionError::Inflate`]: enum.CompressionError.html#variant.Inflate
+    /// [`CompressionError::Json`]: enum.CompressionError.html#variant.Json
+    pub fn push(&mut self, frame: &[u8]) -> StdResult<Option<GatewayEvent>, CompressionError> {
+        match self.push_raw(frame)? {
+            Some(message) => serde_json::from_slice(message)
+                .map(Some)
+                .map_err(CompressionError::Json),
+            None => Ok(None),
+        }
+    }
+
+    /// Pushes a frame received from the gateway, returning the inflated JSON
+    /// of the message it completes, if any.
+    ///
+    /// This is useful for inspecting a message before deserializing it, such
+    /// as into a [`GatewayPayload`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompressionError::Inflate`] if the message could not be
+    /// inflated, after which the connection must be closed.
+    ///
+    /// [`CompressionError::Inflate`]: enum.CompressionError.html#variant.Inflate
+    /// [`GatewayPayload`]: event/struct.GatewayPayload.html
+    pub fn push_raw(&mut self, frame: &[u8]) -> StdResult<Option<&[u8]>, CompressionError> {
+        self.buffer.extend_from_slice(frame);
+
+        if !Compression::is_complete(&self.buffer) {
+            return Ok(None);
+        }
+
+        self.output.clear();
+
+        let start = self.inflater.total_in();
+
+        loop {
+            let consumed = (self.inflater.total_in() - start) as usize;
+
+            if consumed >= self.buffer.len() && self.output.len() < self.output.capacity() {
+                break;
+            }
+
+            // `decompress_vec` only writes into spare capacity, so make room
+            // for at least as much again as the compressed input.
+            let reserve = self.buffer.len().max(1024);
+            self.output.reserve(reserve);
+
+            let (total_in, total_out) = (self.inflater.total_in(), self.inflater.total_out());
+            let status = self.inflater
+                .decompress_vec(&self.buffer[consumed..], &mut self.output, FlushDecompress::Sync)
+                .map_err(CompressionError::Inflate)?;
+
+            // Nothing can be inflated after the end of the stream, so any
+            // remaining input would otherwise be retried forever.
+            if status == Status::StreamEnd {
+                let consumed = (self.inflater.total_in() - start) as usize;
+
+                if consumed < self.buffer.len() {
+                    self.buffer.clear();
+
+                    return Err(CompressionError::StreamEnded);
+                }
+            } else if self.inflater.total_in() == total_in
+                && self.inflater.total_out() == total_out {
+                self.buffer.clear();
+
+                return Err(CompressionError::Stalled);
+            }
+        }
+
+        self.buffer.clear();
+
+        Ok(Some(&self.output))
+    }
+}
+
+#[cfg(feature = "compression")]
+impl Default for Compression {
+    fn default() -> Self { Compression::new() }
+}
+
+/// An error returned when decompressing a gateway message with
+/// [`Compression`].
+///
+/// [`Compression`]: struct.Compression.html
+#[cfg(feature = "compression")]
+#[derive(Debug)]
+pub enum CompressionError {
+    /// The message could not be inflated.
+    Inflate(DecompressError),
+    /// The inflated message was not a valid event.
+    Json(serde_json::Error),
+    /// The message could not be inflated any further, despite input
+    /// remaining.
+    Stalled,
+    /// Data was received after the end of the zlib stream.
+    StreamEnded,
+}
+
+#[cfg(feature = "compression")]
+impl Display for CompressionError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            CompressionError::Inflate(ref inner) => Display::fmt(inner, f),
+            CompressionError::Json(ref inner) => Display::fmt(inner, f),
+            CompressionError::Stalled | CompressionError::StreamEnded => {
+                f.write_str(self.description())
+            },
+        }
+    }
+}
+
+#[cfg(feature = "compression")]
+impl StdError for CompressionError {
+    fn description(&self) -> &str {
+        match *self {
+            CompressionError::Inflate(ref inner) => inner.description(),
+            CompressionError::Json(ref inner) => inner.description(),
+            CompressionError::Stalled => "Message could not be inflated any further",
+            CompressionError::StreamEnded => "Data was received after the end of the stream",
+        }
+    }
+}
+
+#[cfg(all(test, feature = "compression"))]
+mod tests {
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression as Level;
+    use std::io::Write;
+    use super::{Compression, CompressionError};
+
+    const MESSAGE: &'static [u8] = br#"{"op":11,"d":null}"#;
+
+    #[test]
+    fn inflate_messages() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Level::default());
+        let mut compression = Compression::new();
+
+        for _ in 0..2 {
+            encoder.write_all(MESSAGE).unwrap();
+            encoder.flush().unwrap();
+
+            let frame = encoder.get_mut().split_off(0);
+            let (first, second) = frame.split_at(frame.len() / 2);
+
+            assert_eq!(compression.push_raw(first).unwrap(), None);
+            assert_eq!(compression.push_raw(second).unwrap(), Some(MESSAGE));
+        }
+    }
+
+    #[test]
+    fn data_after_stream_end() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Level::default());
+        encoder.write_all(MESSAGE).unwrap();
+
+        let mut frame = encoder.finish().unwrap();
+        frame.extend_from_slice(&[0x01, 0x02, 0x00, 0x00, 0xFF, 0xFF]);
+
+        match Compression::new().push_raw(&frame) {
+            Err(CompressionError::StreamEnded) => {},
+            other => panic!("expected the stream to have ended: {:?}", other),
+        }
+    }
+}
@@ -1,6 +1,17 @@
 use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
-use super::super::{AuditLogEntryId, User, UserId, ChannelId, Webhook};
-use std::collections::HashMap;
+use serde_json::Value;
+use super::super::utils::{deserialize_option_u64, deserialize_u64};
+use super::super::{
+    ApplicationCommand,
+    AuditLogEntryId,
+    ChannelId,
+    GuildChannel,
+    IdMap,
+    User,
+    UserId,
+    Webhook,
+};
+use super::{GuildScheduledEvent, PartialIntegration};
 use std::mem::transmute;
 use std::fmt;
 
@@ -29,6 +40,7 @@ pub enum Action {
     Webhook(ActionWebhook),
     Emoji(ActionEmoji),
     MessageDelete,
+    CreatorMonetization(ActionCreatorMonetization),
 }
 
 #[derive(Debug)]
@@ -56,6 +68,9 @@ pub enum ActionMember {
     BanRemove = 23,
     Update = 24,
     RoleUpdate = 25,
+    Move = 26,
+    Disconnect = 27,
+    BotAdd = 28,
 }
 
 #[derive(Debug)]
@@ -90,19 +105,44 @@ pub enum ActionEmoji {
     Update = 62,
 }
 
-#[derive(Debug, Deserialize)]
+/// Actions taken on a guild's creator monetization, which role subscriptions
+/// are sold through.
+#[derive(Debug)]
+#[repr(u8)]
+pub enum ActionCreatorMonetization {
+    RequestCreated = 150,
+    TermsAccepted = 151,
+}
+
+/// A change to a single property of an entity.
+///
+/// The values are kept as JSON, as their type depends on the property that
+/// was changed. A value is absent when the property was unset, such as on the
+/// creation or deletion of the entity.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct Change {
     #[serde(rename = "key")] pub name: String,
-    // TODO: Change these to an actual type.
-    #[serde(rename = "old_value")] pub old: String,
-    #[serde(rename = "new_value")] pub new: String,
+    #[serde(default, rename = "old_value")] pub old: Option<Value>,
+    #[serde(default, rename = "new_value")] pub new: Option<Value>,
 }
 
 #[derive(Debug)]
 pub struct AuditLogs {
-    pub entries: HashMap<AuditLogEntryId, AuditLogEntry>,
+    pub entries: IdMap<AuditLogEntryId, AuditLogEntry>,
     pub webhooks: Vec<Webhook>,
     pub users: Vec<User>,
+    /// The application commands referenced in the audit log.
+    pub application_commands: Vec<ApplicationCommand>,
+    /// The auto moderation rules referenced in the audit log.
+    ///
+    /// These are kept as JSON, as auto moderation rules are not yet modeled.
+    pub auto_moderation_rules: Vec<Value>,
+    /// The scheduled events referenced in the audit log.
+    pub guild_scheduled_events: Vec<GuildScheduledEvent>,
+    /// The integrations referenced in the audit log.
+    pub integrations: Vec<PartialIntegration>,
+    /// The threads referenced in the audit log.
+    pub threads: Vec<GuildChannel>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -110,6 +150,7 @@ pub struct AuditLogEntry {
     /// Determines to what entity an [`action`] was used on.
     ///
     /// [`action`]: #structfield.action
+    #[serde(deserialize_with = "deserialize_u64")]
     pub target_id: u64,
     /// Determines what action was done on a [`target`]
     ///
@@ -128,23 +169,117 @@ pub struct AuditLogEntry {
     pub options: Option<Options>,
 }
 
+/// The extra information of an [`AuditLogEntry`], as sent by Discord.
+///
+/// Which fields are present depends on the entry's action. Use
+/// [`AuditLogEntry::typed_options`] to get the fields for the action.
+///
+/// [`AuditLogEntry`]: struct.AuditLogEntry.html
+/// [`AuditLogEntry::typed_options`]: struct.AuditLogEntry.html#method.typed_options
 #[derive(Debug, Deserialize)]
 pub struct Options {
     /// Number of days after which inactive members were kicked.
-    pub delete_member_days: String,
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub delete_member_days: Option<u64>,
     /// Number of members removed by the prune
-    pub members_removed: String,
-    /// Channel in which the messages were deleted
-    pub channel_id: ChannelId,
-    /// Number of deleted messages.
-    pub count: u32,
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub members_removed: Option<u64>,
+    /// Channel in which the messages were deleted, or that members were
+    /// moved to.
+    #[serde(default)]
+    pub channel_id: Option<ChannelId>,
+    /// Number of deleted messages, or of members moved or disconnected.
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub count: Option<u64>,
     /// Id of the overwritten entity
-    pub id: u64,
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub id: Option<u64>,
     /// Type of overwritten entity ("member" or "role").
-    #[serde(rename = "type")] pub kind: String,
+    #[serde(default, rename = "type")] pub kind: Option<String>,
     /// Name of the role if type is "role"
-    pub role_name: String,
+    #[serde(default)]
+    pub role_name: Option<String>,
+}
 
+/// The extra information of an [`AuditLogEntry`], typed by its action.
+///
+/// [`AuditLogEntry`]: struct.AuditLogEntry.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuditLogOptions {
+    /// Members were disconnected from voice channels.
+    MemberDisconnect {
+        /// The number of members disconnected.
+        count: u64,
+    },
+    /// Members were moved to a voice channel.
+    MemberMove {
+        /// The channel that the members were moved to.
+        channel_id: ChannelId,
+        /// The number of members moved.
+        count: u64,
+    },
+    /// Inactive members were pruned.
+    MemberPrune {
+        /// The number of days of inactivity after which members were pruned.
+        delete_member_days: u64,
+        /// The number of members removed.
+        members_removed: u64,
+    },
+    /// Messages were deleted.
+    MessageDelete {
+        /// The channel that the messages were deleted in.
+        channel_id: ChannelId,
+        /// The number of messages deleted.
+        count: u64,
+    },
+    /// A channel's permission overwrite was changed.
+    Overwrite {
+        /// The Id of the overwritten member or role.
+        id: u64,
+        /// The type of overwritten entity ("member" or "role").
+        kind: String,
+        /// The name of the role, if a role was overwritten.
+        role_name: Option<String>,
+    },
+}
+
+impl AuditLogEntry {
+    /// Retrieves the entry's [`options`] as the fields for its action.
+    ///
+    /// Returns `None` if the entry has no options, or they are missing a
+    /// field that the action requires.
+    ///
+    /// [`options`]: #structfield.options
+    pub fn typed_options(&self) -> Option<AuditLogOptions> {
+        let options = match self.options {
+            Some(ref options) => options,
+            None => return None,
+        };
+
+        match self.action {
+            Action::ChannelOverwrite(_) => Some(AuditLogOptions::Overwrite {
+                id: options.id?,
+                kind: options.kind.clone()?,
+                role_name: options.role_name.clone(),
+            }),
+            Action::Member(ActionMember::Disconnect) => Some(AuditLogOptions::MemberDisconnect {
+                count: options.count?,
+            }),
+            Action::Member(ActionMember::Move) => Some(AuditLogOptions::MemberMove {
+                channel_id: options.channel_id?,
+                count: options.count?,
+            }),
+            Action::Member(ActionMember::Prune) => Some(AuditLogOptions::MemberPrune {
+                delete_member_days: options.delete_member_days?,
+                members_removed: options.members_removed?,
+            }),
+            Action::MessageDelete => Some(AuditLogOptions::MessageDelete {
+                channel_id: options.channel_id?,
+                count: options.count?,
+            }),
+            _ => None,
+        }
+    }
 }
 
 fn deserialize_action<'de, D: Deserializer<'de>>(de: D) -> Result<Action, D::Error> {
@@ -154,7 +289,7 @@ fn deserialize_action<'de, D: Deserializer<'de>>(de: D) -> Result<Action, D::Err
         type Value = Action;
 
         fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("an integer between 1 to 72")
+            formatter.write_str("an integer between 1 to 151")
         }
 
         fn visit_u8<E: de::Error>(self, value: u8) -> Result<Action, E> {
@@ -162,15 +297,24 @@ fn deserialize_action<'de, D: Deserializer<'de>>(de: D) -> Result<Action, D::Err
                 1 => Action::GuildUpdate,
                 10...12 => Action::Channel(unsafe { transmute(value) }),
                 13...15 => Action::ChannelOverwrite(unsafe { transmute(value) }),
-                20...25 => Action::Member(unsafe { transmute(value) }),
+                20...28 => Action::Member(unsafe { transmute(value) }),
                 30...32 => Action::Role(unsafe { transmute(value) }),
                 40...42 => Action::Invite(unsafe { transmute(value) }),
                 50...52 => Action::Webhook(unsafe { transmute(value) }),
                 60...62 => Action::Emoji(unsafe { transmute(value) }),
                 72 => Action::MessageDelete,
+                150...151 => Action::CreatorMonetization(unsafe { transmute(value) }),
                 _ => return Err(E::custom(format!("Unexpected action number: {}", value))),
             })
         }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Action, E> {
+            if value > u64::from(u8::max_value()) {
+                return Err(E::custom(format!("Unexpected action number: {}", value)));
+            }
+
+            self.visit_u8(value as u8)
+        }
     }
 
     de.deserialize_u8(ActionVisitor)
@@ -184,6 +328,12 @@ impl<'de> Deserialize<'de> for AuditLogs {
             #[serde(rename = "audit_log_entries")] Entries,
             #[serde(rename = "webhooks")] Webhooks,
             #[serde(rename = "users")] Users,
+            #[serde(rename = "application_commands")] ApplicationCommands,
+            #[serde(rename = "auto_moderation_rules")] AutoModerationRules,
+            #[serde(rename = "guild_scheduled_events")] GuildScheduledEvents,
+            #[serde(rename = "integrations")] Integrations,
+            #[serde(rename = "threads")] Threads,
+            #[serde(other)] Unknown,
         }
 
         struct EntriesVisitor;
@@ -199,6 +349,11 @@ impl<'de> Deserialize<'de> for AuditLogs {
                 let mut audit_log_entries = None;
                 let mut users = None;
                 let mut webhooks = None;
+                let mut application_commands = None;
+                let mut auto_moderation_rules = None;
+                let mut guild_scheduled_events = None;
+                let mut integrations = None;
+                let mut threads = None;
 
                 while let Some(field) = map.next_key()? {
                     match field {
@@ -223,6 +378,46 @@ impl<'de> Deserialize<'de> for AuditLogs {
 
                             users = Some(map.next_value::<Vec<User>>()?);
                         },
+                        Field::ApplicationCommands => {
+                            if application_commands.is_some() {
+                                return Err(de::Error::duplicate_field("application_commands"));
+                            }
+
+                            application_commands =
+                                Some(map.next_value::<Vec<ApplicationCommand>>()?);
+                        },
+                        Field::AutoModerationRules => {
+                            if auto_moderation_rules.is_some() {
+                                return Err(de::Error::duplicate_field("auto_moderation_rules"));
+                            }
+
+                            auto_moderation_rules = Some(map.next_value::<Vec<Value>>()?);
+                        },
+                        Field::GuildScheduledEvents => {
+                            if guild_scheduled_events.is_some() {
+                                return Err(de::Error::duplicate_field("guild_scheduled_events"));
+                            }
+
+                            guild_scheduled_events =
+                                Some(map.next_value::<Vec<GuildScheduledEvent>>()?);
+                        },
+                        Field::Integrations => {
+                            if integrations.is_some() {
+                                return Err(de::Error::duplicate_field("integrations"));
+                            }
+
+                            integrations = Some(map.next_value::<Vec<PartialIntegration>>()?);
+                        },
+                        Field::Threads => {
+                            if threads.is_some() {
+                                return Err(de::Error::duplicate_field("threads"));
+                            }
+
+                            threads = Some(map.next_value::<Vec<GuildChannel>>()?);
+                        },
+                        Field::Unknown => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        },
                     }
                 }
 
@@ -234,6 +429,11 @@ impl<'de> Deserialize<'de> for AuditLogs {
                         .collect(),
                     webhooks: webhooks.unwrap(),
                     users: users.unwrap(),
+                    application_commands: application_commands.unwrap_or_default(),
+                    auto_moderation_rules: auto_moderation_rules.unwrap_or_default(),
+                    guild_scheduled_events: guild_scheduled_events.unwrap_or_default(),
+                    integrations: integrations.unwrap_or_default(),
+                    threads: threads.unwrap_or_default(),
                 })
             }
         }
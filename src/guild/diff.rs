@@ -0,0 +1,231 @@
+use serde::ser::Serialize;
+use serde_json::{self, Value};
+use std::hash::Hash;
+use super::super::{ChannelId, EmojiId, GuildChannel, IdMap, RoleId};
+use super::{Change, Emoji, Guild, Role};
+
+/// The entity that a [`GuildDiffEntry`] applies to.
+///
+/// [`GuildDiffEntry`]: struct.GuildDiffEntry.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum GuildDiffTarget {
+    /// The guild itself.
+    Guild,
+    /// A channel in the guild.
+    Channel(ChannelId),
+    /// A custom emoji in the guild.
+    Emoji(EmojiId),
+    /// A role in the guild.
+    Role(RoleId),
+}
+
+/// What happened to the target of a [`GuildDiffEntry`].
+///
+/// [`GuildDiffEntry`]: struct.GuildDiffEntry.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum GuildDiffAction {
+    /// The target was added.
+    Create,
+    /// The target was removed.
+    Delete,
+    /// One or more properties of the target changed.
+    Update,
+}
+
+/// A single changed entity within a [`GuildDiff`].
+///
+/// [`GuildDiff`]: struct.GuildDiff.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuildDiffEntry {
+    /// What happened to the target.
+    pub action: GuildDiffAction,
+    /// The changed properties, keyed the same way as in the audit log.
+    ///
+    /// For created targets only the new values are set, and for deleted
+    /// targets only the old values are set.
+    pub changes: Vec<Change>,
+    /// The entity that changed.
+    pub target: GuildDiffTarget,
+}
+
+/// The differences between two snapshots of a [`Guild`], as produced by
+/// [`Guild::diff`].
+///
+/// [`Guild`]: struct.Guild.html
+/// [`Guild::diff`]: struct.Guild.html#method.diff
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GuildDiff {
+    /// The changed entities, with the guild first, followed by channels,
+    /// roles, and emojis.
+    pub entries: Vec<GuildDiffEntry>,
+}
+
+impl GuildDiff {
+    /// Whether the two snapshots were identical.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+}
+
+impl Guild {
+    /// Compares an older snapshot of the guild against this one, producing
+    /// the changes made to the guild, its channels, roles, and emojis.
+    ///
+    /// Members, presences, and voice states are not compared.
+    pub fn diff(&self, old: &Guild) -> GuildDiff {
+        let mut entries = Vec::new();
+
+        let mut changes = Vec::new();
+        change(&mut changes, "afk_channel_id", &old.afk_channel_id, &self.afk_channel_id);
+        change(&mut changes, "afk_timeout", &old.afk_timeout, &self.afk_timeout);
+        change(&mut changes, "banner_hash", &old.banner, &self.banner);
+        change(
+            &mut changes,
+            "default_message_notifications",
+            &old.default_message_notifications,
+            &self.default_message_notifications,
+        );
+        change(&mut changes, "icon_hash", &old.icon, &self.icon);
+        change(&mut changes, "mfa_level", &old.mfa_level, &self.mfa_level);
+        change(&mut changes, "name", &old.name, &self.name);
+        change(&mut changes, "owner_id", &old.owner_id, &self.owner_id);
+        change(&mut changes, "preferred_locale", &old.preferred_locale, &self.preferred_locale);
+        change(&mut changes, "region", &old.region, &self.region);
+        change(&mut changes, "splash_hash", &old.splash, &self.splash);
+        change(&mut changes, "system_channel_id", &old.system_channel_id, &self.system_channel_id);
+        change(
+            &mut changes,
+            "verification_level",
+            &old.verification_level,
+            &self.verification_level,
+        );
+
+        if !changes.is_empty() {
+            entries.push(GuildDiffEntry {
+                action: GuildDiffAction::Update,
+                changes: changes,
+                target: GuildDiffTarget::Guild,
+            });
+        }
+
+        diff_map(
+            &mut entries,
+            &old.channels,
+            &self.channels,
+            GuildDiffTarget::Channel,
+            channel_changes,
+        );
+        diff_map(&mut entries, &old.roles, &self.roles, GuildDiffTarget::Role, role_changes);
+        diff_map(&mut entries, &old.emojis, &self.emojis, GuildDiffTarget::Emoji, emoji_changes);
+
+        GuildDiff {
+            entries: entries,
+        }
+    }
+}
+
+/// Compares two maps of entities, pushing an entry for each created, deleted,
+/// and updated entity.
+///
+/// Entries are ordered by Id so that diffs are deterministic.
+fn diff_map<K, V, T, C>(
+    entries: &mut Vec<GuildDiffEntry>,
+    old: &IdMap<K, V>,
+    new: &IdMap<K, V>,
+    target: T,
+    compare: C,
+) where K: Copy + Hash + Ord,
+        T: Fn(K) -> GuildDiffTarget,
+        C: Fn(Option<&V>, Option<&V>) -> Vec<Change> {
+    let mut ids = old.keys()
+        .chain(new.keys().filter(|id| !old.contains_key(id)))
+        .cloned()
+        .collect::<Vec<K>>();
+    ids.sort();
+
+    for id in ids {
+        let (action, changes) = match (old.get(&id), new.get(&id)) {
+            (Some(old), Some(new)) => (GuildDiffAction::Update, compare(Some(old), Some(new))),
+            (Some(old), None) => (GuildDiffAction::Delete, compare(Some(old), None)),
+            (None, Some(new)) => (GuildDiffAction::Create, compare(None, Some(new))),
+            (None, None) => continue,
+        };
+
+        if action == GuildDiffAction::Update && changes.is_empty() {
+            continue;
+        }
+
+        entries.push(GuildDiffEntry {
+            action: action,
+            changes: changes,
+            target: target(id),
+        });
+    }
+}
+
+fn channel_changes(old: Option<&GuildChannel>, new: Option<&GuildChannel>) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    field(&mut changes, "bitrate", old, new, |c| &c.bitrate);
+    field(&mut changes, "name", old, new, |c| &c.name);
+    field(&mut changes, "nsfw", old, new, |c| &c.nsfw);
+    field(&mut changes, "parent_id", old, new, |c| &c.category_id);
+    field(&mut changes, "permission_overwrites", old, new, |c| &c.permission_overwrites);
+    field(&mut changes, "position", old, new, |c| &c.position);
+    field(&mut changes, "rate_limit_per_user", old, new, |c| &c.rate_limit_per_user);
+    field(&mut changes, "topic", old, new, |c| &c.topic);
+    field(&mut changes, "type", old, new, |c| &c.kind);
+    field(&mut changes, "user_limit", old, new, |c| &c.user_limit);
+
+    changes
+}
+
+fn emoji_changes(old: Option<&Emoji>, new: Option<&Emoji>) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    field(&mut changes, "name", old, new, |e| &e.name);
+    field(&mut changes, "roles", old, new, |e| &e.roles);
+
+    changes
+}
+
+fn role_changes(old: Option<&Role>, new: Option<&Role>) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    #[cfg(feature = "utils")]
+    field(&mut changes, "color", old, new, |r| &r.colour.0);
+    #[cfg(not(feature = "utils"))]
+    field(&mut changes, "color", old, new, |r| &r.colour);
+    field(&mut changes, "hoist", old, new, |r| &r.hoist);
+    field(&mut changes, "icon_hash", old, new, |r| &r.icon);
+    field(&mut changes, "mentionable", old, new, |r| &r.mentionable);
+    field(&mut changes, "name", old, new, |r| &r.name);
+    field(&mut changes, "permissions", old, new, |r| &r.permissions);
+    field(&mut changes, "position", old, new, |r| &r.position);
+    field(&mut changes, "unicode_emoji", old, new, |r| &r.unicode_emoji);
+
+    changes
+}
+
+/// Pushes a change for a property of an entity that may not exist on one side.
+fn field<E, T, F>(changes: &mut Vec<Change>, key: &str, old: Option<&E>, new: Option<&E>, f: F)
+    where T: Serialize, F: Fn(&E) -> &T {
+    let old = old.map(|e| to_value(f(e)));
+    let new = new.map(|e| to_value(f(e)));
+
+    if old != new {
+        changes.push(Change {
+            name: key.to_string(),
+            old: old,
+            new: new,
+        });
+    }
+}
+
+/// Pushes a change for a property if its value differs.
+fn change<T: Serialize>(changes: &mut Vec<Change>, key: &str, old: &T, new: &T) {
+    field(changes, key, Some(old), Some(new), |value| value);
+}
+
+fn to_value<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
@@ -1,24 +1,36 @@
 use std::fmt::{Display, Formatter, Result as FmtResult, Write as FmtWrite};
-use super::super::{EmojiId, RoleId};
+use super::super::{EmojiId, GuildId, RoleId, User};
+use super::Guild;
 
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
+use http;
+#[cfg(feature = "model")]
 use internal::prelude::*;
-#[cfg(all(feature = "cache", feature = "model"))]
-use std::mem;
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
+use serenity_common::prelude::{JsonMap, Value};
+#[cfg(feature = "model")]
+use super::super::utils::image_data_uri;
+#[cfg(feature = "model")]
 use super::super::ModelError;
-#[cfg(all(feature = "cache", feature = "model"))]
-use super::super::GuildId;
-#[cfg(all(feature = "cache", feature = "model"))]
-use {CACHE, http};
+
+/// The maximum size of an emoji image, in bytes.
+#[cfg(feature = "model")]
+const EMOJI_SIZE_LIMIT: u64 = 256 * 1024;
 
 /// Represents a custom guild emoji, which can either be created using the API,
 /// or via an integration. Emojis created using the API only work within the
 /// guild it was created in.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Emoji {
     /// The Id of the emoji.
     pub id: EmojiId,
+    /// The Id of the guild that the emoji belongs to.
+    ///
+    /// **Note**: This is only present on emojis received as part of a guild
+    /// or a guild emojis update, as Discord does not send it on emojis
+    /// themselves.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
     /// The name of the emoji. It must be at least 2 characters long and can
     /// only contain alphanumeric characters and underscores.
     pub name: String,
@@ -34,6 +46,14 @@ pub struct Emoji {
     ///
     /// [`Role`]: struct.Role.html
     pub roles: Vec<RoleId>,
+    /// The user that uploaded the emoji.
+    ///
+    /// **Note**: This is only present when the emoji is retrieved with the
+    /// [Manage Emojis] permission.
+    ///
+    /// [Manage Emojis]: permissions/constant.MANAGE_EMOJIS.html
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
 }
 
 impl Display for Emoji {
@@ -50,12 +70,125 @@ impl Display for Emoji {
     }
 }
 
-impl From<Emoji> for EmojiId {
-    /// Gets the Id of an `Emoji`.
-    fn from(emoji: Emoji) -> EmojiId { emoji.id }
+impl EmojiId {
+    /// Looks up the emoji in the given guild.
+    ///
+    /// Returns `None` if the emoji does not belong to the guild.
+    #[inline]
+    pub fn to_emoji<'a>(&self, guild: &'a Guild) -> Option<&'a Emoji> { guild.emojis.get(self) }
 }
 
-impl<'a> From<&'a Emoji> for EmojiId {
-    /// Gets the Id of an `Emoji`.
-    fn from(emoji: &Emoji) -> EmojiId { emoji.id }
+#[cfg(feature = "model")]
+impl Emoji {
+    /// Deletes the emoji from the guild it belongs to.
+    ///
+    /// An optional reason may be given, which is shown in the guild's audit
+    /// log.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MissingGuildId`] if the emoji's [`guild_id`] is
+    /// not known.
+    ///
+    /// [`ModelError::MissingGuildId`]: enum.ModelError.html#variant.MissingGuildId
+    /// [`guild_id`]: #structfield.guild_id
+    /// [Manage Emojis]: permissions/constant.MANAGE_EMOJIS.html
+    #[inline]
+    pub fn delete(&self, audit_log_reason: Option<&str>) -> Result<()> {
+        http::delete_emoji(self.find_guild_id()?.0, self.id.0, audit_log_reason)
+    }
+
+    /// Renames the emoji, updating the local emoji with the result.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidEmojiName`] if the name is invalid, or a
+    /// [`ModelError::MissingGuildId`] if the emoji's [`guild_id`] is not known.
+    ///
+    /// [`ModelError::InvalidEmojiName`]: enum.ModelError.html#variant.InvalidEmojiName
+    /// [`ModelError::MissingGuildId`]: enum.ModelError.html#variant.MissingGuildId
+    /// [`guild_id`]: #structfield.guild_id
+    /// [Manage Emojis]: permissions/constant.MANAGE_EMOJIS.html
+    pub fn edit(&mut self, name: &str) -> Result<()> {
+        validate_emoji_name(name)?;
+
+        let mut map = JsonMap::new();
+        map.insert("name".to_string(), Value::String(name.to_string()));
+
+        self._edit(Value::Object(map))
+    }
+
+    /// Replaces the roles that are allowed to use the emoji, updating the
+    /// local emoji with the result.
+    ///
+    /// An empty list of roles allows everyone to use the emoji.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MissingGuildId`] if the emoji's [`guild_id`] is
+    /// not known.
+    ///
+    /// [`ModelError::MissingGuildId`]: enum.ModelError.html#variant.MissingGuildId
+    /// [`guild_id`]: #structfield.guild_id
+    /// [Manage Emojis]: permissions/constant.MANAGE_EMOJIS.html
+    pub fn edit_roles(&mut self, roles: &[RoleId]) -> Result<()> {
+        let roles = roles.iter().map(|role| Value::String(role.0.to_string())).collect();
+
+        let mut map = JsonMap::new();
+        map.insert("roles".to_string(), Value::Array(roles));
+
+        self._edit(Value::Object(map))
+    }
+
+    fn _edit(&mut self, map: Value) -> Result<()> {
+        let guild_id = self.find_guild_id()?;
+
+        match http::edit_emoji(guild_id.0, self.id.0, &map) {
+            Ok(mut emoji) => {
+                emoji.guild_id = Some(guild_id);
+                *self = emoji;
+
+                Ok(())
+            },
+            Err(why) => Err(why),
+        }
+    }
+
+    fn find_guild_id(&self) -> Result<GuildId> {
+        self.guild_id.ok_or(Error::Model(ModelError::MissingGuildId))
+    }
+}
+
+/// Checks that an emoji name is between 2 and 32 characters long and only
+/// contains ASCII alphanumeric characters and underscores.
+#[cfg(feature = "model")]
+pub(crate) fn validate_emoji_name(name: &str) -> Result<()> {
+    let length = name.chars().count();
+    let valid = length >= 2
+        && length <= 32
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::Model(ModelError::InvalidEmojiName))
+    }
+}
+
+/// Validates an emoji image and builds the data URI used to upload it.
+#[cfg(feature = "model")]
+pub(crate) fn emoji_image_data(image: &[u8]) -> Result<String> {
+    let size = image.len() as u64;
+
+    if size > EMOJI_SIZE_LIMIT {
+        return Err(Error::Model(ModelError::EmojiTooLarge(size - EMOJI_SIZE_LIMIT)));
+    }
+
+    image_data_uri(image).ok_or(Error::Model(ModelError::UnsupportedImageFormat))
 }
@@ -1,41 +1,610 @@
-use ::*;
+use super::super::{GuildId, RoleId};
 
-impl From<PartialGuild> for GuildId {
-    /// Gets the Id of a partial guild.
-    fn from(guild: PartialGuild) -> GuildId { guild.id }
-}
+#[cfg(feature = "model")]
+use http;
+#[cfg(feature = "model")]
+use internal::prelude::*;
+#[cfg(feature = "model")]
+use serde_json;
+#[cfg(feature = "model")]
+use serenity_common::prelude::{JsonMap, Value};
+#[cfg(feature = "model")]
+use super::super::{
+    ChannelId,
+    ChannelPosition,
+    CreateChannel,
+    GuildChannel,
+    IdMap,
+    IntegrationId,
+    ModelError,
+    RichInvite,
+    ScheduledEventId,
+    ThreadsData,
+    UserId,
+};
+#[cfg(feature = "model")]
+use super::emoji::{emoji_image_data, validate_emoji_name};
+#[cfg(feature = "model")]
+use super::integration::SYNCABLE_INTEGRATION_KINDS;
+#[cfg(feature = "model")]
+use super::{
+    Ban,
+    BanOptions,
+    CreateScheduledEvent,
+    EditGuild,
+    EditIncidentActions,
+    EditMembershipScreening,
+    EditRole,
+    EditScheduledEvent,
+    EditWidget,
+    Emoji,
+    GuildEmbed,
+    GuildPrune,
+    GuildScheduledEvent,
+    GuildVanityUrl,
+    IncidentsData,
+    Integration,
+    Member,
+    MembershipScreening,
+    MfaLevel,
+    Onboarding,
+    PartialGuild,
+    Role,
+    RolePosition,
+};
+#[cfg(feature = "model")]
+use std::fs;
+#[cfg(feature = "model")]
+use std::path::Path;
 
-impl<'a> From<&'a PartialGuild> for GuildId {
-    /// Gets the Id of a partial guild.
-    fn from(guild: &PartialGuild) -> GuildId { guild.id }
-}
+impl GuildId {
+    /// Retrieves the Id of the guild's `@everyone` role, which always shares
+    /// the Id of the guild.
+    #[inline]
+    pub fn everyone_role(&self) -> RoleId { RoleId(self.0) }
 
-impl From<GuildInfo> for GuildId {
-    /// Gets the Id of Guild information struct.
-    fn from(guild_info: GuildInfo) -> GuildId { guild_info.id }
+    /// Calculates the Id of the shard that receives the guild's events, out
+    /// of the given total number of shards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `total_shards` is `0`.
+    #[inline]
+    pub fn shard_id(&self, total_shards: u64) -> u64 { (self.0 >> 22) % total_shards }
 }
 
-impl<'a> From<&'a GuildInfo> for GuildId {
-    /// Gets the Id of Guild information struct.
-    fn from(guild_info: &GuildInfo) -> GuildId { guild_info.id }
-}
+#[cfg(feature = "model")]
+impl GuildId {
+    /// Gets all of the active threads in the guild that the current user can
+    /// view, along with the current user's membership of them.
+    ///
+    /// Active threads are retrieved all at once; use
+    /// [`ThreadsData::into_threads`] to attach the memberships to each thread.
+    ///
+    /// [`ThreadsData::into_threads`]: struct.ThreadsData.html#method.into_threads
+    #[inline]
+    pub fn active_threads(&self) -> Result<ThreadsData> {
+        http::get_guild_active_threads(self.0)
+    }
 
-impl From<InviteGuild> for GuildId {
-    /// Gets the Id of Invite Guild struct.
-    fn from(invite_guild: InviteGuild) -> GuildId { invite_guild.id }
-}
+    /// Bans a user from the guild, optionally deleting their messages from
+    /// the past number of days and recording a reason in the audit log.
+    ///
+    /// Ban a user and delete their messages from the past day:
+    ///
+    /// ```rust,ignore
+    /// guild_id.ban(user_id, &(1, "spamming"))?;
+    /// ```
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::DeleteMessageDaysAmount`] if the number of days
+    /// is greater than 7.
+    ///
+    /// [`ModelError::DeleteMessageDaysAmount`]: enum.ModelError.html#variant.DeleteMessageDaysAmount
+    /// [Ban Members]: permissions/constant.BAN_MEMBERS.html
+    pub fn ban<U, BO>(&self, user_id: U, options: &BO) -> Result<()>
+        where U: Into<UserId>, BO: BanOptions + ?Sized {
+        let dmd = options.dmd();
 
-impl<'a> From<&'a InviteGuild> for GuildId {
-    /// Gets the Id of Invite Guild struct.
-    fn from(invite_guild: &InviteGuild) -> GuildId { invite_guild.id }
-}
+        if dmd > 7 {
+            return Err(Error::Model(ModelError::DeleteMessageDaysAmount(dmd)));
+        }
 
-impl From<Guild> for GuildId {
-    /// Gets the Id of Guild.
-    fn from(live_guild: Guild) -> GuildId { live_guild.id }
-}
+        let reason = options.reason();
+        let reason = if reason.is_empty() { None } else { Some(reason) };
+
+        http::ban_user(self.0, user_id.into().0, dmd, reason)
+    }
+
+    /// Gets the guild's bans.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// [Ban Members]: permissions/constant.BAN_MEMBERS.html
+    #[inline]
+    pub fn bans(&self) -> Result<Vec<Ban>> { http::get_bans(self.0) }
+
+    /// Gets the guild's channels, including threads that are not archived.
+    pub fn channels(&self) -> Result<IdMap<ChannelId, GuildChannel>> {
+        let channels = http::get_channels(self.0)?;
+
+        Ok(channels.into_iter().map(|channel| (channel.id, channel)).collect())
+    }
+
+    /// Completes the guild's membership screening form as the current user,
+    /// allowing them to interact with the guild.
+    ///
+    /// The form should be retrieved via [`membership_screening`], with the
+    /// [`response`] of each field set.
+    ///
+    /// [`membership_screening`]: #method.membership_screening
+    /// [`response`]: struct.MembershipScreeningField.html#structfield.response
+    pub fn complete_membership_screening(&self, screening: &MembershipScreening) -> Result<()> {
+        let map = serde_json::to_value(screening)?;
+
+        http::complete_membership_screening(self.0, &map)
+    }
+
+    /// Creates a channel in the guild, configured through the [`CreateChannel`]
+    /// builder.
+    ///
+    /// Create a voice channel named `lounge` in a category:
+    ///
+    /// ```rust,ignore
+    /// use model::{ChannelId, ChannelType, GuildId};
+    ///
+    /// let channel = GuildId(81384788765712384).create_channel(|c| c
+    ///     .name("lounge")
+    ///     .kind(ChannelType::Voice)
+    ///     .parent(ChannelId(381880193251409931)))?;
+    /// ```
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::OutOfLimits`] if the channel is outside of
+    /// Discord's limits.
+    ///
+    /// [`CreateChannel`]: struct.CreateChannel.html
+    /// [`ModelError::OutOfLimits`]: enum.ModelError.html#variant.OutOfLimits
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    pub fn create_channel<F>(&self, f: F) -> Result<GuildChannel>
+        where F: FnOnce(CreateChannel) -> CreateChannel {
+        let channel = f(CreateChannel::default());
+        let map = channel.to_value().map_err(|why| Error::Model(ModelError::OutOfLimits(why)))?;
+
+        http::create_channel(self.0, &map, channel.reason.as_ref().map(String::as_str))
+    }
+
+    /// Creates an emoji in the guild from the raw bytes of a PNG, JPEG, GIF,
+    /// or WebP image.
+    ///
+    /// The name must be at least 2 characters long and may only contain
+    /// alphanumeric characters and underscores.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidEmojiName`] if the name is invalid,
+    /// [`ModelError::EmojiTooLarge`] if the image is larger than 256KB, and
+    /// [`ModelError::UnsupportedImageFormat`] if the image format could not
+    /// be recognized.
+    ///
+    /// [`ModelError::EmojiTooLarge`]: enum.ModelError.html#variant.EmojiTooLarge
+    /// [`ModelError::InvalidEmojiName`]: enum.ModelError.html#variant.InvalidEmojiName
+    /// [`ModelError::UnsupportedImageFormat`]: enum.ModelError.html#variant.UnsupportedImageFormat
+    /// [Manage Emojis]: permissions/constant.MANAGE_EMOJIS.html
+    pub fn create_emoji(&self, name: &str, image: &[u8]) -> Result<Emoji> {
+        validate_emoji_name(name)?;
+        let image = emoji_image_data(image)?;
+
+        let mut map = JsonMap::new();
+        map.insert("name".to_string(), Value::String(name.to_string()));
+        map.insert("image".to_string(), Value::String(image));
+
+        http::create_emoji(self.0, &Value::Object(map))
+    }
+
+    /// Creates an emoji in the guild from an image file on disk.
+    ///
+    /// Refer to [`create_emoji`] for the validation performed.
+    ///
+    /// [`create_emoji`]: #method.create_emoji
+    pub fn create_emoji_from_path<P: AsRef<Path>>(&self, name: &str, path: P) -> Result<Emoji> {
+        let image = fs::read(path)?;
+
+        self.create_emoji(name, &image)
+    }
+
+    /// Attaches an integration of the given kind from the current user's
+    /// connected accounts to the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::UnsupportedIntegration`] if the kind is not one
+    /// of the [`SYNCABLE_INTEGRATION_KINDS`].
+    ///
+    /// [`ModelError::UnsupportedIntegration`]: enum.ModelError.html#variant.UnsupportedIntegration
+    /// [`SYNCABLE_INTEGRATION_KINDS`]: constant.SYNCABLE_INTEGRATION_KINDS.html
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    pub fn create_integration<I>(&self, integration_id: I, kind: &str) -> Result<()>
+        where I: Into<IntegrationId> {
+        if !SYNCABLE_INTEGRATION_KINDS.contains(&kind) {
+            return Err(Error::Model(ModelError::UnsupportedIntegration));
+        }
+
+        let integration_id = integration_id.into().0;
+
+        let mut map = JsonMap::new();
+        map.insert("id".to_string(), Value::String(integration_id.to_string()));
+        map.insert("type".to_string(), Value::String(kind.to_string()));
+
+        http::create_guild_integration(self.0, integration_id, &Value::Object(map))
+    }
+
+    /// Creates a role in the guild, configured through the [`EditRole`]
+    /// builder.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::OutOfLimits`] if the role is outside of
+    /// Discord's limits.
+    ///
+    /// [`EditRole`]: struct.EditRole.html
+    /// [`ModelError::OutOfLimits`]: enum.ModelError.html#variant.OutOfLimits
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    pub fn create_role<F>(&self, f: F) -> Result<Role>
+        where F: FnOnce(EditRole) -> EditRole {
+        let role = f(EditRole::default());
+        let map = role.to_value().map_err(|why| Error::Model(ModelError::OutOfLimits(why)))?;
+
+        http::create_role(self.0, &map, role.reason.as_ref().map(String::as_str))
+    }
+
+    /// Creates a scheduled event in the guild, configured through the
+    /// [`CreateScheduledEvent`] builder.
+    ///
+    /// **Note**: Requires the [Manage Events] permission.
+    ///
+    /// [`CreateScheduledEvent`]: struct.CreateScheduledEvent.html
+    /// [Manage Events]: permissions/constant.MANAGE_EVENTS.html
+    pub fn create_scheduled_event<F>(&self, f: F) -> Result<GuildScheduledEvent>
+        where F: FnOnce(CreateScheduledEvent) -> CreateScheduledEvent {
+        let map = serde_json::to_value(f(CreateScheduledEvent::default()))?;
+
+        http::create_scheduled_event(self.0, &map)
+    }
+
+    /// Deletes the guild, returning its final state.
+    ///
+    /// **Note**: Only the owner of the guild can delete it.
+    #[inline]
+    pub fn delete(&self) -> Result<PartialGuild> { http::delete_guild(self.0) }
+
+    /// Deletes an integration from the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    #[inline]
+    pub fn delete_integration<I: Into<IntegrationId>>(&self, integration_id: I) -> Result<()> {
+        http::delete_guild_integration(self.0, integration_id.into().0)
+    }
+
+    /// Deletes a role from the guild.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    #[inline]
+    pub fn delete_role<R: Into<RoleId>>(&self, role_id: R) -> Result<()> {
+        http::delete_role(self.0, role_id.into().0)
+    }
+
+    /// Deletes a scheduled event from the guild.
+    ///
+    /// **Note**: Requires the [Manage Events] permission.
+    ///
+    /// [Manage Events]: permissions/constant.MANAGE_EVENTS.html
+    #[inline]
+    pub fn delete_scheduled_event<E: Into<ScheduledEventId>>(&self, event_id: E) -> Result<()> {
+        http::delete_scheduled_event(self.0, event_id.into().0)
+    }
+
+    /// Edits the guild, configured through the [`EditGuild`] builder.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::OutOfLimits`] if the guild is outside of
+    /// Discord's limits.
+    ///
+    /// [`EditGuild`]: struct.EditGuild.html
+    /// [`ModelError::OutOfLimits`]: enum.ModelError.html#variant.OutOfLimits
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    pub fn edit<F>(&self, f: F) -> Result<PartialGuild>
+        where F: FnOnce(EditGuild) -> EditGuild {
+        let guild = f(EditGuild::default());
+        let map = guild.to_value().map_err(|why| Error::Model(ModelError::OutOfLimits(why)))?;
+
+        http::edit_guild(self.0, &map, guild.reason.as_ref().map(String::as_str))
+    }
+
+    /// Edits the guild's incident actions, such as pausing invites during a
+    /// raid, configured through the [`EditIncidentActions`] builder.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`EditIncidentActions`]: struct.EditIncidentActions.html
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    pub fn edit_incident_actions<F>(&self, f: F) -> Result<IncidentsData>
+        where F: FnOnce(EditIncidentActions) -> EditIncidentActions {
+        let map = serde_json::to_value(f(EditIncidentActions::default()))?;
+
+        http::edit_guild_incident_actions(self.0, &map)
+    }
+
+    /// Edits the guild's membership screening form, configured through the
+    /// [`EditMembershipScreening`] builder.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`EditMembershipScreening`]: struct.EditMembershipScreening.html
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    pub fn edit_membership_screening<F>(&self, f: F) -> Result<MembershipScreening>
+        where F: FnOnce(EditMembershipScreening) -> EditMembershipScreening {
+        let map = serde_json::to_value(f(EditMembershipScreening::default()))?;
+
+        http::edit_guild_membership_screening(self.0, &map)
+    }
+
+    /// Sets the multi-factor authentication requirement for moderation
+    /// actions, returning the new level.
+    ///
+    /// **Note**: Only the owner of the guild can change this, and they must
+    /// have multi-factor authentication enabled themselves.
+    #[inline]
+    pub fn edit_mfa_level(&self, level: MfaLevel) -> Result<MfaLevel> {
+        http::edit_guild_mfa_level(self.0, level as u64)
+    }
+
+    /// Replaces the guild's onboarding, returning the new onboarding.
+    ///
+    /// Use [`Guild::validate_onboarding`] beforehand to check that Discord
+    /// will accept it.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// [`Guild::validate_onboarding`]: struct.Guild.html#method.validate_onboarding
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    pub fn edit_onboarding(&self, onboarding: &Onboarding) -> Result<Onboarding> {
+        let map = serde_json::to_value(onboarding)?;
+
+        http::edit_guild_onboarding(self.0, &map)
+    }
+
+    /// Edits a role in the guild, configured through the [`EditRole`] builder.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::OutOfLimits`] if the role is outside of
+    /// Discord's limits.
+    ///
+    /// [`EditRole`]: struct.EditRole.html
+    /// [`ModelError::OutOfLimits`]: enum.ModelError.html#variant.OutOfLimits
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    pub fn edit_role<R, F>(&self, role_id: R, f: F) -> Result<Role>
+        where R: Into<RoleId>, F: FnOnce(EditRole) -> EditRole {
+        let role = f(EditRole::default());
+        let map = role.to_value().map_err(|why| Error::Model(ModelError::OutOfLimits(why)))?;
+
+        http::edit_role(self.0, role_id.into().0, &map, role.reason.as_ref().map(String::as_str))
+    }
+
+    /// Edits a scheduled event in the guild, configured through the
+    /// [`EditScheduledEvent`] builder.
+    ///
+    /// **Note**: Requires the [Manage Events] permission.
+    ///
+    /// [`EditScheduledEvent`]: struct.EditScheduledEvent.html
+    /// [Manage Events]: permissions/constant.MANAGE_EVENTS.html
+    pub fn edit_scheduled_event<E, F>(&self, event_id: E, f: F) -> Result<GuildScheduledEvent>
+        where E: Into<ScheduledEventId>, F: FnOnce(EditScheduledEvent) -> EditScheduledEvent {
+        let map = serde_json::to_value(f(EditScheduledEvent::default()))?;
+
+        http::edit_scheduled_event(self.0, event_id.into().0, &map)
+    }
+
+    /// Edits the guild's widget settings, configured through the
+    /// [`EditWidget`] builder.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`EditWidget`]: struct.EditWidget.html
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    pub fn edit_widget<F>(&self, f: F) -> Result<GuildEmbed>
+        where F: FnOnce(EditWidget) -> EditWidget {
+        let map = serde_json::to_value(f(EditWidget::default()))?;
+
+        http::edit_guild_widget(self.0, &map)
+    }
+
+    /// Gets the guild's custom emojis.
+    #[inline]
+    pub fn emojis(&self) -> Result<Vec<Emoji>> { http::get_emojis(self.0) }
+
+    /// Gets the integrations attached to the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    #[inline]
+    pub fn integrations(&self) -> Result<Vec<Integration>> { http::get_guild_integrations(self.0) }
+
+    /// Gets the guild's invites, along with their metadata.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    #[inline]
+    pub fn invites(&self) -> Result<Vec<RichInvite>> { http::get_guild_invites(self.0) }
+
+    /// Kicks a member from the guild.
+    ///
+    /// An optional reason may be given, which is shown in the guild's audit
+    /// log.
+    ///
+    /// **Note**: Requires the [Kick Members] permission.
+    ///
+    /// [Kick Members]: permissions/constant.KICK_MEMBERS.html
+    #[inline]
+    pub fn kick<U: Into<UserId>>(&self, user_id: U, audit_log_reason: Option<&str>) -> Result<()> {
+        http::kick_member(self.0, user_id.into().0, audit_log_reason)
+    }
+
+    /// Leaves the guild as the current user.
+    ///
+    /// The owner of a guild can not leave it, and should [`delete`] it
+    /// instead.
+    ///
+    /// [`delete`]: #method.delete
+    #[inline]
+    pub fn leave(&self) -> Result<()> { http::leave_guild(self.0) }
+
+    /// Gets a member of the guild.
+    #[inline]
+    pub fn member<U: Into<UserId>>(&self, user_id: U) -> Result<Member> {
+        http::get_member(self.0, user_id.into().0)
+    }
+
+    /// Gets a page of the guild's members, ordered by user Id.
+    ///
+    /// Up to `limit` members are returned, with a maximum and default of
+    /// 1000. Members with a user Id after `after` are returned, or members
+    /// from the start of the list if it is `None`.
+    ///
+    /// **Note**: Requires the `GUILD_MEMBERS` privileged intent.
+    #[inline]
+    pub fn members<U: Into<UserId>>(&self, limit: Option<u64>, after: Option<U>)
+        -> Result<Vec<Member>> {
+        http::get_guild_members(self.0, limit, after.map(|x| x.into().0))
+    }
+
+    /// Gets the guild's membership screening form.
+    #[inline]
+    pub fn membership_screening(&self) -> Result<MembershipScreening> {
+        http::get_guild_membership_screening(self.0)
+    }
+
+    /// Gets the guild's onboarding.
+    #[inline]
+    pub fn onboarding(&self) -> Result<Onboarding> { http::get_guild_onboarding(self.0) }
+
+    /// Gets the number of members that would be removed by pruning members
+    /// who have been inactive for the given number of days.
+    ///
+    /// **Note**: Requires the [Kick Members] permission.
+    ///
+    /// [Kick Members]: permissions/constant.KICK_MEMBERS.html
+    pub fn prune_count(&self, days: u16) -> Result<GuildPrune> {
+        let mut map = JsonMap::new();
+        map.insert("days".to_string(), Value::from(days));
+
+        http::get_guild_prune_count(self.0, &Value::Object(map))
+    }
+
+    /// Moves channels to new positions within the guild's channel list,
+    /// optionally moving them between categories.
+    ///
+    /// Positions can be given either as [`ChannelPosition`]s or as tuples of a
+    /// channel Id and its position.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// [`ChannelPosition`]: struct.ChannelPosition.html
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    pub fn reorder_channels<It, P>(&self, positions: It) -> Result<()>
+        where It: IntoIterator<Item = P>, P: Into<ChannelPosition> {
+        let positions = positions.into_iter().map(Into::into).collect::<Vec<ChannelPosition>>();
+        let map = serde_json::to_value(&positions)?;
+
+        http::edit_channel_positions(self.0, &map)
+    }
+
+    /// Moves roles to new positions within the guild's role list, returning
+    /// all of the guild's roles after the change.
+    ///
+    /// Positions can be given either as [`RolePosition`]s or as tuples of a
+    /// role Id and its position.
+    ///
+    /// This does not validate the positions or check the role hierarchy;
+    /// refer to [`Guild::reorder_roles`] for a checked variant.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`Guild::reorder_roles`]: struct.Guild.html#method.reorder_roles
+    /// [`RolePosition`]: struct.RolePosition.html
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    pub fn reorder_roles<It, P>(&self, positions: It) -> Result<Vec<Role>>
+        where It: IntoIterator<Item = P>, P: Into<RolePosition> {
+        let positions = positions.into_iter().map(Into::into).collect::<Vec<RolePosition>>();
+        let map = serde_json::to_value(&positions)?;
+
+        http::edit_role_positions(self.0, &map)
+    }
+
+    /// Gets the guild's roles.
+    pub fn roles(&self) -> Result<IdMap<RoleId, Role>> {
+        let roles = http::get_guild_roles(self.0)?;
+
+        Ok(roles.into_iter().map(|role| (role.id, role)).collect())
+    }
+
+    /// Gets the scheduled events of the guild.
+    ///
+    /// If `with_user_count` is `true`, then each event's [`user_count`] is
+    /// populated.
+    ///
+    /// [`user_count`]: struct.GuildScheduledEvent.html#structfield.user_count
+    #[inline]
+    pub fn scheduled_events(&self, with_user_count: bool) -> Result<Vec<GuildScheduledEvent>> {
+        http::get_scheduled_events(self.0, with_user_count)
+    }
+
+    /// Syncs an integration in the guild.
+    ///
+    /// Prefer [`Integration::sync`], which checks that the integration is of a
+    /// kind that can be synced.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`Integration::sync`]: struct.Integration.html#method.sync
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    #[inline]
+    pub fn sync_integration<I: Into<IntegrationId>>(&self, integration_id: I) -> Result<()> {
+        http::start_integration_sync(self.0, integration_id.into().0)
+    }
 
-impl<'a> From<&'a Guild> for GuildId {
-    /// Gets the Id of Guild.
-    fn from(live_guild: &Guild) -> GuildId { live_guild.id }
+    /// Retrieves the guild's vanity invite code and its number of uses.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    #[inline]
+    pub fn vanity_url(&self) -> Result<GuildVanityUrl> {
+        http::get_guild_vanity_url(self.0)
+    }
 }
@@ -1,4 +1,15 @@
-use super::*;
+use super::super::{ApplicationId, IntegrationId, RoleId, User};
+
+#[cfg(feature = "model")]
+use internal::prelude::*;
+#[cfg(feature = "model")]
+use super::super::{GuildId, ModelError};
+
+/// The kinds of integration that can be created and synced through the API.
+///
+/// Other kinds, such as `discord` bot integrations, can only be listed and
+/// deleted.
+pub const SYNCABLE_INTEGRATION_KINDS: &[&str] = &["twitch", "youtube"];
 
 /// Various information about integrations.
 #[derive(Clone, Debug, Deserialize)]
@@ -8,7 +19,7 @@ pub struct Integration {
     pub enabled: bool,
     #[serde(rename = "expire_behaviour")] pub expire_behaviour: u64,
     pub expire_grace_period: u64,
-    pub kind: String,
+    #[serde(rename = "type")] pub kind: String,
     pub name: String,
     pub role_id: RoleId,
     pub synced_at: u64,
@@ -16,9 +27,56 @@ pub struct Integration {
     pub user: User,
 }
 
-impl From<Integration> for IntegrationId {
-    /// Gets the Id of integration.
-    fn from(integration: Integration) -> IntegrationId { integration.id }
+/// Partial information about an [`Integration`], such as is included in a
+/// guild's audit log.
+///
+/// [`Integration`]: struct.Integration.html
+#[derive(Clone, Debug, Deserialize)]
+pub struct PartialIntegration {
+    pub id: IntegrationId,
+    pub account: IntegrationAccount,
+    /// The Id of the application, for bot integrations.
+    pub application_id: Option<ApplicationId>,
+    #[serde(rename = "type")] pub kind: String,
+    pub name: String,
+}
+
+#[cfg(feature = "model")]
+impl Integration {
+    /// Deletes the integration from the given guild, removing any associated
+    /// webhooks and bots.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    #[inline]
+    pub fn delete<G: Into<GuildId>>(&self, guild_id: G) -> Result<()> {
+        guild_id.into().delete_integration(self.id)
+    }
+
+    /// Whether the integration is of a kind that can be synced.
+    pub fn is_syncable(&self) -> bool {
+        SYNCABLE_INTEGRATION_KINDS.contains(&self.kind.as_str())
+    }
+
+    /// Syncs the integration in the given guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::UnsupportedIntegration`] if the integration is
+    /// not of a kind that can be synced.
+    ///
+    /// [`ModelError::UnsupportedIntegration`]: enum.ModelError.html#variant.UnsupportedIntegration
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    pub fn sync<G: Into<GuildId>>(&self, guild_id: G) -> Result<()> {
+        if !self.is_syncable() {
+            return Err(Error::Model(ModelError::UnsupportedIntegration));
+        }
+
+        guild_id.into().sync_integration(self.id)
+    }
 }
 
 /// Integration account object.
@@ -1,17 +1,30 @@
-use ::*;
-use chrono::{DateTime, FixedOffset};
+use super::super::misc::Mentionable;
+use super::super::utils::U64Visitor;
+use super::super::{GuildId, RoleId, User};
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::result::Result as StdResult;
 
 #[cfg(all(feature = "builder", feature = "cache", feature = "model"))]
 use builder::EditMember;
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
+use http;
+#[cfg(feature = "model")]
 use internal::prelude::*;
 #[cfg(feature = "model")]
+use serenity_common::prelude::{JsonMap, Value};
+#[cfg(feature = "model")]
 use std::borrow::Cow;
+#[cfg(feature = "model")]
+use super::super::ChannelId;
+#[cfg(all(feature = "cache", feature = "model"))]
+use super::super::ModelError;
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
 use utils::Colour;
 #[cfg(all(feature = "cache", feature = "model"))]
-use {CACHE, http, utils};
+use {CACHE, utils};
 
 /// A trait for allowing both u8 or &str or (u8, &str) to be passed into the `ban` methods in `Guild` and `Member`.
 pub trait BanOptions {
@@ -48,10 +61,23 @@ impl BanOptions for (u8, String) {
 }
 
 /// Information about a member of a guild.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Member {
+    /// When the member's timeout ends, if they have been timed out.
+    ///
+    /// While timed out, a member can only view channels and read their
+    /// message history. Use [`is_timed_out`] to check whether the timeout is
+    /// still active.
+    ///
+    /// [`is_timed_out`]: #method.is_timed_out
+    #[serde(default)]
+    pub communication_disabled_until: Option<DateTime<FixedOffset>>,
     /// Indicator of whether the member can hear in voice channels.
     pub deaf: bool,
+    /// The member's flags, describing their progress through the guild's
+    /// onboarding and verification.
+    #[serde(default)]
+    pub flags: MemberFlags,
     /// The unique Id of the guild that the member is a part of.
     pub guild_id: GuildId,
     /// Timestamp representing the date when the member joined.
@@ -62,12 +88,47 @@ pub struct Member {
     ///
     /// Can't be longer than 32 characters.
     pub nick: Option<String>,
+    /// Whether the member has not yet passed the guild's membership screening.
+    ///
+    /// Pending members can not interact with the guild until they do.
+    #[serde(default)]
+    pub pending: bool,
+    /// When the member started boosting the guild, if they are boosting it.
+    pub premium_since: Option<DateTime<FixedOffset>>,
     /// Vector of Ids of [`Role`]s given to the member.
     pub roles: Vec<RoleId>,
     /// Attached User struct.
     pub user: User,
 }
 
+bitflags! {
+    /// Describes a member's progress through a guild's onboarding and
+    /// verification.
+    #[derive(Default)]
+    pub struct MemberFlags: u64 {
+        /// The member has left and rejoined the guild.
+        const DID_REJOIN = 1;
+        /// The member has completed onboarding.
+        const COMPLETED_ONBOARDING = 1 << 1;
+        /// The member is exempt from the guild's verification requirements.
+        const BYPASSES_VERIFICATION = 1 << 2;
+        /// The member has started onboarding.
+        const STARTED_ONBOARDING = 1 << 3;
+    }
+}
+
+impl<'de> Deserialize<'de> for MemberFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(MemberFlags::from_bits_truncate(deserializer.deserialize_u64(U64Visitor)?))
+    }
+}
+
+impl Serialize for MemberFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
 impl Display for Member {
     /// Mentions the user so that they receive a notification.
     ///
@@ -83,3 +144,213 @@ impl Display for Member {
         Display::fmt(&self.user.mention(), f)
     }
 }
+
+impl Member {
+    /// Whether the member is boosting the guild.
+    #[inline]
+    pub fn is_booster(&self) -> bool { self.premium_since.is_some() }
+
+    /// Whether the member is currently timed out.
+    pub fn is_timed_out(&self) -> bool {
+        self.communication_disabled_until
+            .map_or(false, |until| until > Utc::now())
+    }
+}
+
+#[cfg(feature = "model")]
+impl Member {
+    /// Gives a role to the member, updating the local list of roles on
+    /// success.
+    ///
+    /// If the member already has the role, then nothing is done.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` feature is enabled, then returns a
+    /// [`ModelError::ManagedRole`] if the role is managed by an integration,
+    /// or a [`ModelError::Hierarchy`] if the role is not below the current
+    /// user's highest role.
+    ///
+    /// [`ModelError::Hierarchy`]: enum.ModelError.html#variant.Hierarchy
+    /// [`ModelError::ManagedRole`]: enum.ModelError.html#variant.ManagedRole
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    pub fn add_role<R: Into<RoleId>>(&mut self, role_id: R) -> Result<()> {
+        let role_id = role_id.into();
+
+        if self.roles.contains(&role_id) {
+            return Ok(());
+        }
+
+        #[cfg(feature = "cache")]
+        self.check_assignable(&[role_id])?;
+
+        http::add_member_role(self.guild_id.0, self.user.id.0, role_id.0)?;
+        self.roles.push(role_id);
+
+        Ok(())
+    }
+
+    /// Gives multiple roles to the member in a single request, updating the
+    /// local list of roles on success.
+    ///
+    /// Refer to [`add_role`] for the errors that may be returned.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`add_role`]: #method.add_role
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    pub fn add_roles(&mut self, role_ids: &[RoleId]) -> Result<()> {
+        let mut roles = self.roles.clone();
+
+        for role_id in role_ids {
+            if !roles.contains(role_id) {
+                roles.push(*role_id);
+            }
+        }
+
+        #[cfg(feature = "cache")]
+        self.check_assignable(role_ids)?;
+
+        self.set_roles(roles)
+    }
+
+    /// Disconnects the member from the voice channel they are connected to.
+    ///
+    /// **Note**: Requires the [Move Members] permission.
+    ///
+    /// [Move Members]: permissions/constant.MOVE_MEMBERS.html
+    #[inline]
+    pub fn disconnect_from_voice(&self) -> Result<()> {
+        self.edit_field("channel_id", Value::Null)
+    }
+
+    /// Changes the member's nickname, or removes it if `None` is given, and
+    /// updates the local member on success.
+    ///
+    /// **Note**: Requires the [Manage Nicknames] permission.
+    ///
+    /// [Manage Nicknames]: permissions/constant.MANAGE_NICKNAMES.html
+    pub fn edit_nick(&mut self, nick: Option<&str>) -> Result<()> {
+        let value = nick.map_or(Value::Null, |nick| Value::String(nick.to_string()));
+
+        self.edit_field("nick", value)?;
+        self.nick = nick.map(|nick| nick.to_string());
+
+        Ok(())
+    }
+
+    /// Kicks the member from the guild, with a reason that is shown in the
+    /// audit log.
+    ///
+    /// **Note**: Requires the [Kick Members] permission.
+    ///
+    /// [Kick Members]: permissions/constant.KICK_MEMBERS.html
+    #[inline]
+    pub fn kick_with_reason(&self, reason: &str) -> Result<()> {
+        self.guild_id.kick(self.user.id, Some(reason))
+    }
+
+    /// Moves the member to a voice channel.
+    ///
+    /// The member must already be connected to a voice channel in the guild.
+    ///
+    /// **Note**: Requires the [Move Members] permission.
+    ///
+    /// [Move Members]: permissions/constant.MOVE_MEMBERS.html
+    #[inline]
+    pub fn move_to_voice_channel<C: Into<ChannelId>>(&self, channel_id: C) -> Result<()> {
+        self.edit_field("channel_id", Value::String(channel_id.into().0.to_string()))
+    }
+
+    /// Takes a role away from the member, updating the local list of roles on
+    /// success.
+    ///
+    /// If the member does not have the role, then nothing is done.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// # Errors
+    ///
+    /// Refer to [`add_role`] for the errors that may be returned.
+    ///
+    /// [`add_role`]: #method.add_role
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    pub fn remove_role<R: Into<RoleId>>(&mut self, role_id: R) -> Result<()> {
+        let role_id = role_id.into();
+
+        if !self.roles.contains(&role_id) {
+            return Ok(());
+        }
+
+        #[cfg(feature = "cache")]
+        self.check_assignable(&[role_id])?;
+
+        http::remove_member_role(self.guild_id.0, self.user.id.0, role_id.0)?;
+        self.roles.retain(|r| *r != role_id);
+
+        Ok(())
+    }
+
+    /// Takes multiple roles away from the member in a single request,
+    /// updating the local list of roles on success.
+    ///
+    /// Refer to [`add_role`] for the errors that may be returned.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`add_role`]: #method.add_role
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    pub fn remove_roles(&mut self, role_ids: &[RoleId]) -> Result<()> {
+        let roles = self.roles
+            .iter()
+            .filter(|role_id| !role_ids.contains(role_id))
+            .cloned()
+            .collect::<Vec<RoleId>>();
+
+        #[cfg(feature = "cache")]
+        self.check_assignable(role_ids)?;
+
+        self.set_roles(roles)
+    }
+
+    /// Checks against the cached guild that none of the roles are managed,
+    /// and that each is below the current user's highest role.
+    #[cfg(feature = "cache")]
+    fn check_assignable(&self, role_ids: &[RoleId]) -> Result<()> {
+        let cache = CACHE.read();
+
+        let guild = match cache.guild(self.guild_id) {
+            Some(guild) => guild,
+            None => return Ok(()),
+        };
+        let guild = guild.read();
+
+        for role_id in role_ids {
+            if guild.roles.get(role_id).map_or(false, |role| role.is_managed()) {
+                return Err(Error::Model(ModelError::ManagedRole));
+            }
+
+            guild.check_role_hierarchy(cache.user.id, *role_id)?;
+        }
+
+        Ok(())
+    }
+
+    fn edit_field(&self, key: &str, value: Value) -> Result<()> {
+        let mut map = JsonMap::new();
+        map.insert(key.to_string(), value);
+
+        http::edit_member(self.guild_id.0, self.user.id.0, &map)
+    }
+
+    fn set_roles(&mut self, roles: Vec<RoleId>) -> Result<()> {
+        let value = Value::Array(roles.iter().map(|r| Value::String(r.0.to_string())).collect());
+
+        self.edit_field("roles", value)?;
+        self.roles = roles;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use serde::ser::{Error as SerError, Serializer};
+use serde_json;
+use std::result::Result as StdResult;
+
+/// A guild's membership screening form, which new members must complete
+/// before they can interact with the guild.
+///
+/// While a member has not completed it, their [`pending`] field is `true`.
+///
+/// [`pending`]: struct.Member.html#structfield.pending
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MembershipScreening {
+    /// The description of the guild shown on the form.
+    pub description: Option<String>,
+    /// The fields of the form.
+    #[serde(default)]
+    pub form_fields: Vec<MembershipScreeningField>,
+    /// When the form was last modified, which must be given back when
+    /// completing it.
+    pub version: String,
+}
+
+/// A field of a [`MembershipScreening`] form.
+///
+/// [`MembershipScreening`]: struct.MembershipScreening.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MembershipScreeningField {
+    /// The type of the field.
+    pub field_type: MembershipScreeningFieldType,
+    /// The title of the field.
+    pub label: String,
+    /// The values of the field, such as the list of rules for a
+    /// [`Terms`] field.
+    ///
+    /// [`Terms`]: enum.MembershipScreeningFieldType.html#variant.Terms
+    #[serde(default)]
+    pub values: Vec<String>,
+    /// Whether the member must respond to the field.
+    pub required: bool,
+    /// The member's response to the field, when completing the form.
+    ///
+    /// For [`Terms`] fields, this is whether the rules were accepted.
+    ///
+    /// [`Terms`]: enum.MembershipScreeningFieldType.html#variant.Terms
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<bool>,
+}
+
+/// The type of a [`MembershipScreeningField`].
+///
+/// [`MembershipScreeningField`]: struct.MembershipScreeningField.html
+#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum MembershipScreeningFieldType {
+    /// A list of rules which the member must agree to.
+    #[serde(rename = "TERMS")] Terms,
+}
+
+/// The payload used to edit a guild's membership screening form.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EditMembershipScreening {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(serialize_with = "serialize_form_fields", skip_serializing_if = "Option::is_none")]
+    form_fields: Option<Vec<MembershipScreeningField>>,
+}
+
+impl EditMembershipScreening {
+    /// Sets the description of the guild shown on the form.
+    pub fn description<S: ToString>(mut self, description: S) -> Self {
+        self.description = Some(description.to_string());
+
+        self
+    }
+
+    /// Sets whether new members must complete the form.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+
+        self
+    }
+
+    /// Sets the fields of the form, replacing all existing fields.
+    pub fn form_fields(mut self, form_fields: &[MembershipScreeningField]) -> Self {
+        self.form_fields = Some(form_fields.to_vec());
+
+        self
+    }
+}
+
+/// Serializes the fields of a form as a JSON-encoded string, which is how
+/// Discord expects them.
+fn serialize_form_fields<S>(form_fields: &Option<Vec<MembershipScreeningField>>, serializer: S)
+    -> StdResult<S::Ok, S::Error> where S: Serializer {
+    match *form_fields {
+        Some(ref form_fields) => {
+            let encoded = serde_json::to_string(form_fields).map_err(S::Error::custom)?;
+
+            serializer.serialize_str(&encoded)
+        },
+        None => serializer.serialize_none(),
+    }
+}
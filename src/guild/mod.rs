@@ -1,21 +1,63 @@
+mod diff;
 mod emoji;
 mod guild_id;
 mod integration;
 mod member;
+mod membership_screening;
+mod onboarding;
 mod partial_guild;
 mod role;
+mod scheduled_event;
+mod snapshot;
+mod update;
+mod welcome_screen;
 mod audit_log;
 
+pub use self::diff::*;
 pub use self::emoji::*;
 pub use self::guild_id::*;
 pub use self::integration::*;
 pub use self::member::*;
+pub use self::membership_screening::*;
+pub use self::onboarding::*;
 pub use self::partial_guild::*;
 pub use self::role::*;
+pub use self::scheduled_event::*;
+pub use self::snapshot::*;
+pub use self::welcome_screen::*;
 pub use self::audit_log::*;
 
 use chrono::{DateTime, FixedOffset};
-use ::*;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serenity_common::prelude::{JsonMap, Number, Value};
+use std::collections::HashSet;
+use std::result::Result as StdResult;
+use super::limits::{
+    self,
+    LimitError,
+    AFK_TIMEOUTS,
+    MAX_GUILD_NAME_LENGTH,
+    MIN_GUILD_NAME_LENGTH,
+};
+use super::permissions::{PermissionCalculator, Permissions};
+use super::{
+    ChannelId,
+    ChannelType,
+    EmojiId,
+    GuildChannel,
+    GuildId,
+    IdMap,
+    Locale,
+    OnlineStatus,
+    PermissionOverwrite,
+    PermissionOverwriteType,
+    Presence,
+    RoleId,
+    User,
+    UserId,
+    VoiceState,
+};
 use serde::de::Error as DeError;
 use serde_json;
 use super::utils::*;
@@ -25,11 +67,17 @@ use CACHE;
 #[cfg(feature = "model")]
 use http;
 #[cfg(feature = "model")]
-use builder::{EditGuild, EditMember, EditRole};
+use builder::EditMember;
 #[cfg(feature = "model")]
 use constants::LARGE_THRESHOLD;
 #[cfg(feature = "model")]
+use internal::prelude::*;
+#[cfg(feature = "model")]
 use std;
+#[cfg(feature = "model")]
+use std::path::Path;
+#[cfg(feature = "model")]
+use super::{ChannelPosition, CreateChannel, IntegrationId, ModelError, ThreadsData};
 
 /// A representation of a banning of a user.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Hash)]
@@ -48,16 +96,27 @@ pub struct Guild {
     /// The amount of seconds a user can not show any activity in a voice
     /// channel before being moved to an AFK channel -- if one exists.
     pub afk_timeout: u64,
+    /// The hash of the guild's banner, if it has one.
+    pub banner: Option<String>,
+    /// The guild's bans, keyed by the Id of the banned user.
+    ///
+    /// These are not sent by the gateway, so this is `None` until populated,
+    /// such as by a cache fetching the guild's bans. Once populated, it is
+    /// kept up to date by [`apply_ban_add`] and [`apply_ban_remove`].
+    ///
+    /// [`apply_ban_add`]: #method.apply_ban_add
+    /// [`apply_ban_remove`]: #method.apply_ban_remove
+    pub bans: Option<IdMap<UserId, Ban>>,
     /// All voice and text channels contained within a guild.
     ///
     /// This contains all channels regardless of permissions (i.e. the ability
     /// of the bot to read from or connect to them).
-    pub channels: HashMap<ChannelId, GuildChannel>,
+    pub channels: IdMap<ChannelId, GuildChannel>,
     /// Indicator of whether notifications for all messages are enabled by
     /// default in the guild.
     pub default_message_notifications: u64,
     /// All of the guild's custom emojis.
-    pub emojis: HashMap<EmojiId, Emoji>,
+    pub emojis: IdMap<EmojiId, Emoji>,
     /// VIP features enabled for the guild. Can be obtained through the
     /// [Discord Partnership] website.
     ///
@@ -74,6 +133,9 @@ pub struct Guild {
     ///
     /// In the client, this appears on the guild list on the left-hand side.
     pub icon: Option<String>,
+    /// The security actions currently in effect for the guild, such as paused
+    /// invites after a raid.
+    pub incidents_data: Option<IncidentsData>,
     /// The unique Id identifying the guild.
     ///
     /// This is equivilant to the Id of the default role (`@everyone`) and also
@@ -92,7 +154,7 @@ pub struct Guild {
     /// the library.
     ///
     /// [`ReadyEvent`]: events/struct.ReadyEvent.html
-    pub members: HashMap<UserId, Member>,
+    pub members: IdMap<UserId, Member>,
     /// Indicator of whether the guild requires multi-factor authentication for
     /// [`Role`]s or [`User`]s with moderation permissions.
     ///
@@ -105,14 +167,27 @@ pub struct Guild {
     ///
     /// [`User`]: struct.User.html
     pub owner_id: UserId,
+    /// The locale that the guild uses for community features, such as system
+    /// messages and server discovery.
+    pub preferred_locale: Option<String>,
+    /// The guild's server boost level.
+    pub premium_tier: PremiumTier,
     /// A mapping of [`User`]s' Ids to their current presences.
     ///
     /// [`User`]: struct.User.html
-    pub presences: HashMap<UserId, Presence>,
+    pub presences: IdMap<UserId, Presence>,
     /// The region that the voice servers that the guild uses are located in.
+    ///
+    /// **Note**: Discord has replaced this with the per-channel
+    /// [`GuildChannel::rtc_region`], and no longer uses it for voice
+    /// connections. Use [`effective_rtc_region`] to find the region of a
+    /// voice channel.
+    ///
+    /// [`GuildChannel::rtc_region`]: struct.GuildChannel.html#structfield.rtc_region
+    /// [`effective_rtc_region`]: #method.effective_rtc_region
     pub region: String,
     /// A mapping of the guild's roles.
-    pub roles: HashMap<RoleId, Role>,
+    pub roles: IdMap<RoleId, Role>,
     /// An identifying hash of the guild's splash icon.
     ///
     /// If the [`InviteSplash`] feature is enabled, this can be used to generate
@@ -120,64 +195,501 @@ pub struct Guild {
     ///
     /// [`InviteSplash`]: enum.Feature.html#variant.InviteSplash
     pub splash: Option<String>,
+    /// The settings of the guild's system channel, controlling which system
+    /// messages are sent to it.
+    pub system_channel_flags: SystemChannelFlags,
+    /// The Id of the channel that system messages, such as member joins, are
+    /// sent to.
+    pub system_channel_id: Option<ChannelId>,
     /// Indicator of the current verification level of the guild.
     pub verification_level: VerificationLevel,
     /// A mapping of of [`User`]s to their current voice state.
     ///
     /// [`User`]: struct.User.html
-    pub voice_states: HashMap<UserId, VoiceState>,
+    pub voice_states: IdMap<UserId, VoiceState>,
 }
 
 impl Guild {
-    /// Calculate a [`Member`]'s permissions in the guild.
+    /// Gets all of the active threads in the guild that the current user can
+    /// view.
     ///
-    /// [`Member`]: struct.Member.html
-    pub fn member_permissions<U>(&self, user_id: U) -> Permissions
-        where U: Into<UserId> {
+    /// Refer to [`GuildId::active_threads`] for more information.
+    ///
+    /// [`GuildId::active_threads`]: struct.GuildId.html#method.active_threads
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn active_threads(&self) -> Result<ThreadsData> { self.id.active_threads() }
+
+    /// Retrieves the maximum bitrate of the guild's voice channels, in bits
+    /// per second.
+    ///
+    /// Guilds with the `VIP_REGIONS` feature have the highest limit
+    /// regardless of their [`premium_tier`].
+    ///
+    /// [`premium_tier`]: #structfield.premium_tier
+    pub fn bitrate_limit(&self) -> u64 {
+        if self.has_feature("VIP_REGIONS") {
+            return 384_000;
+        }
+
+        match self.premium_tier {
+            PremiumTier::None => 96_000,
+            PremiumTier::Tier1 => 128_000,
+            PremiumTier::Tier2 => 256_000,
+            PremiumTier::Tier3 => 384_000,
+        }
+    }
+
+    /// Retrieves the members who are boosting the guild, from the longest
+    /// boosting to the most recent.
+    pub fn boosters(&self) -> Vec<&Member> {
+        let mut boosters = self.members
+            .values()
+            .filter(|member| member.is_booster())
+            .collect::<Vec<&Member>>();
+        boosters.sort_by_key(|member| member.premium_since);
+
+        boosters
+    }
+
+    /// Retrieves the guild's channels in the order that the official client
+    /// displays them.
+    ///
+    /// Channels without a category come first, followed by each category and
+    /// the channels within it. Within a category, text channels are listed
+    /// before voice channels, and otherwise by position.
+    pub fn channels_sorted(&self) -> Vec<&GuildChannel> {
+        let mut channels = self.channels.values().collect::<Vec<&GuildChannel>>();
+
+        channels.sort_by_key(|channel| {
+            let category = if channel.kind == ChannelType::Category {
+                Some((channel.position, channel.id))
+            } else {
+                channel.category_id
+                    .and_then(|id| self.channels.get(&id))
+                    .map(|category| (category.position, category.id))
+            };
+
+            (
+                category,
+                channel.kind != ChannelType::Category,
+                channel.kind == ChannelType::Voice,
+                channel.position,
+                channel.id,
+            )
+        });
+
+        channels
+    }
+
+    /// Retrieves the channels within a category, in the order that the
+    /// official client displays them.
+    ///
+    /// Text channels are listed before voice channels, and otherwise by
+    /// position. Threads are not included.
+    pub fn channels_in_category<C: Into<ChannelId>>(&self, category_id: C) -> Vec<&GuildChannel> {
+        let category_id = Some(category_id.into());
+
+        let mut channels = self.channels
+            .values()
+            .filter(|channel| channel.category_id == category_id && !channel.is_thread())
+            .collect::<Vec<&GuildChannel>>();
+
+        channels.sort_by_key(|channel| {
+            (channel.kind == ChannelType::Voice, channel.position, channel.id)
+        });
+
+        channels
+    }
+
+    /// Computes the permission overwrites needed to stop the given roles from
+    /// talking in the guild's channels, without applying them.
+    ///
+    /// Text and announcement channels deny [Send Messages] and
+    /// [Send Messages in Threads], voice channels deny [Connect], and
+    /// categories deny all three. Each role's existing overwrite is kept,
+    /// other than removing these permissions from what it allows.
+    ///
+    /// Channels whose overwrites already deny the permissions are left out, as
+    /// are threads, which inherit their parent's overwrites. The plan is
+    /// ordered as the official client displays the channels.
+    ///
+    /// [Connect]: permissions/constant.CONNECT.html
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    /// [Send Messages in Threads]: permissions/constant.SEND_MESSAGES_IN_THREADS.html
+    pub fn plan_lockdown(&self, roles_to_deny: &[RoleId]) -> Vec<(ChannelId, PermissionOverwrite)> {
+        let text = Permissions::SEND_MESSAGES | Permissions::SEND_MESSAGES_IN_THREADS;
+        let mut plan = Vec::new();
+
+        for channel in self.channels_sorted() {
+            let locked = match channel.kind {
+                ChannelType::Text | ChannelType::News => text,
+                ChannelType::Voice => Permissions::CONNECT,
+                ChannelType::Category => text | Permissions::CONNECT,
+                _ => continue,
+            };
+
+            for role_id in roles_to_deny {
+                let kind = PermissionOverwriteType::Role(*role_id);
+
+                let (allow, deny) = channel.overwrite_for(kind)
+                    .map_or((Permissions::empty(), Permissions::empty()), |overwrite| {
+                        (overwrite.allow, overwrite.deny)
+                    });
+
+                if deny.contains(locked) && !allow.intersects(locked) {
+                    continue;
+                }
+
+                plan.push((channel.id, PermissionOverwrite {
+                    allow: allow - locked,
+                    deny: deny | locked,
+                    kind: kind,
+                }));
+            }
+        }
+
+        plan
+    }
+
+    /// Calculates the Id of the shard that receives the guild's events, out
+    /// of the given total number of shards.
+    ///
+    /// Refer to [`GuildId::shard_id`] for more information.
+    ///
+    /// [`GuildId::shard_id`]: struct.GuildId.html#method.shard_id
+    #[inline]
+    pub fn shard_id(&self, total_shards: u64) -> u64 { self.id.shard_id(total_shards) }
+
+    /// Checks that a member is able to manage a role, as their highest role
+    /// is above it in the role hierarchy.
+    ///
+    /// The owner of the guild is always able to manage every role.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::Hierarchy`] if the member's highest role is not
+    /// above the target role, or if either can not be found.
+    ///
+    /// [`ModelError::Hierarchy`]: enum.ModelError.html#variant.Hierarchy
+    #[cfg(feature = "model")]
+    pub fn check_role_hierarchy<U, R>(&self, user_id: U, role_id: R) -> Result<()>
+        where U: Into<UserId>, R: Into<RoleId> {
         let user_id = user_id.into();
 
         if user_id == self.owner_id {
-            return Permissions::all();
+            return Ok(());
         }
 
-        let everyone = match self.roles.get(&RoleId(self.id.0)) {
-            Some(everyone) => everyone,
-            None => {
-                error!(
-                    "(╯°□°）╯︵ ┻━┻ @everyone role ({}) missing in '{}'",
-                    self.id,
-                    self.name,
-                );
-
-                return Permissions::empty();
-            },
+        let target = match self.roles.get(&role_id.into()) {
+            Some(role) => role,
+            None => return Err(Error::Model(ModelError::Hierarchy)),
         };
 
-        let member = match self.members.get(&user_id) {
-            Some(member) => member,
-            None => return everyone.permissions,
-        };
+        match self.member_highest_role(user_id) {
+            Some(highest) if highest > target => Ok(()),
+            _ => Err(Error::Model(ModelError::Hierarchy)),
+        }
+    }
 
-        let mut permissions = everyone.permissions;
+    /// Creates a channel in the guild, configured through the [`CreateChannel`]
+    /// builder.
+    ///
+    /// Refer to [`GuildId::create_channel`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// [`CreateChannel`]: struct.CreateChannel.html
+    /// [`GuildId::create_channel`]: struct.GuildId.html#method.create_channel
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn create_channel<F>(&self, f: F) -> Result<GuildChannel>
+        where F: FnOnce(CreateChannel) -> CreateChannel {
+        self.id.create_channel(f)
+    }
 
-        for role in &member.roles {
-            if let Some(role) = self.roles.get(&role) {
-                if role.permissions.contains(Permissions::ADMINISTRATOR) {
-                    return Permissions::all();
-                }
+    /// Creates an emoji in the guild from the raw bytes of an image.
+    ///
+    /// Refer to [`GuildId::create_emoji`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// [`GuildId::create_emoji`]: struct.GuildId.html#method.create_emoji
+    /// [Manage Emojis]: permissions/constant.MANAGE_EMOJIS.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn create_emoji(&self, name: &str, image: &[u8]) -> Result<Emoji> {
+        self.id.create_emoji(name, image)
+    }
 
-                permissions |= role.permissions;
-            } else {
-                warn!(
-                    "(╯°□°）╯︵ ┻━┻ {} on {} has non-existent role {:?}",
-                    member.user.id,
-                    self.id,
-                    role,
-                );
+    /// Creates an emoji in the guild from an image file on disk.
+    ///
+    /// Refer to [`GuildId::create_emoji_from_path`] for more information.
+    ///
+    /// [`GuildId::create_emoji_from_path`]: struct.GuildId.html#method.create_emoji_from_path
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn create_emoji_from_path<P: AsRef<Path>>(&self, name: &str, path: P) -> Result<Emoji> {
+        self.id.create_emoji_from_path(name, path)
+    }
+
+    /// Attaches an integration of the given kind to the guild.
+    ///
+    /// Refer to [`GuildId::create_integration`] for more information.
+    ///
+    /// [`GuildId::create_integration`]: struct.GuildId.html#method.create_integration
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn create_integration<I>(&self, integration_id: I, kind: &str) -> Result<()>
+        where I: Into<IntegrationId> {
+        self.id.create_integration(integration_id, kind)
+    }
+
+    /// Creates a role in the guild, configured through the [`EditRole`]
+    /// builder.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`EditRole`]: struct.EditRole.html
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn create_role<F>(&self, f: F) -> Result<Role>
+        where F: FnOnce(EditRole) -> EditRole {
+        self.id.create_role(f)
+    }
+
+    /// Creates a scheduled event in the guild, configured through the
+    /// [`CreateScheduledEvent`] builder.
+    ///
+    /// Refer to [`GuildId::create_scheduled_event`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Events] permission.
+    ///
+    /// [`CreateScheduledEvent`]: struct.CreateScheduledEvent.html
+    /// [`GuildId::create_scheduled_event`]: struct.GuildId.html#method.create_scheduled_event
+    /// [Manage Events]: permissions/constant.MANAGE_EVENTS.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn create_scheduled_event<F>(&self, f: F) -> Result<GuildScheduledEvent>
+        where F: FnOnce(CreateScheduledEvent) -> CreateScheduledEvent {
+        self.id.create_scheduled_event(f)
+    }
+
+    /// Edits the guild, configured through the [`EditGuild`] builder, and
+    /// updates the local guild with the result.
+    ///
+    /// Refer to [`GuildId::edit`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::NotGuildOwner`] if
+    /// ownership is being transferred through [`EditGuild::owner`] and the
+    /// current user is not the owner of the guild.
+    ///
+    /// [`EditGuild`]: struct.EditGuild.html
+    /// [`EditGuild::owner`]: struct.EditGuild.html#method.owner
+    /// [`GuildId::edit`]: struct.GuildId.html#method.edit
+    /// [`ModelError::NotGuildOwner`]: enum.ModelError.html#variant.NotGuildOwner
+    #[cfg(feature = "model")]
+    pub fn edit<F>(&mut self, f: F) -> Result<()>
+        where F: FnOnce(EditGuild) -> EditGuild {
+        let edit = f(EditGuild::default());
+
+        #[cfg(feature = "cache")]
+        {
+            if edit.owner.is_some() && !self.is_owner(CACHE.read().user.id) {
+                return Err(Error::Model(ModelError::NotGuildOwner));
             }
         }
 
-        permissions
+        let guild = self.id.edit(|_| edit)?;
+
+        self.afk_channel_id = guild.afk_channel_id;
+        self.afk_timeout = guild.afk_timeout;
+        self.banner = guild.banner;
+        self.icon = guild.icon;
+        self.name = guild.name;
+        self.owner_id = guild.owner_id;
+        self.preferred_locale = guild.preferred_locale;
+        self.splash = guild.splash;
+        self.system_channel_flags = guild.system_channel_flags;
+        self.system_channel_id = guild.system_channel_id;
+        self.verification_level = guild.verification_level;
+
+        Ok(())
+    }
+
+    /// Edits the guild's membership screening form, configured through the
+    /// [`EditMembershipScreening`] builder.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`EditMembershipScreening`]: struct.EditMembershipScreening.html
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn edit_membership_screening<F>(&self, f: F) -> Result<MembershipScreening>
+        where F: FnOnce(EditMembershipScreening) -> EditMembershipScreening {
+        self.id.edit_membership_screening(f)
+    }
+
+    /// Sets the multi-factor authentication requirement for moderation
+    /// actions, returning the new level.
+    ///
+    /// Refer to [`GuildId::edit_mfa_level`] for more information.
+    ///
+    /// [`GuildId::edit_mfa_level`]: struct.GuildId.html#method.edit_mfa_level
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn edit_mfa_level(&self, level: MfaLevel) -> Result<MfaLevel> {
+        self.id.edit_mfa_level(level)
+    }
+
+    /// Replaces the guild's onboarding, after checking it with
+    /// [`validate_onboarding`].
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidOnboarding`] if the onboarding would be
+    /// rejected by Discord.
+    ///
+    /// [`ModelError::InvalidOnboarding`]: enum.ModelError.html#variant.InvalidOnboarding
+    /// [`validate_onboarding`]: #method.validate_onboarding
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    #[cfg(feature = "model")]
+    pub fn edit_onboarding(&self, onboarding: &Onboarding) -> Result<Onboarding> {
+        if let Err(why) = self.validate_onboarding(onboarding) {
+            return Err(Error::Model(ModelError::InvalidOnboarding(why)));
+        }
+
+        self.id.edit_onboarding(onboarding)
+    }
+
+    /// Edits the guild's widget settings, configured through the
+    /// [`EditWidget`] builder.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`EditWidget`]: struct.EditWidget.html
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn edit_widget<F>(&self, f: F) -> Result<GuildEmbed>
+        where F: FnOnce(EditWidget) -> EditWidget {
+        self.id.edit_widget(f)
+    }
+
+    /// Retrieves the voice region of a channel in the guild.
+    ///
+    /// This is the channel's [`rtc_region`] if one is set, and otherwise the
+    /// guild's legacy [`region`], for bots that still rely on it. If the
+    /// channel is not in the guild, the guild's region is returned.
+    ///
+    /// [`region`]: #structfield.region
+    /// [`rtc_region`]: struct.GuildChannel.html#structfield.rtc_region
+    pub fn effective_rtc_region<C: Into<ChannelId>>(&self, channel_id: C) -> &str {
+        self.channels
+            .get(&channel_id.into())
+            .and_then(|channel| channel.rtc_region.region())
+            .unwrap_or(&self.region)
+    }
+
+    /// Retrieves the emojis that a member is allowed to use, sorted by name.
+    ///
+    /// An emoji can be used by everyone if it is not limited to any roles, and
+    /// otherwise only by members with at least one of its roles.
+    pub fn emojis_usable_by(&self, member: &Member) -> Vec<&Emoji> {
+        let mut emojis = self.emojis
+            .values()
+            .filter(|emoji| {
+                emoji.roles.is_empty() || emoji.roles.iter().any(|role| member.roles.contains(role))
+            })
+            .collect::<Vec<&Emoji>>();
+        emojis.sort_by(|a, b| a.name.cmp(&b.name));
+
+        emojis
+    }
+
+    /// Retrieves the maximum number of custom emojis the guild can have, of
+    /// each of static and animated emojis.
+    ///
+    /// Guilds with the `MORE_EMOJI` feature can have at least `200`.
+    pub fn emoji_limit(&self) -> u64 {
+        let limit = match self.premium_tier {
+            PremiumTier::None => 50,
+            PremiumTier::Tier1 => 100,
+            PremiumTier::Tier2 => 150,
+            PremiumTier::Tier3 => 250,
+        };
+
+        if self.has_feature("MORE_EMOJI") {
+            limit.max(200)
+        } else {
+            limit
+        }
+    }
+
+    /// Retrieves the maximum size of a file uploaded to the guild, in bytes.
+    ///
+    /// Guilds below the second boost level share Discord's base limit of
+    /// 10MiB.
+    pub fn filesize_limit(&self) -> u64 {
+        match self.premium_tier {
+            PremiumTier::None | PremiumTier::Tier1 => 10 * 1024 * 1024,
+            PremiumTier::Tier2 => 50 * 1024 * 1024,
+            PremiumTier::Tier3 => 100 * 1024 * 1024,
+        }
+    }
+
+    /// Gets the integrations attached to the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn integrations(&self) -> Result<Vec<Integration>> { self.id.integrations() }
+
+    /// Checks whether a user is banned from the guild.
+    ///
+    /// Returns `None` if the guild's [`bans`] have not been populated.
+    ///
+    /// [`bans`]: #structfield.bans
+    pub fn is_banned<U: Into<UserId>>(&self, user_id: U) -> Option<bool> {
+        self.bans.as_ref().map(|bans| bans.contains_key(&user_id.into()))
+    }
+
+    /// Whether the user is the owner of the guild.
+    #[inline]
+    pub fn is_owner<U: Into<UserId>>(&self, user_id: U) -> bool { user_id.into() == self.owner_id }
+
+    /// Retrieves the highest [`Role`] of a [`Member`] in the hierarchy.
+    ///
+    /// Returns `None` if the member could not be found or has no roles.
+    ///
+    /// [`Member`]: struct.Member.html
+    /// [`Role`]: struct.Role.html
+    pub fn member_highest_role<U: Into<UserId>>(&self, user_id: U) -> Option<&Role> {
+        self.members
+            .get(&user_id.into())?
+            .roles
+            .iter()
+            .filter_map(|role_id| self.roles.get(role_id))
+            .max()
+    }
+
+    /// Calculate a [`Member`]'s permissions in the guild.
+    ///
+    /// [`Member`]: struct.Member.html
+    pub fn member_permissions<U>(&self, user_id: U) -> Permissions
+        where U: Into<UserId> {
+        self.permission_calculator(user_id.into())
+            .map_or_else(Permissions::empty, |calculator| calculator.base())
     }
 
     /// Gets a list of all the members (satisfying the status provided to the function) in this
@@ -280,26 +792,26 @@ impl Guild {
                     let name_a = match a.nick {
                         Some(ref nick) => {
                             if contains_case_insensitive(&a.user.name[..], prefix) {
-                                a.user.name.clone()
+                                &a.user.name[..]
                             } else {
-                                nick.clone()
+                                &nick[..]
                             }
                         },
-                        None => a.user.name.clone(),
+                        None => &a.user.name[..],
                     };
 
                     let name_b = match b.nick {
                         Some(ref nick) => {
                             if contains_case_insensitive(&b.user.name[..], prefix) {
-                                b.user.name.clone()
+                                &b.user.name[..]
                             } else {
-                                nick.clone()
+                                &nick[..]
                             }
                         },
-                        None => b.user.name.clone(),
+                        None => &b.user.name[..],
                     };
 
-                    closest_to_origin(prefix, &name_a[..], &name_b[..])
+                    closest_to_origin(prefix, name_a, name_b)
                 });
             members
         } else {
@@ -356,26 +868,26 @@ impl Guild {
                     let name_a = match a.nick {
                         Some(ref nick) => {
                             if contains_case_insensitive(&a.user.name[..], substring) {
-                                a.user.name.clone()
+                                &a.user.name[..]
                             } else {
-                                nick.clone()
+                                &nick[..]
                             }
                         },
-                        None => a.user.name.clone(),
+                        None => &a.user.name[..],
                     };
 
                     let name_b = match b.nick {
                         Some(ref nick) => {
                             if contains_case_insensitive(&b.user.name[..], substring) {
-                                b.user.name.clone()
+                                &b.user.name[..]
                             } else {
-                                nick.clone()
+                                &nick[..]
                             }
                         },
-                        None => b.user.name.clone(),
+                        None => &b.user.name[..],
                     };
 
-                    closest_to_origin(substring, &name_a[..], &name_b[..])
+                    closest_to_origin(substring, name_a, name_b)
                 });
             members
         } else {
@@ -413,9 +925,9 @@ impl Guild {
         if sorted {
             members
                 .sort_by(|a, b| {
-                    let name_a = &a.user.name;
-                    let name_b = &b.user.name;
-                    closest_to_origin(substring, &name_a[..], &name_b[..])
+                    let name_a = &a.user.name[..];
+                    let name_b = &b.user.name[..];
+                    closest_to_origin(substring, name_a, name_b)
                 });
             members
         } else {
@@ -460,20 +972,16 @@ impl Guild {
             members
                 .sort_by(|a, b| {
                     let name_a = match a.nick {
-                        Some(ref nick) => {
-                            nick.clone()
-                        },
-                        None => a.user.name.clone(),
+                        Some(ref nick) => &nick[..],
+                        None => &a.user.name[..],
                     };
 
                     let name_b = match b.nick {
-                        Some(ref nick) => {
-                                nick.clone()
-                            },
-                        None => b.user.name.clone(),
+                        Some(ref nick) => &nick[..],
+                        None => &b.user.name[..],
                     };
 
-                    closest_to_origin(substring, &name_a[..], &name_b[..])
+                    closest_to_origin(substring, name_a, name_b)
                 });
             members
         } else {
@@ -481,132 +989,332 @@ impl Guild {
         }
     }
 
+    /// Gets the guild's membership screening form.
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn membership_screening(&self) -> Result<MembershipScreening> {
+        self.id.membership_screening()
+    }
+
+    /// Retrieves the [`Member`] that owns the guild.
+    ///
+    /// Returns `None` if the owner is not in the guild's [`members`], such as
+    /// when the guild is too large for all members to have been received.
+    ///
+    /// [`Member`]: struct.Member.html
+    /// [`members`]: #structfield.members
+    #[inline]
+    pub fn owner(&self) -> Option<&Member> { self.members.get(&self.owner_id) }
+
     /// Calculate a [`User`]'s permissions in a given channel in the guild.
     ///
+    /// Permissions which depend on another permission are implicitly denied
+    /// without it, such as message permissions without [Read Messages], and
+    /// a [timed out] member only keeps [Read Messages] and
+    /// [Read Message History].
+    ///
     /// [`User`]: struct.User.html
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    /// [Read Messages]: permissions/constant.READ_MESSAGES.html
+    /// [timed out]: struct.Member.html#method.is_timed_out
     pub fn permissions_in<C, U>(&self, channel_id: C, user_id: U) -> Permissions
         where C: Into<ChannelId>, U: Into<UserId> {
+        let channel_id = channel_id.into();
+
+        let mut calculator = match self.permission_calculator(user_id.into()) {
+            Some(calculator) => calculator,
+            None => return Permissions::empty(),
+        };
+
+        if let Some(channel) = self.channels.get(&channel_id) {
+            calculator.channel_kind = Some(channel.kind);
+            calculator.overwrites = &channel.permission_overwrites;
+        } else {
+            warn!(
+                "(╯°□°）╯︵ ┻━┻ Guild {} does not contain channel {}",
+                self.id,
+                channel_id
+            );
+        }
+
+        calculator.channel_id = Some(channel_id);
+
+        calculator.calculate()
+    }
+
+    /// Moves channels to new positions within the guild's channel list,
+    /// optionally moving them between categories.
+    ///
+    /// Refer to [`GuildId::reorder_channels`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// [`GuildId::reorder_channels`]: struct.GuildId.html#method.reorder_channels
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn reorder_channels<It, P>(&self, positions: It) -> Result<()>
+        where It: IntoIterator<Item = P>, P: Into<ChannelPosition> {
+        self.id.reorder_channels(positions)
+    }
+
+    /// Moves roles to new positions within the guild's role list, returning
+    /// all of the guild's roles after the change.
+    ///
+    /// Positions can be given either as [`RolePosition`]s or as tuples of a
+    /// role Id and its position. They are checked with
+    /// [`validate_role_positions`] before being sent.
+    ///
+    /// Every role being moved, both at its current and its new position, must
+    /// be below the acting member's highest role.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidRolePositions`] if the positions are
+    /// inconsistent, or a [`ModelError::Hierarchy`] if the acting member is
+    /// not able to move one of the roles.
+    ///
+    /// [`ModelError::Hierarchy`]: enum.ModelError.html#variant.Hierarchy
+    /// [`ModelError::InvalidRolePositions`]: enum.ModelError.html#variant.InvalidRolePositions
+    /// [`RolePosition`]: struct.RolePosition.html
+    /// [`validate_role_positions`]: #method.validate_role_positions
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    #[cfg(feature = "model")]
+    pub fn reorder_roles<U, It, P>(&self, user_id: U, positions: It) -> Result<Vec<Role>>
+        where U: Into<UserId>, It: IntoIterator<Item = P>, P: Into<RolePosition> {
         let user_id = user_id.into();
+        let positions = positions.into_iter().map(Into::into).collect::<Vec<RolePosition>>();
 
-        // The owner has all permissions in all cases.
-        if user_id == self.owner_id {
-            return Permissions::all();
+        if let Err(why) = self.validate_role_positions(&positions) {
+            return Err(Error::Model(ModelError::InvalidRolePositions(why)));
         }
 
-        let channel_id = channel_id.into();
+        if user_id != self.owner_id {
+            let highest = self.member_highest_role(user_id).map_or(0, |role| role.position);
 
-        // Start by retrieving the @everyone role's permissions.
-        let everyone = match self.roles.get(&RoleId(self.id.0)) {
-            Some(everyone) => everyone,
-            None => {
-                error!(
-                    "(╯°□°）╯︵ ┻━┻ @everyone role ({}) missing in '{}'",
-                    self.id,
-                    self.name
-                );
+            for role in &positions {
+                self.check_role_hierarchy(user_id, role.id)?;
 
-                return Permissions::empty();
-            },
-        };
+                if role.position >= highest {
+                    return Err(Error::Model(ModelError::Hierarchy));
+                }
+            }
+        }
+
+        self.id.reorder_roles(positions)
+    }
 
-        // Create a base set of permissions, starting with `@everyone`s.
-        let mut permissions = everyone.permissions;
+    /// Retrieves the guild's roles from the highest in the hierarchy to the
+    /// lowest, as the official client displays them.
+    pub fn roles_sorted(&self) -> Vec<&Role> {
+        let mut roles = self.roles.values().collect::<Vec<&Role>>();
+        roles.sort_by(|a, b| b.cmp(a));
 
-        let member = match self.members.get(&user_id) {
-            Some(member) => member,
-            None => return everyone.permissions,
+        roles
+    }
+
+    /// Retrieves the maximum number of custom stickers the guild can have.
+    ///
+    /// Guilds with the `MORE_STICKERS` feature can have at least `60`.
+    pub fn sticker_limit(&self) -> u64 {
+        let limit = match self.premium_tier {
+            PremiumTier::None => 5,
+            PremiumTier::Tier1 => 15,
+            PremiumTier::Tier2 => 30,
+            PremiumTier::Tier3 => 60,
         };
 
-        for &role in &member.roles {
-            if let Some(role) = self.roles.get(&role) {
-                permissions |= role.permissions;
-            } else {
-                warn!(
-                    "(╯°□°）╯︵ ┻━┻ {} on {} has non-existent role {:?}",
-                    member.user.id,
-                    self.id,
-                    role
-                );
-            }
+        if self.has_feature("MORE_STICKERS") {
+            limit.max(60)
+        } else {
+            limit
+        }
+    }
+
+    /// Checks that onboarding meets Discord's requirements before it is sent.
+    ///
+    /// Every channel and role must exist in the guild, there may be at most
+    /// [`MAX_ONBOARDING_PROMPTS`] prompts, and each prompt must have between
+    /// one and [`MAX_ONBOARDING_PROMPT_OPTIONS`] options.
+    ///
+    /// When onboarding is enabled, at least [`MIN_ONBOARDING_CHANNELS`]
+    /// channels must count towards its requirements, and `@everyone` must be
+    /// able to send messages in at least
+    /// [`MIN_ONBOARDING_CHATTABLE_CHANNELS`] of them. Which channels count is
+    /// decided by the onboarding's [`mode`].
+    ///
+    /// [`MAX_ONBOARDING_PROMPTS`]: constant.MAX_ONBOARDING_PROMPTS.html
+    /// [`MAX_ONBOARDING_PROMPT_OPTIONS`]: constant.MAX_ONBOARDING_PROMPT_OPTIONS.html
+    /// [`MIN_ONBOARDING_CHANNELS`]: constant.MIN_ONBOARDING_CHANNELS.html
+    /// [`MIN_ONBOARDING_CHATTABLE_CHANNELS`]: constant.MIN_ONBOARDING_CHATTABLE_CHANNELS.html
+    /// [`mode`]: struct.Onboarding.html#structfield.mode
+    pub fn validate_onboarding(&self, onboarding: &Onboarding)
+        -> StdResult<(), OnboardingError> {
+        if onboarding.prompts.len() > MAX_ONBOARDING_PROMPTS {
+            return Err(OnboardingError::TooManyPrompts(onboarding.prompts.len()));
         }
 
-        // Administrators have all permissions in any channel.
-        if permissions.contains(Permissions::ADMINISTRATOR) {
-            return Permissions::all();
+        let mut channels = HashSet::new();
+
+        for &channel_id in &onboarding.default_channel_ids {
+            channels.insert(channel_id);
         }
 
-        if let Some(channel) = self.channels.get(&channel_id) {
-            // If this is a text channel, then throw out voice permissions.
-            if channel.kind == ChannelType::Text {
-                permissions &= !(Permissions::CONNECT
-                    | Permissions::SPEAK
-                    | Permissions::MUTE_MEMBERS
-                    | Permissions::DEAFEN_MEMBERS
-                    | Permissions::MOVE_MEMBERS
-                    | Permissions::USE_VAD);
+        for prompt in &onboarding.prompts {
+            if prompt.options.is_empty() {
+                return Err(OnboardingError::EmptyPrompt);
             }
 
-            // Apply the permission overwrites for the channel for each of the
-            // overwrites that - first - applies to the member's roles, and then
-            // the member itself.
-            //
-            // First apply the denied permission overwrites for each, then apply
-            // the allowed.
-
-            // Roles
-            for overwrite in &channel.permission_overwrites {
-                if let PermissionOverwriteType::Role(role) = overwrite.kind {
-                    if role.0 != self.id.0 && !member.roles.contains(&role) {
-                        continue;
+            if prompt.options.len() > MAX_ONBOARDING_PROMPT_OPTIONS {
+                return Err(OnboardingError::TooManyOptions(prompt.options.len()));
+            }
+
+            for option in &prompt.options {
+                if let Some(&role_id) = option.role_ids
+                    .iter()
+                    .find(|role_id| !self.roles.contains_key(role_id)) {
+                    return Err(OnboardingError::UnknownRole(role_id));
+                }
+
+                for &channel_id in &option.channel_ids {
+                    if !self.channels.contains_key(&channel_id) {
+                        return Err(OnboardingError::UnknownChannel(channel_id));
                     }
 
-                    permissions = (permissions & !overwrite.deny) | overwrite.allow;
+                    if onboarding.mode == OnboardingMode::Advanced {
+                        channels.insert(channel_id);
+                    }
                 }
             }
+        }
 
-            // Member
-            for overwrite in &channel.permission_overwrites {
-                if PermissionOverwriteType::Member(user_id) != overwrite.kind {
-                    continue;
-                }
+        let everyone = self.roles
+            .get(&self.id.everyone_role())
+            .map_or_else(Permissions::empty, |role| role.permissions);
+        let mut chattable = 0;
+
+        for &channel_id in &channels {
+            let channel = match self.channels.get(&channel_id) {
+                Some(channel) => channel,
+                None => return Err(OnboardingError::UnknownChannel(channel_id)),
+            };
 
-                permissions = (permissions & !overwrite.deny) | overwrite.allow;
+            let mut calculator = PermissionCalculator::new(self.id, UserId(0), everyone);
+            calculator.channel_id = Some(channel_id);
+            calculator.channel_kind = Some(channel.kind);
+            calculator.overwrites = &channel.permission_overwrites;
+
+            if calculator.calculate().contains(Permissions::SEND_MESSAGES) {
+                chattable += 1;
             }
-        } else {
-            warn!(
-                "(╯°□°）╯︵ ┻━┻ Guild {} does not contain channel {}",
-                self.id,
-                channel_id
-            );
         }
 
-        // The default channel is always readable.
-        if channel_id.0 == self.id.0 {
-            permissions |= Permissions::READ_MESSAGES;
+        if onboarding.enabled {
+            if channels.len() < MIN_ONBOARDING_CHANNELS {
+                return Err(OnboardingError::TooFewChannels(channels.len()));
+            }
+
+            if chattable < MIN_ONBOARDING_CHATTABLE_CHANNELS {
+                return Err(OnboardingError::TooFewChattableChannels(chattable));
+            }
         }
 
-        // No SEND_MESSAGES => no message-sending-related actions
-        // If the member does not have the `SEND_MESSAGES` permission, then
-        // throw out message-able permissions.
-        if !permissions.contains(Permissions::SEND_MESSAGES) {
-            permissions &= !(Permissions::SEND_TTS_MESSAGES
-                | Permissions::MENTION_EVERYONE
-                | Permissions::EMBED_LINKS
-                | Permissions::ATTACH_FILES);
+        Ok(())
+    }
+
+    /// Checks that a set of new role positions is consistent before it is
+    /// sent to Discord.
+    ///
+    /// Each role must exist in the guild and be given only one position, no
+    /// two roles may be given the same position, and the `@everyone` role must
+    /// stay at position `0` with every other role above it.
+    pub fn validate_role_positions(&self, positions: &[RolePosition])
+        -> StdResult<(), RolePositionError> {
+        let everyone_role = self.id.everyone_role();
+        let mut roles = HashSet::with_capacity(positions.len());
+        let mut taken = HashSet::with_capacity(positions.len());
+
+        for role in positions {
+            if !self.roles.contains_key(&role.id) {
+                return Err(RolePositionError::UnknownRole(role.id));
+            }
+
+            if !roles.insert(role.id) {
+                return Err(RolePositionError::DuplicateRole(role.id));
+            }
+
+            if (role.id == everyone_role) != (role.position == 0) || role.position < 0 {
+                return Err(RolePositionError::EveryonePosition);
+            }
+
+            if !taken.insert(role.position) {
+                return Err(RolePositionError::DuplicatePosition(role.position));
+            }
         }
 
-        // If the member does not have the `READ_MESSAGES` permission, then
-        // throw out actionable permissions.
-        if !permissions.contains(Permissions::READ_MESSAGES) {
-            permissions &= Permissions::KICK_MEMBERS
-                | Permissions::BAN_MEMBERS
-                | Permissions::ADMINISTRATOR
-                | Permissions::MANAGE_GUILD
-                | Permissions::CHANGE_NICKNAME
-                | Permissions::MANAGE_NICKNAMES;
+        Ok(())
+    }
+
+    /// Retrieves the guild's vanity invite code and its number of uses.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: permissions/constant.MANAGE_GUILD.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn vanity_url(&self) -> Result<GuildVanityUrl> { self.id.vanity_url() }
+
+    /// Creates a [`PermissionCalculator`] for a user, filled in with the
+    /// guild's roles and, if they are a member, their roles.
+    ///
+    /// Returns `None` if the guild's `@everyone` role is missing, unless the
+    /// user is the owner.
+    ///
+    /// [`PermissionCalculator`]: permissions/struct.PermissionCalculator.html
+    fn permission_calculator(&self, user_id: UserId) -> Option<PermissionCalculator> {
+        let is_owner = user_id == self.owner_id;
+
+        let everyone = match self.roles.get(&self.id.everyone_role()) {
+            Some(everyone) => everyone.permissions,
+            None if is_owner => Permissions::empty(),
+            None => {
+                error!(
+                    "(╯°□°）╯︵ ┻━┻ @everyone role ({}) missing in '{}'",
+                    self.id,
+                    self.name,
+                );
+
+                return None;
+            },
+        };
+
+        let mut calculator = PermissionCalculator::new(self.id, user_id, everyone);
+        calculator.is_owner = is_owner;
+
+        if let Some(member) = self.members.get(&user_id) {
+            calculator.is_timed_out = member.is_timed_out();
+
+            for &role_id in &member.roles {
+                if let Some(role) = self.roles.get(&role_id) {
+                    calculator.member_roles.push((role_id, role.permissions));
+                } else {
+                    warn!(
+                        "(╯°□°）╯︵ ┻━┻ {} on {} has non-existent role {:?}",
+                        member.user.id,
+                        self.id,
+                        role_id,
+                    );
+                }
+            }
         }
 
-        permissions
+        Some(calculator)
+    }
+
+    fn has_feature(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f == feature)
     }
 }
 
@@ -636,6 +1344,15 @@ impl<'de> Deserialize<'de> for Guild {
                     }
                 }
             }
+
+            if let Some(array) = map.get_mut("emojis").and_then(|x| x.as_array_mut()) {
+                for value in array {
+                    if let Some(emoji) = value.as_object_mut() {
+                        emoji
+                            .insert("guild_id".to_string(), Value::Number(Number::from(guild_id)));
+                    }
+                }
+            }
         }
 
         let afk_channel_id = match map.remove("afk_channel_id") {
@@ -647,6 +1364,10 @@ impl<'de> Deserialize<'de> for Guild {
             .ok_or_else(|| DeError::custom("expected guild afk_timeout"))
             .and_then(u64::deserialize)
             .map_err(DeError::custom)?;
+        let banner = match map.remove("banner") {
+            Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
         let channels = map.remove("channels")
             .ok_or_else(|| DeError::custom("expected guild channels"))
             .and_then(deserialize_guild_channels)
@@ -669,6 +1390,10 @@ impl<'de> Deserialize<'de> for Guild {
             Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
             None => None,
         };
+        let incidents_data = match map.remove("incidents_data") {
+            Some(v) => Option::<IncidentsData>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
         let id = map.remove("id")
             .ok_or_else(|| DeError::custom("expected guild id"))
             .and_then(GuildId::deserialize)
@@ -701,6 +1426,14 @@ impl<'de> Deserialize<'de> for Guild {
             .ok_or_else(|| DeError::custom("expected guild owner_id"))
             .and_then(UserId::deserialize)
             .map_err(DeError::custom)?;
+        let preferred_locale = match map.remove("preferred_locale") {
+            Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
+        let premium_tier = match map.remove("premium_tier") {
+            Some(v) => PremiumTier::deserialize(v).map_err(DeError::custom)?,
+            None => PremiumTier::None,
+        };
         let presences = map.remove("presences")
             .ok_or_else(|| DeError::custom("expected guild presences"))
             .and_then(deserialize_presences)
@@ -717,6 +1450,14 @@ impl<'de> Deserialize<'de> for Guild {
             Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
             None => None,
         };
+        let system_channel_flags = match map.remove("system_channel_flags") {
+            Some(v) => SystemChannelFlags::deserialize(v).map_err(DeError::custom)?,
+            None => SystemChannelFlags::empty(),
+        };
+        let system_channel_id = match map.remove("system_channel_id") {
+            Some(v) => Option::<ChannelId>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
         let verification_level = map.remove("verification_level")
             .ok_or_else(|| DeError::custom("expected guild verification_level"))
             .and_then(VerificationLevel::deserialize)
@@ -729,12 +1470,15 @@ impl<'de> Deserialize<'de> for Guild {
         Ok(Self {
             afk_channel_id: afk_channel_id,
             afk_timeout: afk_timeout,
+            banner: banner,
+            bans: None,
             channels: channels,
             default_message_notifications: default_message_notifications,
             emojis: emojis,
             features: features,
             icon: icon,
             id: id,
+            incidents_data: incidents_data,
             joined_at: joined_at,
             large: large,
             member_count: member_count,
@@ -742,16 +1486,87 @@ impl<'de> Deserialize<'de> for Guild {
             mfa_level: mfa_level,
             name: name,
             owner_id: owner_id,
+            preferred_locale: preferred_locale,
+            premium_tier: premium_tier,
             presences: presences,
             region: region,
             roles: roles,
             splash: splash,
+            system_channel_flags: system_channel_flags,
+            system_channel_id: system_channel_id,
             verification_level: verification_level,
             voice_states: voice_states,
         })
     }
 }
 
+impl Serialize for Guild {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        // Maps keyed by Id are written as arrays, which is the shape that
+        // they are received from the gateway and deserialized from.
+        #[derive(Serialize)]
+        struct SerializedGuild<'a> {
+            afk_channel_id: &'a Option<ChannelId>,
+            afk_timeout: u64,
+            banner: &'a Option<String>,
+            channels: Vec<&'a GuildChannel>,
+            default_message_notifications: u64,
+            emojis: Vec<&'a Emoji>,
+            features: &'a [String],
+            icon: &'a Option<String>,
+            id: GuildId,
+            incidents_data: &'a Option<IncidentsData>,
+            joined_at: &'a DateTime<FixedOffset>,
+            large: bool,
+            member_count: u64,
+            members: Vec<&'a Member>,
+            mfa_level: u64,
+            name: &'a str,
+            owner_id: UserId,
+            preferred_locale: &'a Option<String>,
+            premium_tier: PremiumTier,
+            presences: Vec<&'a Presence>,
+            region: &'a str,
+            roles: Vec<&'a Role>,
+            splash: &'a Option<String>,
+            system_channel_flags: SystemChannelFlags,
+            system_channel_id: &'a Option<ChannelId>,
+            verification_level: VerificationLevel,
+            voice_states: Vec<&'a VoiceState>,
+        }
+
+        SerializedGuild {
+            afk_channel_id: &self.afk_channel_id,
+            afk_timeout: self.afk_timeout,
+            banner: &self.banner,
+            channels: self.channels.values().collect(),
+            default_message_notifications: self.default_message_notifications,
+            emojis: self.emojis.values().collect(),
+            features: &self.features,
+            icon: &self.icon,
+            id: self.id,
+            incidents_data: &self.incidents_data,
+            joined_at: &self.joined_at,
+            large: self.large,
+            member_count: self.member_count,
+            members: self.members.values().collect(),
+            mfa_level: self.mfa_level,
+            name: &self.name,
+            owner_id: self.owner_id,
+            preferred_locale: &self.preferred_locale,
+            premium_tier: self.premium_tier,
+            presences: self.presences.values().collect(),
+            region: &self.region,
+            roles: self.roles.values().collect(),
+            splash: &self.splash,
+            system_channel_flags: self.system_channel_flags,
+            system_channel_id: &self.system_channel_id,
+            verification_level: self.verification_level,
+            voice_states: self.voice_states.values().collect(),
+        }.serialize(serializer)
+    }
+}
+
 /// Checks if a `&str` contains another `&str`.
 fn contains_case_insensitive(to_look_at: &str, to_find: &str) -> bool {
     to_look_at.to_lowercase().contains(to_find)
@@ -792,6 +1607,253 @@ pub struct GuildEmbed {
     pub enabled: bool,
 }
 
+/// The payload used to edit a guild's widget settings.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EditWidget {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+}
+
+impl EditWidget {
+    /// Sets the channel that the widget generates invites to.
+    pub fn channel_id<C: Into<ChannelId>>(mut self, channel_id: C) -> Self {
+        self.channel_id = Some(channel_id.into());
+
+        self
+    }
+
+    /// Sets whether the widget is enabled.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+
+        self
+    }
+}
+
+/// The security actions in effect for a guild, such as those taken
+/// automatically after a raid is detected.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IncidentsData {
+    /// When DM spam was detected in the guild, if it was.
+    #[serde(default)]
+    pub dm_spam_detected_at: Option<DateTime<FixedOffset>>,
+    /// When DMs between members who are not friends are re-enabled, if they
+    /// are disabled.
+    #[serde(default)]
+    pub dms_disabled_until: Option<DateTime<FixedOffset>>,
+    /// When invites to the guild are re-enabled, if they are disabled.
+    #[serde(default)]
+    pub invites_disabled_until: Option<DateTime<FixedOffset>>,
+    /// When a raid was detected in the guild, if one was.
+    #[serde(default)]
+    pub raid_detected_at: Option<DateTime<FixedOffset>>,
+}
+
+/// The payload used to edit a guild's incident actions.
+///
+/// Each action can be enabled until a time up to 24 hours in the future, or
+/// disabled by setting it to `None`. Actions that are not set are left
+/// unchanged.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EditIncidentActions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dms_disabled_until: Option<Option<DateTime<FixedOffset>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invites_disabled_until: Option<Option<DateTime<FixedOffset>>>,
+}
+
+impl EditIncidentActions {
+    /// Sets when DMs between members who are not friends are re-enabled.
+    pub fn dms_disabled_until(mut self, until: Option<DateTime<FixedOffset>>) -> Self {
+        self.dms_disabled_until = Some(until);
+
+        self
+    }
+
+    /// Sets when invites to the guild are re-enabled.
+    pub fn invites_disabled_until(mut self, until: Option<DateTime<FixedOffset>>) -> Self {
+        self.invites_disabled_until = Some(until);
+
+        self
+    }
+}
+
+/// The payload used to edit a guild.
+///
+/// Fields that are not set are left unchanged.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EditGuild {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    afk_channel_id: Option<Option<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    afk_timeout: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    banner: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(rename = "owner_id", skip_serializing_if = "Option::is_none")]
+    owner: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preferred_locale: Option<Locale>,
+    #[serde(skip)]
+    pub(crate) reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    splash: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_channel_flags: Option<SystemChannelFlags>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_channel_id: Option<Option<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verification_level: Option<VerificationLevel>,
+}
+
+impl EditGuild {
+    /// Sets the channel that inactive members are moved to, or removes it by
+    /// passing `None`.
+    pub fn afk_channel<C: Into<ChannelId>>(mut self, channel: Option<C>) -> Self {
+        self.afk_channel_id = Some(channel.map(Into::into));
+
+        self
+    }
+
+    /// Sets the number of seconds after which inactive members are moved to
+    /// the AFK channel, which must be one of the [`AFK_TIMEOUTS`].
+    ///
+    /// [`AFK_TIMEOUTS`]: limits/constant.AFK_TIMEOUTS.html
+    pub fn afk_timeout(mut self, timeout: u64) -> Self {
+        self.afk_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Sets the banner of the guild, as a data URI of the image, or removes it
+    /// by passing `None`.
+    ///
+    /// **Note**: Banners are only available to guilds with the `BANNER`
+    /// feature.
+    pub fn banner(mut self, banner: Option<&str>) -> Self {
+        self.banner = Some(banner.map(str::to_string));
+
+        self
+    }
+
+    /// Sets the icon of the guild, as a data URI of the image, or removes it
+    /// by passing `None`.
+    pub fn icon(mut self, icon: Option<&str>) -> Self {
+        self.icon = Some(icon.map(str::to_string));
+
+        self
+    }
+
+    /// Sets the name of the guild.
+    ///
+    /// The name must be between [`MIN_GUILD_NAME_LENGTH`] and
+    /// [`MAX_GUILD_NAME_LENGTH`] characters long.
+    ///
+    /// [`MAX_GUILD_NAME_LENGTH`]: limits/constant.MAX_GUILD_NAME_LENGTH.html
+    /// [`MIN_GUILD_NAME_LENGTH`]: limits/constant.MIN_GUILD_NAME_LENGTH.html
+    pub fn name<S: ToString>(mut self, name: S) -> Self {
+        self.name = Some(name.to_string());
+
+        self
+    }
+
+    /// Transfers ownership of the guild to another member.
+    ///
+    /// **Note**: Only the owner of the guild can transfer it. When edited
+    /// through [`Guild::edit`] with the `cache` enabled, this is checked
+    /// before the request is made.
+    ///
+    /// [`Guild::edit`]: struct.Guild.html#method.edit
+    pub fn owner<U: Into<UserId>>(mut self, owner: U) -> Self {
+        self.owner = Some(owner.into());
+
+        self
+    }
+
+    /// Sets the locale used for the guild's discovery listing and for notices
+    /// from Discord.
+    pub fn preferred_locale(mut self, locale: Locale) -> Self {
+        self.preferred_locale = Some(locale);
+
+        self
+    }
+
+    /// Sets the reason for the change, which is shown in the guild's audit log.
+    pub fn reason<S: ToString>(mut self, reason: S) -> Self {
+        self.reason = Some(reason.to_string());
+
+        self
+    }
+
+    /// Sets the invite splash of the guild, as a data URI of the image, or
+    /// removes it by passing `None`.
+    ///
+    /// **Note**: Invite splashes are only available to guilds with the
+    /// `INVITE_SPLASH` feature.
+    pub fn splash(mut self, splash: Option<&str>) -> Self {
+        self.splash = Some(splash.map(str::to_string));
+
+        self
+    }
+
+    /// Sets the channel that system messages are sent to, or removes it by
+    /// passing `None`.
+    pub fn system_channel<C: Into<ChannelId>>(mut self, channel: Option<C>) -> Self {
+        self.system_channel_id = Some(channel.map(Into::into));
+
+        self
+    }
+
+    /// Sets which messages are suppressed in the system channel.
+    pub fn system_channel_flags(mut self, flags: SystemChannelFlags) -> Self {
+        self.system_channel_flags = Some(flags);
+
+        self
+    }
+
+    /// Sets the verification level that members must meet before they can
+    /// send messages.
+    pub fn verification_level(mut self, level: VerificationLevel) -> Self {
+        self.verification_level = Some(level);
+
+        self
+    }
+
+    /// Validates the guild against Discord's [limits] and converts it into a
+    /// value suitable for editing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LimitError`] if the name is too short or too long, or if
+    /// the AFK timeout is not one that Discord allows.
+    ///
+    /// [`LimitError`]: limits/enum.LimitError.html
+    /// [limits]: limits/index.html
+    pub fn to_value(&self) -> StdResult<Value, LimitError> {
+        if let Some(ref name) = self.name {
+            limits::check_length(
+                name,
+                MIN_GUILD_NAME_LENGTH,
+                MAX_GUILD_NAME_LENGTH,
+                LimitError::GuildNameLength,
+            )?;
+        }
+
+        if let Some(timeout) = self.afk_timeout {
+            if !AFK_TIMEOUTS.contains(&timeout) {
+                return Err(LimitError::AfkTimeout(timeout));
+            }
+        }
+
+        serde_json::to_value(self).map_err(|why| LimitError::Serialization(why.to_string()))
+    }
+}
+
 /// Representation of the number of members that would be pruned by a guild
 /// prune operation.
 #[derive(Clone, Copy, Debug, Deserialize)]
@@ -807,6 +1869,9 @@ pub struct GuildInfo {
     ///
     /// Can be used to calculate creation date.
     pub id: GuildId,
+    /// The features enabled for the guild, such as `COMMUNITY`.
+    #[serde(default)]
+    pub features: Vec<String>,
     /// The hash of the icon of the guild.
     ///
     /// This can be used to generate a URL to the guild's icon image.
@@ -819,16 +1884,113 @@ pub struct GuildInfo {
     pub permissions: Permissions,
 }
 
-impl From<PartialGuild> for GuildContainer {
-    fn from(guild: PartialGuild) -> GuildContainer { GuildContainer::Guild(guild) }
+impl GuildInfo {
+    /// Returns the formatted URL of the guild's icon, if the guild has an icon.
+    pub fn icon_url(&self) -> Option<String> {
+        self.icon.as_ref().map(|icon| icon_url(self.id, icon))
+    }
+}
+
+/// Formats the URL of a guild's icon from its hash.
+pub(crate) fn icon_url(guild_id: GuildId, icon: &str) -> String {
+    format!("https://cdn.discordapp.com/icons/{}/{}.webp", guild_id, icon)
+}
+
+/// A borrowed reference to a guild, used by helpers that can work with either
+/// a full [`Guild`], a [`PartialGuild`], or only the guild's Id.
+///
+/// Unlike [`GuildContainer`], this does not require ownership of the guild.
+///
+/// [`Guild`]: struct.Guild.html
+/// [`GuildContainer`]: enum.GuildContainer.html
+/// [`PartialGuild`]: struct.PartialGuild.html
+#[derive(Clone, Copy, Debug)]
+pub enum GuildRef<'a> {
+    /// A reference to a full guild.
+    Guild(&'a Guild),
+    /// A reference to a partial guild.
+    PartialGuild(&'a PartialGuild),
+    /// Only the guild's Id, which can be used to search the cache for a guild.
+    Id(GuildId),
+}
+
+impl<'a> GuildRef<'a> {
+    /// Retrieves the Id of the referenced guild.
+    pub fn id(&self) -> GuildId {
+        match *self {
+            GuildRef::Guild(guild) => guild.id,
+            GuildRef::PartialGuild(guild) => guild.id,
+            GuildRef::Id(id) => id,
+        }
+    }
+
+    /// Retrieves the name of the referenced guild, if guild data is available.
+    pub fn name(&self) -> Option<&'a str> {
+        match *self {
+            GuildRef::Guild(guild) => Some(&guild.name),
+            GuildRef::PartialGuild(guild) => Some(&guild.name),
+            GuildRef::Id(_) => None,
+        }
+    }
+
+    /// Retrieves the roles of the referenced guild, if guild data is available.
+    pub fn roles(&self) -> Option<&'a IdMap<RoleId, Role>> {
+        match *self {
+            GuildRef::Guild(guild) => Some(&guild.roles),
+            GuildRef::PartialGuild(guild) => Some(&guild.roles),
+            GuildRef::Id(_) => None,
+        }
+    }
+
+    /// Retrieves a role of the referenced guild by its Id, if guild data is
+    /// available and the role exists.
+    pub fn role<R: Into<RoleId>>(&self, role_id: R) -> Option<&'a Role> {
+        self.roles().and_then(|roles| roles.get(&role_id.into()))
+    }
+}
+
+impl<'a> From<&'a Guild> for GuildRef<'a> {
+    fn from(guild: &'a Guild) -> GuildRef<'a> { GuildRef::Guild(guild) }
+}
+
+impl<'a> From<&'a PartialGuild> for GuildRef<'a> {
+    fn from(guild: &'a PartialGuild) -> GuildRef<'a> { GuildRef::PartialGuild(guild) }
 }
 
-impl From<GuildId> for GuildContainer {
-    fn from(guild_id: GuildId) -> GuildContainer { GuildContainer::Id(guild_id) }
+impl<'a> From<GuildId> for GuildRef<'a> {
+    fn from(guild_id: GuildId) -> GuildRef<'a> { GuildRef::Id(guild_id) }
 }
 
-impl From<u64> for GuildContainer {
-    fn from(id: u64) -> GuildContainer { GuildContainer::Id(GuildId(id)) }
+impl<'a> From<u64> for GuildRef<'a> {
+    fn from(id: u64) -> GuildRef<'a> { GuildRef::Id(GuildId(id)) }
+}
+
+bitflags! {
+    /// Describes which system messages are suppressed in a guild's system
+    /// channel.
+    #[derive(Default)]
+    pub struct SystemChannelFlags: u64 {
+        /// Member join notifications are not sent.
+        const SUPPRESS_JOIN_NOTIFICATIONS = 1;
+        /// Server boost notifications are not sent.
+        const SUPPRESS_PREMIUM_SUBSCRIPTIONS = 1 << 1;
+        /// Server setup tips are not sent.
+        const SUPPRESS_GUILD_REMINDER_NOTIFICATIONS = 1 << 2;
+        /// Sticker reply buttons are hidden on member join notifications.
+        const SUPPRESS_JOIN_NOTIFICATION_REPLIES = 1 << 3;
+    }
+}
+
+impl<'de> Deserialize<'de> for SystemChannelFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(SystemChannelFlags::from_bits_truncate(deserializer.deserialize_u64(U64Visitor)?))
+    }
+}
+
+impl Serialize for SystemChannelFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_u64(self.bits())
+    }
 }
 
 /// Data for an unavailable guild.
@@ -853,6 +2015,60 @@ pub enum GuildStatus {
     Offline(GuildUnavailable),
 }
 
+impl GuildStatus {
+    /// Retrieves the Id of the guild, whether or not it is available.
+    pub fn id(&self) -> GuildId {
+        match *self {
+            GuildStatus::OnlinePartialGuild(ref guild) => guild.id,
+            GuildStatus::OnlineGuild(ref guild) => guild.id,
+            GuildStatus::Offline(ref guild) => guild.id,
+        }
+    }
+
+    /// Calculates the Id of the shard that receives the guild's events, out
+    /// of the given total number of shards.
+    ///
+    /// Refer to [`GuildId::shard_id`] for more information.
+    ///
+    /// [`GuildId::shard_id`]: struct.GuildId.html#method.shard_id
+    #[inline]
+    pub fn shard_id(&self, total_shards: u64) -> u64 { self.id().shard_id(total_shards) }
+}
+
+enum_number!(
+    #[doc="The multi-factor authentication requirement for moderation actions
+in a [`Guild`].
+
+[`Guild`]: struct.Guild.html"]
+    MfaLevel {
+        /// Moderators are not required to have multi-factor authentication enabled.
+        None = 0,
+        /// Moderators must have multi-factor authentication enabled.
+        Elevated = 1,
+    }
+);
+
+enum_number!(
+    #[doc="The server boost level of a [`Guild`], which unlocks higher limits
+and additional features.
+
+[`Guild`]: struct.Guild.html"]
+    PremiumTier {
+        /// The guild has not reached the first boost level.
+        None = 0,
+        /// The guild has reached the first boost level.
+        Tier1 = 1,
+        /// The guild has reached the second boost level.
+        Tier2 = 2,
+        /// The guild has reached the third boost level.
+        Tier3 = 3,
+    }
+);
+
+impl Default for PremiumTier {
+    fn default() -> Self { PremiumTier::None }
+}
+
 enum_number!(
     #[doc="The level to set as criteria prior to a user being able to send
     messages in a [`Guild`].
@@ -871,3 +2087,25 @@ enum_number!(
         Higher = 4,
     }
 );
+
+#[cfg(test)]
+mod tests {
+    use super::super::limits::{LimitError, MAX_GUILD_NAME_LENGTH, MIN_GUILD_NAME_LENGTH};
+    use super::EditGuild;
+
+    #[test]
+    fn edit_guild_name_length() {
+        let name = |length| EditGuild::default().name("é".repeat(length)).to_value();
+
+        assert_eq!(
+            name(MIN_GUILD_NAME_LENGTH - 1).unwrap_err(),
+            LimitError::GuildNameLength(MIN_GUILD_NAME_LENGTH - 1),
+        );
+        assert!(name(MIN_GUILD_NAME_LENGTH).is_ok());
+        assert!(name(MAX_GUILD_NAME_LENGTH).is_ok());
+        assert_eq!(
+            name(MAX_GUILD_NAME_LENGTH + 1).unwrap_err(),
+            LimitError::GuildNameLength(MAX_GUILD_NAME_LENGTH + 1),
+        );
+    }
+}
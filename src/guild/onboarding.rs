@@ -0,0 +1,174 @@
+use std::error::Error as StdError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use super::super::utils::{deserialize_u64, serialize_emoji};
+use super::super::{ChannelId, GuildId, ReactionType, RoleId};
+
+/// The minimum number of channels that onboarding must show new members.
+pub const MIN_ONBOARDING_CHANNELS: usize = 7;
+
+/// The minimum number of channels shown by onboarding in which `@everyone`
+/// must be able to send messages.
+pub const MIN_ONBOARDING_CHATTABLE_CHANNELS: usize = 5;
+
+/// The maximum number of prompts that onboarding may have.
+pub const MAX_ONBOARDING_PROMPTS: usize = 15;
+
+/// The maximum number of options that an onboarding prompt may have.
+pub const MAX_ONBOARDING_PROMPT_OPTIONS: usize = 50;
+
+enum_number!(
+    #[doc="Which channels count towards the requirements of a guild's
+[`Onboarding`].
+
+[`Onboarding`]: struct.Onboarding.html"]
+    OnboardingMode {
+        /// Only the default channels count.
+        Default = 0,
+        /// The default channels and the channels of prompt options count.
+        Advanced = 1,
+    }
+);
+
+impl Default for OnboardingMode {
+    fn default() -> Self { OnboardingMode::Default }
+}
+
+enum_number!(
+    #[doc="How the options of an [`OnboardingPrompt`] are shown.
+
+[`OnboardingPrompt`]: struct.OnboardingPrompt.html"]
+    OnboardingPromptType {
+        /// The options are shown as a list of buttons.
+        MultipleChoice = 0,
+        /// The options are shown in a dropdown.
+        Dropdown = 1,
+    }
+);
+
+/// The flow that new members of a guild go through to pick their channels and
+/// roles.
+///
+/// This is also the payload used to edit the onboarding, which replaces all of
+/// its prompts.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Onboarding {
+    /// The Id of the guild.
+    pub guild_id: GuildId,
+    /// The channels that new members are added to by default.
+    #[serde(default)]
+    pub default_channel_ids: Vec<ChannelId>,
+    /// Whether onboarding is enabled.
+    pub enabled: bool,
+    /// Which channels count towards the onboarding requirements.
+    #[serde(default)]
+    pub mode: OnboardingMode,
+    /// The prompts shown to new members.
+    #[serde(default)]
+    pub prompts: Vec<OnboardingPrompt>,
+}
+
+/// A question shown to new members during [`Onboarding`].
+///
+/// [`Onboarding`]: struct.Onboarding.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OnboardingPrompt {
+    /// The Id of the prompt.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub id: u64,
+    /// Whether the prompt is shown during onboarding, rather than only in the
+    /// Channels & Roles tab.
+    pub in_onboarding: bool,
+    /// How the prompt's options are shown.
+    #[serde(rename = "type")]
+    pub kind: OnboardingPromptType,
+    /// The options that new members can pick from.
+    #[serde(default)]
+    pub options: Vec<OnboardingPromptOption>,
+    /// Whether the prompt must be answered to complete onboarding.
+    pub required: bool,
+    /// Whether only one option can be picked.
+    pub single_select: bool,
+    /// The question asked by the prompt.
+    pub title: String,
+}
+
+/// An option of an [`OnboardingPrompt`], which adds the member to channels and
+/// gives them roles when picked.
+///
+/// [`OnboardingPrompt`]: struct.OnboardingPrompt.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OnboardingPromptOption {
+    /// The Id of the option.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub id: u64,
+    /// The channels that the member is added to.
+    #[serde(default)]
+    pub channel_ids: Vec<ChannelId>,
+    /// The description of the option.
+    pub description: Option<String>,
+    /// The emoji shown beside the option.
+    #[serde(default, serialize_with = "serialize_emoji", skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ReactionType>,
+    /// The roles that the member is given.
+    #[serde(default)]
+    pub role_ids: Vec<RoleId>,
+    /// The title of the option.
+    pub title: String,
+}
+
+/// An error returned when validating [`Onboarding`] before it is sent.
+///
+/// Refer to [`Guild::validate_onboarding`] for the checks performed.
+///
+/// [`Guild::validate_onboarding`]: struct.Guild.html#method.validate_onboarding
+/// [`Onboarding`]: struct.Onboarding.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OnboardingError {
+    /// A prompt had no options.
+    EmptyPrompt,
+    /// Fewer than [`MIN_ONBOARDING_CHATTABLE_CHANNELS`] of the counted
+    /// channels allow `@everyone` to send messages. The number of such
+    /// channels is given.
+    ///
+    /// [`MIN_ONBOARDING_CHATTABLE_CHANNELS`]: constant.MIN_ONBOARDING_CHATTABLE_CHANNELS.html
+    TooFewChattableChannels(usize),
+    /// Fewer than [`MIN_ONBOARDING_CHANNELS`] channels count towards the
+    /// requirements. The number of counted channels is given.
+    ///
+    /// [`MIN_ONBOARDING_CHANNELS`]: constant.MIN_ONBOARDING_CHANNELS.html
+    TooFewChannels(usize),
+    /// A prompt had more than [`MAX_ONBOARDING_PROMPT_OPTIONS`] options. The
+    /// number of options is given.
+    ///
+    /// [`MAX_ONBOARDING_PROMPT_OPTIONS`]: constant.MAX_ONBOARDING_PROMPT_OPTIONS.html
+    TooManyOptions(usize),
+    /// More than [`MAX_ONBOARDING_PROMPTS`] prompts were given. The number of
+    /// prompts is given.
+    ///
+    /// [`MAX_ONBOARDING_PROMPTS`]: constant.MAX_ONBOARDING_PROMPTS.html
+    TooManyPrompts(usize),
+    /// A channel does not exist in the guild.
+    UnknownChannel(ChannelId),
+    /// A role does not exist in the guild.
+    UnknownRole(RoleId),
+}
+
+impl Display for OnboardingError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(self.description()) }
+}
+
+impl StdError for OnboardingError {
+    fn description(&self) -> &str {
+        match *self {
+            OnboardingError::EmptyPrompt => "Onboarding prompt has no options",
+            OnboardingError::TooFewChattableChannels(_) => {
+                "Fewer than 5 onboarding channels allow @everyone to send messages"
+            },
+            OnboardingError::TooFewChannels(_) => "Onboarding has fewer than 7 channels",
+            OnboardingError::TooManyOptions(_) => "Onboarding prompt has more than 50 options",
+            OnboardingError::TooManyPrompts(_) => "Onboarding has more than 15 prompts",
+            OnboardingError::UnknownChannel(_) => "Onboarding channel is not in the guild",
+            OnboardingError::UnknownRole(_) => "Onboarding role is not in the guild",
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use ::*;
+use super::super::{ChannelId, EmojiId, GuildId, IdMap, RoleId, UserId};
+use super::{Emoji, IncidentsData, PremiumTier, Role, SystemChannelFlags, VerificationLevel};
 use super::super::utils::{deserialize_emojis, deserialize_roles};
 
 #[cfg(feature = "model")]
-use builder::{EditGuild, EditMember, EditRole};
+use builder::EditMember;
 
 /// Partial information about a [`Guild`]. This does not include information
 /// like member data.
@@ -13,10 +14,11 @@ pub struct PartialGuild {
     pub id: GuildId,
     pub afk_channel_id: Option<ChannelId>,
     pub afk_timeout: u64,
+    pub banner: Option<String>,
     pub default_message_notifications: u64,
     pub embed_channel_id: Option<ChannelId>,
     pub embed_enabled: bool,
-    #[serde(deserialize_with = "deserialize_emojis")] pub emojis: HashMap<EmojiId, Emoji>,
+    #[serde(deserialize_with = "deserialize_emojis")] pub emojis: IdMap<EmojiId, Emoji>,
     /// Features enabled for the guild.
     ///
     /// Refer to [`Guild::features`] for more information.
@@ -24,11 +26,27 @@ pub struct PartialGuild {
     /// [`Guild::features`]: struct.Guild.html#structfield.features
     pub features: Vec<String>,
     pub icon: Option<String>,
+    #[serde(default)] pub incidents_data: Option<IncidentsData>,
     pub mfa_level: u64,
     pub name: String,
     pub owner_id: UserId,
+    pub preferred_locale: Option<String>,
+    #[serde(default)] pub premium_tier: PremiumTier,
     pub region: String,
-    #[serde(deserialize_with = "deserialize_roles")] pub roles: HashMap<RoleId, Role>,
+    #[serde(deserialize_with = "deserialize_roles")] pub roles: IdMap<RoleId, Role>,
     pub splash: Option<String>,
+    #[serde(default)] pub system_channel_flags: SystemChannelFlags,
+    pub system_channel_id: Option<ChannelId>,
     pub verification_level: VerificationLevel,
 }
+
+impl PartialGuild {
+    /// Retrieves the guild's roles from the highest in the hierarchy to the
+    /// lowest, as the official client displays them.
+    pub fn roles_sorted(&self) -> Vec<&Role> {
+        let mut roles = self.roles.values().collect::<Vec<&Role>>();
+        roles.sort_by(|a, b| b.cmp(a));
+
+        roles
+    }
+}
@@ -1,12 +1,22 @@
-use ::*;
+use serde::de::{Deserialize, Deserializer, IgnoredAny};
+use serde::ser::Serializer;
+use serde_json::{self, Value};
 use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::result::Result as StdResult;
+use super::super::limits::{self, LimitError, MAX_ROLE_NAME_LENGTH};
+use super::super::misc::Mentionable;
+use super::super::permissions::Permissions;
+use super::super::{IntegrationId, RgbColour, RoleId, UserId};
+use super::{Guild, Member};
 
-#[cfg(all(feature = "builder", feature = "cache", feature = "model"))]
-use builder::EditRole;
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "utils")]
+use utils::Colour;
+
+#[cfg(feature = "model")]
 use internal::prelude::*;
-#[cfg(all(feature = "cache", feature = "model"))]
-use {CACHE, http};
+#[cfg(feature = "model")]
+use super::super::GuildId;
 
 /// Information about a role within a guild. A role represents a set of
 /// permissions, and can be attached to one or multiple users. A role has
@@ -14,7 +24,7 @@ use {CACHE, http};
 /// are unique per guild and do not cross over to other guilds in any way, and
 /// can have channel-specific permission overrides in addition to guild-level
 /// permissions.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Role {
     /// The Id of the role. Can be used to calculate the role's creation date.
     pub id: RoleId,
@@ -35,6 +45,11 @@ pub struct Role {
     /// [`Member`]: struct.Member.html
     /// [`position`]: #structfield.position
     pub hoist: bool,
+    /// The hash of the role's icon, if it has one.
+    ///
+    /// **Note**: Role icons are only available to guilds with the
+    /// `ROLE_ICONS` feature.
+    pub icon: Option<String>,
     /// Indicator of whether the role is managed by an integration service.
     pub managed: bool,
     /// Indicator of whether the role can be mentioned, similar to mentioning a
@@ -57,8 +72,93 @@ pub struct Role {
     ///
     /// The `@everyone` role is usually either `-1` or `0`.
     pub position: i64,
+    /// Information about what manages the role, if anything.
+    #[serde(default)]
+    pub tags: Option<RoleTags>,
+    /// The unicode emoji used as the role's icon, if it has one.
+    pub unicode_emoji: Option<String>,
+}
+
+impl Role {
+    /// Whether a member is able to give the role to or remove it from others.
+    ///
+    /// This checks that the role is neither `@everyone` nor managed by an
+    /// integration, bot, or server boosting, that the member has the
+    /// [Manage Roles] permission, and that the member's highest role is above
+    /// it. The owner of the guild bypasses the permission and hierarchy
+    /// checks.
+    ///
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    pub fn is_assignable_by(&self, member: &Member, guild: &Guild) -> bool {
+        if self.id == guild.id.everyone_role() || self.is_managed() {
+            return false;
+        }
+
+        if member.user.id == guild.owner_id {
+            return true;
+        }
+
+        if !guild.member_permissions(member.user.id).manage_roles() {
+            return false;
+        }
+
+        member.roles
+            .iter()
+            .filter_map(|role_id| guild.roles.get(role_id))
+            .max()
+            .map_or(false, |highest| highest > self)
+    }
+
+    /// Whether the role is managed by an integration, a bot, or server
+    /// boosting, in which case it can not be manually given or removed.
+    pub fn is_managed(&self) -> bool {
+        self.managed || self.tags.as_ref().map_or(false, |tags| {
+            tags.bot_id.is_some() || tags.integration_id.is_some() || tags.premium_subscriber
+        })
+    }
+}
+
+impl RoleId {
+    /// Looks up the role in the given guild.
+    ///
+    /// Returns `None` if the role does not belong to the guild.
+    #[inline]
+    pub fn to_role<'a>(&self, guild: &'a Guild) -> Option<&'a Role> { guild.roles.get(self) }
+}
+
+/// Information about what manages a [`Role`].
+///
+/// [`Role`]: struct.Role.html
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RoleTags {
+    /// The Id of the bot that the role belongs to.
+    pub bot_id: Option<UserId>,
+    /// The Id of the integration that manages the role.
+    pub integration_id: Option<IntegrationId>,
+    /// Whether the role is the guild's booster role.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_present",
+        serialize_with = "serialize_present",
+        skip_serializing_if = "is_false"
+    )]
+    pub premium_subscriber: bool,
 }
 
+/// Discord marks boolean role tags by including them with a `null` value, so
+/// only their presence matters.
+fn deserialize_present<'de, D: Deserializer<'de>>(deserializer: D) -> StdResult<bool, D::Error> {
+    IgnoredAny::deserialize(deserializer).map(|_| true)
+}
+
+/// Serializes a boolean role tag as a `null` value, which is only done when it
+/// is `true`, so that it reads back the same as Discord's.
+fn serialize_present<S: Serializer>(_: &bool, serializer: S) -> StdResult<S::Ok, S::Error> {
+    serializer.serialize_unit()
+}
+
+fn is_false(value: &bool) -> bool { !*value }
+
 impl Display for Role {
     /// Format a mention for the role, pinging its members.
     // This is in the format of: `<@&ROLE_ID>`.
@@ -85,12 +185,275 @@ impl PartialOrd for Role {
     fn partial_cmp(&self, other: &Role) -> Option<Ordering> { Some(self.cmp(other)) }
 }
 
-impl From<Role> for RoleId {
-    /// Gets the Id of a role.
-    fn from(role: Role) -> RoleId { role.id }
+/// A new position for a role, as used when reordering the guild's roles.
+///
+/// Refer to [`Guild::reorder_roles`] for more information.
+///
+/// [`Guild::reorder_roles`]: struct.Guild.html#method.reorder_roles
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RolePosition {
+    /// The Id of the role to move.
+    pub id: RoleId,
+    /// The new position of the role.
+    pub position: i64,
 }
 
-impl<'a> From<&'a Role> for RoleId {
-    /// Gets the Id of a role.
-    fn from(role: &Role) -> RoleId { role.id }
+impl<R: Into<RoleId>> From<(R, i64)> for RolePosition {
+    fn from((id, position): (R, i64)) -> RolePosition {
+        RolePosition {
+            id: id.into(),
+            position: position,
+        }
+    }
+}
+
+/// The reason that a set of [`RolePosition`]s is invalid, as returned by
+/// [`Guild::validate_role_positions`].
+///
+/// [`Guild::validate_role_positions`]: struct.Guild.html#method.validate_role_positions
+/// [`RolePosition`]: struct.RolePosition.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RolePositionError {
+    /// More than one role was given the same position.
+    DuplicatePosition(i64),
+    /// A role was given more than one position.
+    DuplicateRole(RoleId),
+    /// The `@everyone` role was moved away from position `0`, or another role
+    /// was moved to or below it.
+    EveryonePosition,
+    /// A role does not exist in the guild.
+    UnknownRole(RoleId),
+}
+
+impl Display for RolePositionError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            RolePositionError::DuplicatePosition(position) => {
+                write!(f, "Multiple roles given position {}", position)
+            },
+            RolePositionError::DuplicateRole(id) => {
+                write!(f, "Role {} given multiple positions", id)
+            },
+            RolePositionError::EveryonePosition => {
+                f.write_str("The @everyone role must stay at position 0")
+            },
+            RolePositionError::UnknownRole(id) => write!(f, "Role {} is not in the guild", id),
+        }
+    }
+}
+
+#[cfg(feature = "model")]
+impl Role {
+    /// Deletes the role from the given guild.
+    ///
+    /// Use [`Guild::check_role_hierarchy`] beforehand to ensure that the
+    /// acting member is able to manage the role.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`Guild::check_role_hierarchy`]: struct.Guild.html#method.check_role_hierarchy
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    #[inline]
+    pub fn delete<G: Into<GuildId>>(&self, guild_id: G) -> Result<()> {
+        guild_id.into().delete_role(self.id)
+    }
+
+    /// Edits the role in the given guild, configured through the [`EditRole`]
+    /// builder, and updates the local role with the result.
+    ///
+    /// Use [`Guild::check_role_hierarchy`] beforehand to ensure that the
+    /// acting member is able to manage the role.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`EditRole`]: struct.EditRole.html
+    /// [`Guild::check_role_hierarchy`]: struct.Guild.html#method.check_role_hierarchy
+    /// [Manage Roles]: permissions/constant.MANAGE_ROLES.html
+    pub fn edit<G, F>(&mut self, guild_id: G, f: F) -> Result<()>
+        where G: Into<GuildId>, F: FnOnce(EditRole) -> EditRole {
+        match guild_id.into().edit_role(self.id, f) {
+            Ok(role) => {
+                *self = role;
+
+                Ok(())
+            },
+            Err(why) => Err(why),
+        }
+    }
+}
+
+/// The payload used to create or edit a role.
+///
+/// Fields that are not set are left unchanged when editing, and take
+/// Discord's defaults when creating.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EditRole {
+    #[serde(rename = "color", skip_serializing_if = "Option::is_none")]
+    colour: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hoist: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mentionable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permissions: Option<Permissions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<i64>,
+    #[serde(skip)]
+    pub(crate) reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unicode_emoji: Option<String>,
+}
+
+impl EditRole {
+    /// Sets the colour of the role.
+    ///
+    /// This accepts an [`RgbColour`], a tuple of its components, or a raw RGB
+    /// value.
+    ///
+    /// [`RgbColour`]: struct.RgbColour.html
+    pub fn colour<C: Into<RgbColour>>(mut self, colour: C) -> Self {
+        self.colour = Some(colour.into().0);
+
+        self
+    }
+
+    /// Sets whether members of the role are displayed separately from online
+    /// members.
+    pub fn hoist(mut self, hoist: bool) -> Self {
+        self.hoist = Some(hoist);
+
+        self
+    }
+
+    /// Sets the icon of the role, as a data URI of the image.
+    ///
+    /// **Note**: Role icons are only available to guilds with the
+    /// `ROLE_ICONS` feature.
+    pub fn icon<S: ToString>(mut self, icon: S) -> Self {
+        self.icon = Some(icon.to_string());
+
+        self
+    }
+
+    /// Sets whether the role can be mentioned by anyone.
+    pub fn mentionable(mut self, mentionable: bool) -> Self {
+        self.mentionable = Some(mentionable);
+
+        self
+    }
+
+    /// Sets the name of the role.
+    ///
+    /// The name must be at most [`MAX_ROLE_NAME_LENGTH`] characters long.
+    ///
+    /// [`MAX_ROLE_NAME_LENGTH`]: limits/constant.MAX_ROLE_NAME_LENGTH.html
+    pub fn name<S: ToString>(mut self, name: S) -> Self {
+        self.name = Some(name.to_string());
+
+        self
+    }
+
+    /// Sets the set of permissions that the role grants.
+    pub fn permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions = Some(permissions);
+
+        self
+    }
+
+    /// Sets the position of the role in the role list.
+    ///
+    /// **Note**: This is ignored when creating a role. Use
+    /// [`GuildId::reorder_roles`] to move roles instead.
+    ///
+    /// [`GuildId::reorder_roles`]: struct.GuildId.html#method.reorder_roles
+    pub fn position(mut self, position: i64) -> Self {
+        self.position = Some(position);
+
+        self
+    }
+
+    /// Sets the reason for the change, which is shown in the guild's audit log.
+    pub fn reason<S: ToString>(mut self, reason: S) -> Self {
+        self.reason = Some(reason.to_string());
+
+        self
+    }
+
+    /// Sets the unicode emoji used as the role's icon.
+    ///
+    /// **Note**: This can not be set alongside an [`icon`].
+    ///
+    /// [`icon`]: #method.icon
+    pub fn unicode_emoji<S: ToString>(mut self, emoji: S) -> Self {
+        self.unicode_emoji = Some(emoji.to_string());
+
+        self
+    }
+
+    /// Validates the role against Discord's [limits] and converts it into a
+    /// value suitable for creating or editing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LimitError`] if the name is too long, or if both an icon
+    /// and a unicode emoji are set.
+    ///
+    /// [`LimitError`]: limits/enum.LimitError.html
+    /// [limits]: limits/index.html
+    pub fn to_value(&self) -> StdResult<Value, LimitError> {
+        if let Some(ref name) = self.name {
+            limits::check_length(name, 0, MAX_ROLE_NAME_LENGTH, LimitError::RoleNameLength)?;
+        }
+
+        if self.icon.is_some() && self.unicode_emoji.is_some() {
+            return Err(LimitError::RoleIconConflict);
+        }
+
+        serde_json::to_value(self).map_err(|why| LimitError::Serialization(why.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+    use super::super::super::limits::{LimitError, MAX_ROLE_NAME_LENGTH};
+    use super::super::super::{IntegrationId, UserId};
+    use super::{EditRole, RoleTags};
+
+    #[test]
+    fn role_tags_round_trip() {
+        let tags = RoleTags {
+            bot_id: Some(UserId(1)),
+            integration_id: Some(IntegrationId(2)),
+            premium_subscriber: true,
+        };
+        let value = serde_json::to_value(&tags).unwrap();
+
+        assert_eq!(value["premium_subscriber"], Value::Null);
+        assert_eq!(serde_json::from_value::<RoleTags>(value).unwrap(), tags);
+    }
+
+    #[test]
+    fn role_tags_omit_false_premium_subscriber() {
+        let value = serde_json::to_value(&RoleTags::default()).unwrap();
+
+        assert!(value.get("premium_subscriber").is_none());
+        assert_eq!(serde_json::from_value::<RoleTags>(value).unwrap(), RoleTags::default());
+    }
+
+    #[test]
+    fn edit_role_name_length() {
+        let name = |length| EditRole::default().name("é".repeat(length)).to_value();
+
+        assert!(name(0).is_ok());
+        assert!(name(MAX_ROLE_NAME_LENGTH).is_ok());
+        assert_eq!(
+            name(MAX_ROLE_NAME_LENGTH + 1).unwrap_err(),
+            LimitError::RoleNameLength(MAX_ROLE_NAME_LENGTH + 1),
+        );
+    }
 }
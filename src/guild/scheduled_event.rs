@@ -0,0 +1,399 @@
+use chrono::{DateTime, FixedOffset};
+use super::super::{ChannelId, GuildId, ScheduledEventId, User, UserId};
+use super::Member;
+
+#[cfg(feature = "model")]
+use http;
+#[cfg(feature = "model")]
+use internal::prelude::*;
+
+/// The maximum number of interested users that are retrieved per request.
+#[cfg(feature = "model")]
+const USERS_PAGE_LIMIT: u64 = 100;
+
+/// The only privacy level an event can currently have, restricting it to
+/// members of the guild.
+const PRIVACY_LEVEL_GUILD_ONLY: u64 = 2;
+
+/// An event scheduled in a [`Guild`].
+///
+/// [`Guild`]: struct.Guild.html
+#[derive(Clone, Debug, Deserialize)]
+pub struct GuildScheduledEvent {
+    /// The Id of the event.
+    pub id: ScheduledEventId,
+    /// The Id of the stage or voice channel the event is hosted in.
+    ///
+    /// **Note**: This is `None` for external events.
+    pub channel_id: Option<ChannelId>,
+    /// The user that created the event.
+    ///
+    /// **Note**: This is not available for events created before October 2021.
+    pub creator: Option<User>,
+    /// The Id of the user that created the event.
+    pub creator_id: Option<UserId>,
+    /// The description of the event.
+    pub description: Option<String>,
+    /// Additional information about the event, such as its location.
+    pub entity_metadata: Option<ScheduledEventMetadata>,
+    /// The Id of the guild the event belongs to.
+    pub guild_id: GuildId,
+    /// The hash of the event's cover image, if it has one.
+    pub image: Option<String>,
+    /// Where the event is hosted.
+    #[serde(rename = "entity_type")]
+    pub kind: ScheduledEventType,
+    /// The name of the event.
+    pub name: String,
+    /// The privacy level of the event.
+    pub privacy_level: u64,
+    /// The time at which the event is scheduled to end.
+    ///
+    /// **Note**: This is required for external events.
+    pub scheduled_end_time: Option<DateTime<FixedOffset>>,
+    /// The time at which the event is scheduled to start.
+    pub scheduled_start_time: DateTime<FixedOffset>,
+    /// The current status of the event.
+    pub status: ScheduledEventStatus,
+    /// The number of users interested in the event.
+    ///
+    /// **Note**: This is only available when requested.
+    pub user_count: Option<u64>,
+}
+
+#[cfg(feature = "model")]
+impl GuildScheduledEvent {
+    /// Returns an iterator over the users interested in the event, which pages
+    /// through them as it is advanced.
+    #[inline]
+    pub fn users_iter(&self) -> ScheduledEventUsersIter {
+        ScheduledEventUsersIter::new(self.guild_id, self.id)
+    }
+}
+
+/// Additional information about a [`GuildScheduledEvent`].
+///
+/// [`GuildScheduledEvent`]: struct.GuildScheduledEvent.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScheduledEventMetadata {
+    /// The location of an external event.
+    pub location: Option<String>,
+}
+
+/// A user interested in a [`GuildScheduledEvent`].
+///
+/// [`GuildScheduledEvent`]: struct.GuildScheduledEvent.html
+#[derive(Clone, Debug, Deserialize)]
+pub struct ScheduledEventUser {
+    /// The Id of the event the user is interested in.
+    #[serde(rename = "guild_scheduled_event_id")]
+    pub event_id: ScheduledEventId,
+    /// The user's guild member data, if it was requested.
+    pub member: Option<Member>,
+    /// The interested user.
+    pub user: User,
+}
+
+enum_number!(
+    #[doc="The status of a [`GuildScheduledEvent`].
+
+[`GuildScheduledEvent`]: struct.GuildScheduledEvent.html"]
+    ScheduledEventStatus {
+        /// The event has not started yet.
+        Scheduled = 1,
+        /// The event is in progress.
+        Active = 2,
+        /// The event has ended.
+        Completed = 3,
+        /// The event was cancelled before it started.
+        Canceled = 4,
+    }
+);
+
+enum_number!(
+    #[doc="Where a [`GuildScheduledEvent`] is hosted.
+
+[`GuildScheduledEvent`]: struct.GuildScheduledEvent.html"]
+    ScheduledEventType {
+        /// The event is hosted in a stage channel.
+        StageInstance = 1,
+        /// The event is hosted in a voice channel.
+        Voice = 2,
+        /// The event is hosted outside of Discord, at a given location.
+        External = 3,
+    }
+);
+
+/// The payload used to create a [`GuildScheduledEvent`].
+///
+/// A [`name`], [`kind`], and [`start_time`] must always be set. Stage and voice
+/// events require a [`channel_id`], while external events require a
+/// [`location`] and an [`end_time`].
+///
+/// [`GuildScheduledEvent`]: struct.GuildScheduledEvent.html
+/// [`channel_id`]: #method.channel_id
+/// [`end_time`]: #method.end_time
+/// [`kind`]: #method.kind
+/// [`location`]: #method.location
+/// [`name`]: #method.name
+/// [`start_time`]: #method.start_time
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateScheduledEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_metadata: Option<ScheduledEventMetadata>,
+    #[serde(rename = "entity_type", skip_serializing_if = "Option::is_none")]
+    kind: Option<ScheduledEventType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<String>,
+    name: String,
+    privacy_level: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_end_time: Option<DateTime<FixedOffset>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_start_time: Option<DateTime<FixedOffset>>,
+}
+
+impl CreateScheduledEvent {
+    /// Sets the stage or voice channel that the event is hosted in.
+    pub fn channel_id<C: Into<ChannelId>>(mut self, channel_id: C) -> Self {
+        self.channel_id = Some(channel_id.into());
+
+        self
+    }
+
+    /// Sets the description of the event.
+    pub fn description<D: ToString>(mut self, description: D) -> Self {
+        self.description = Some(description.to_string());
+
+        self
+    }
+
+    /// Sets the time at which the event is scheduled to end.
+    pub fn end_time(mut self, time: DateTime<FixedOffset>) -> Self {
+        self.scheduled_end_time = Some(time);
+
+        self
+    }
+
+    /// Sets the cover image of the event, as a base64-encoded data URI.
+    pub fn image(mut self, image: &str) -> Self {
+        self.image = Some(image.to_string());
+
+        self
+    }
+
+    /// Sets where the event is hosted.
+    pub fn kind(mut self, kind: ScheduledEventType) -> Self {
+        self.kind = Some(kind);
+
+        self
+    }
+
+    /// Sets the location of an external event.
+    pub fn location<D: ToString>(mut self, location: D) -> Self {
+        self.entity_metadata = Some(ScheduledEventMetadata {
+            location: Some(location.to_string()),
+        });
+
+        self
+    }
+
+    /// Sets the name of the event.
+    pub fn name<D: ToString>(mut self, name: D) -> Self {
+        self.name = name.to_string();
+
+        self
+    }
+
+    /// Sets the time at which the event is scheduled to start.
+    pub fn start_time(mut self, time: DateTime<FixedOffset>) -> Self {
+        self.scheduled_start_time = Some(time);
+
+        self
+    }
+}
+
+impl Default for CreateScheduledEvent {
+    /// Creates an empty payload, restricted to members of the guild.
+    fn default() -> Self {
+        CreateScheduledEvent {
+            channel_id: None,
+            description: None,
+            entity_metadata: None,
+            kind: None,
+            image: None,
+            name: String::new(),
+            privacy_level: PRIVACY_LEVEL_GUILD_ONLY,
+            scheduled_end_time: None,
+            scheduled_start_time: None,
+        }
+    }
+}
+
+/// The payload used to edit a [`GuildScheduledEvent`].
+///
+/// [`GuildScheduledEvent`]: struct.GuildScheduledEvent.html
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EditScheduledEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_metadata: Option<ScheduledEventMetadata>,
+    #[serde(rename = "entity_type", skip_serializing_if = "Option::is_none")]
+    kind: Option<ScheduledEventType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_end_time: Option<DateTime<FixedOffset>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_start_time: Option<DateTime<FixedOffset>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<ScheduledEventStatus>,
+}
+
+impl EditScheduledEvent {
+    /// Sets the stage or voice channel that the event is hosted in.
+    pub fn channel_id<C: Into<ChannelId>>(mut self, channel_id: C) -> Self {
+        self.channel_id = Some(channel_id.into());
+
+        self
+    }
+
+    /// Sets the description of the event.
+    pub fn description<D: ToString>(mut self, description: D) -> Self {
+        self.description = Some(description.to_string());
+
+        self
+    }
+
+    /// Sets the time at which the event is scheduled to end.
+    pub fn end_time(mut self, time: DateTime<FixedOffset>) -> Self {
+        self.scheduled_end_time = Some(time);
+
+        self
+    }
+
+    /// Sets the cover image of the event, as a base64-encoded data URI.
+    pub fn image(mut self, image: &str) -> Self {
+        self.image = Some(image.to_string());
+
+        self
+    }
+
+    /// Sets where the event is hosted.
+    pub fn kind(mut self, kind: ScheduledEventType) -> Self {
+        self.kind = Some(kind);
+
+        self
+    }
+
+    /// Sets the location of an external event.
+    pub fn location<D: ToString>(mut self, location: D) -> Self {
+        self.entity_metadata = Some(ScheduledEventMetadata {
+            location: Some(location.to_string()),
+        });
+
+        self
+    }
+
+    /// Sets the name of the event.
+    pub fn name<D: ToString>(mut self, name: D) -> Self {
+        self.name = Some(name.to_string());
+
+        self
+    }
+
+    /// Sets the time at which the event is scheduled to start.
+    pub fn start_time(mut self, time: DateTime<FixedOffset>) -> Self {
+        self.scheduled_start_time = Some(time);
+
+        self
+    }
+
+    /// Sets the status of the event.
+    ///
+    /// A scheduled event may only be made active or cancelled, and an active
+    /// event may only be completed.
+    pub fn status(mut self, status: ScheduledEventStatus) -> Self {
+        self.status = Some(status);
+
+        self
+    }
+}
+
+/// An iterator over the users interested in a [`GuildScheduledEvent`], ordered
+/// by user Id.
+///
+/// Pages are retrieved lazily as the iterator is advanced. If retrieving a page
+/// fails, then the error is yielded and the iterator ends.
+///
+/// This is created via [`GuildScheduledEvent::users_iter`].
+///
+/// [`GuildScheduledEvent`]: struct.GuildScheduledEvent.html
+/// [`GuildScheduledEvent::users_iter`]: struct.GuildScheduledEvent.html#method.users_iter
+#[cfg(feature = "model")]
+#[derive(Clone, Debug)]
+pub struct ScheduledEventUsersIter {
+    after: Option<UserId>,
+    // Stored in reverse order, so users can be popped off.
+    buffer: Vec<ScheduledEventUser>,
+    event_id: ScheduledEventId,
+    guild_id: GuildId,
+    has_more: bool,
+}
+
+#[cfg(feature = "model")]
+impl ScheduledEventUsersIter {
+    /// Creates an iterator starting from the first interested user.
+    pub(crate) fn new(guild_id: GuildId, event_id: ScheduledEventId) -> ScheduledEventUsersIter {
+        ScheduledEventUsersIter {
+            after: None,
+            buffer: vec![],
+            event_id: event_id,
+            guild_id: guild_id,
+            has_more: true,
+        }
+    }
+
+    /// Retrieves the next page of users, after the last one retrieved.
+    fn refresh(&mut self) -> Result<()> {
+        let page = http::get_scheduled_event_users(
+            self.guild_id.0,
+            self.event_id.0,
+            USERS_PAGE_LIMIT,
+            self.after.map(|id| id.0),
+        )?;
+
+        self.has_more = page.len() as u64 == USERS_PAGE_LIMIT;
+        self.after = page.last().map(|event_user| event_user.user.id);
+        self.buffer = page;
+        self.buffer.reverse();
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "model")]
+impl Iterator for ScheduledEventUsersIter {
+    type Item = Result<ScheduledEventUser>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_empty() && self.has_more {
+            if let Err(why) = self.refresh() {
+                self.has_more = false;
+
+                return Some(Err(why));
+            }
+        }
+
+        self.buffer.pop().map(Ok)
+    }
+}
@@ -0,0 +1,312 @@
+use chrono::{DateTime, FixedOffset, TimeZone};
+use super::super::permissions::Permissions;
+use super::super::{
+    ChannelId,
+    ChannelType,
+    EmojiId,
+    GuildChannel,
+    GuildId,
+    IdMap,
+    PermissionOverwrite,
+    RoleId,
+    UserId,
+};
+use super::{
+    Emoji,
+    Guild,
+    PremiumTier,
+    Role,
+    SystemChannelFlags,
+    VerificationLevel,
+};
+
+#[cfg(feature = "utils")]
+use utils::Colour;
+
+/// A self-contained, serializable copy of a guild's structure, suitable for
+/// backups and templating.
+///
+/// This contains the guild's settings, channels, roles, and emojis, but none
+/// of its members, presences, or voice states. Threads are not included.
+///
+/// Snapshots are versioned like other persisted models: save them with
+/// [`persist::to_value`], and load them with [`persist::from_value`], which
+/// migrates snapshots written by older versions of the library.
+///
+/// [`persist::from_value`]: persist/fn.from_value.html
+/// [`persist::to_value`]: persist/fn.to_value.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildSnapshot {
+    /// The Id of the guild the snapshot was taken of.
+    pub id: GuildId,
+    /// The guild's settings.
+    pub settings: GuildSnapshotSettings,
+    /// The guild's channels, ordered by Id.
+    pub channels: Vec<ChannelSnapshot>,
+    /// The guild's custom emojis, ordered by Id.
+    pub emojis: Vec<EmojiSnapshot>,
+    /// The guild's roles, ordered by Id.
+    pub roles: Vec<RoleSnapshot>,
+}
+
+/// The guild-level settings within a [`GuildSnapshot`].
+///
+/// [`GuildSnapshot`]: struct.GuildSnapshot.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildSnapshotSettings {
+    pub afk_channel_id: Option<ChannelId>,
+    pub afk_timeout: u64,
+    pub banner: Option<String>,
+    pub default_message_notifications: u64,
+    pub features: Vec<String>,
+    pub icon: Option<String>,
+    pub mfa_level: u64,
+    pub name: String,
+    pub owner_id: UserId,
+    pub preferred_locale: Option<String>,
+    pub region: String,
+    pub splash: Option<String>,
+    pub system_channel_flags: SystemChannelFlags,
+    pub system_channel_id: Option<ChannelId>,
+    pub verification_level: VerificationLevel,
+}
+
+/// A channel within a [`GuildSnapshot`], including its permission overwrites.
+///
+/// [`GuildSnapshot`]: struct.GuildSnapshot.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelSnapshot {
+    pub id: ChannelId,
+    pub bitrate: Option<u64>,
+    pub category_id: Option<ChannelId>,
+    pub kind: ChannelType,
+    pub name: String,
+    pub nsfw: bool,
+    pub permission_overwrites: Vec<PermissionOverwrite>,
+    pub position: i64,
+    pub rate_limit_per_user: Option<u64>,
+    pub rtc_region: Option<String>,
+    pub topic: Option<String>,
+    pub user_limit: Option<u64>,
+}
+
+/// A custom emoji within a [`GuildSnapshot`].
+///
+/// The emoji's image is not included, and must be retrieved from its URL.
+///
+/// [`GuildSnapshot`]: struct.GuildSnapshot.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EmojiSnapshot {
+    pub id: EmojiId,
+    pub managed: bool,
+    pub name: String,
+    pub require_colons: bool,
+    pub roles: Vec<RoleId>,
+}
+
+/// A role within a [`GuildSnapshot`].
+///
+/// [`GuildSnapshot`]: struct.GuildSnapshot.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RoleSnapshot {
+    pub id: RoleId,
+    pub colour: u32,
+    pub hoist: bool,
+    pub icon: Option<String>,
+    pub managed: bool,
+    pub mentionable: bool,
+    pub name: String,
+    pub permissions: Permissions,
+    pub position: i64,
+    pub unicode_emoji: Option<String>,
+}
+
+impl Guild {
+    /// Takes a [`GuildSnapshot`] of the guild's settings, channels, roles, and
+    /// emojis.
+    ///
+    /// [`GuildSnapshot`]: struct.GuildSnapshot.html
+    pub fn to_snapshot(&self) -> GuildSnapshot {
+        let mut channels = self.channels
+            .values()
+            .filter(|channel| !channel.is_thread())
+            .map(|channel| ChannelSnapshot {
+                id: channel.id,
+                bitrate: channel.bitrate,
+                category_id: channel.category_id,
+                kind: channel.kind,
+                name: channel.name.clone(),
+                nsfw: channel.nsfw,
+                permission_overwrites: channel.permission_overwrites.clone(),
+                position: channel.position,
+                rate_limit_per_user: channel.rate_limit_per_user,
+                rtc_region: channel.rtc_region.clone().into(),
+                topic: channel.topic.clone(),
+                user_limit: channel.user_limit,
+            })
+            .collect::<Vec<_>>();
+        channels.sort_by_key(|channel| channel.id);
+
+        let mut emojis = self.emojis
+            .values()
+            .map(|emoji| EmojiSnapshot {
+                id: emoji.id,
+                managed: emoji.managed,
+                name: emoji.name.clone(),
+                require_colons: emoji.require_colons,
+                roles: emoji.roles.clone(),
+            })
+            .collect::<Vec<_>>();
+        emojis.sort_by_key(|emoji| emoji.id);
+
+        let mut roles = self.roles
+            .values()
+            .map(|role| RoleSnapshot {
+                id: role.id,
+                #[cfg(feature = "utils")]
+                colour: role.colour.0,
+                #[cfg(not(feature = "utils"))]
+                colour: role.colour,
+                hoist: role.hoist,
+                icon: role.icon.clone(),
+                managed: role.managed,
+                mentionable: role.mentionable,
+                name: role.name.clone(),
+                permissions: role.permissions,
+                position: role.position,
+                unicode_emoji: role.unicode_emoji.clone(),
+            })
+            .collect::<Vec<_>>();
+        roles.sort_by_key(|role| role.id);
+
+        GuildSnapshot {
+            id: self.id,
+            settings: GuildSnapshotSettings {
+                afk_channel_id: self.afk_channel_id,
+                afk_timeout: self.afk_timeout,
+                banner: self.banner.clone(),
+                default_message_notifications: self.default_message_notifications,
+                features: self.features.clone(),
+                icon: self.icon.clone(),
+                mfa_level: self.mfa_level,
+                name: self.name.clone(),
+                owner_id: self.owner_id,
+                preferred_locale: self.preferred_locale.clone(),
+                region: self.region.clone(),
+                splash: self.splash.clone(),
+                system_channel_flags: self.system_channel_flags,
+                system_channel_id: self.system_channel_id,
+                verification_level: self.verification_level,
+            },
+            channels: channels,
+            emojis: emojis,
+            roles: roles,
+        }
+    }
+
+    /// Restores a guild from a [`GuildSnapshot`].
+    ///
+    /// As snapshots do not contain members, the restored guild has no members,
+    /// presences, or voice states, a member count of `0`, no boosts, and a
+    /// [`joined_at`] of the Unix epoch.
+    ///
+    /// [`GuildSnapshot`]: struct.GuildSnapshot.html
+    /// [`joined_at`]: #structfield.joined_at
+    pub fn from_snapshot(snapshot: GuildSnapshot) -> Guild {
+        let id = snapshot.id;
+        let settings = snapshot.settings;
+
+        let channels = snapshot.channels
+            .into_iter()
+            .map(|channel| (channel.id, GuildChannel {
+                id: channel.id,
+                bitrate: channel.bitrate,
+                category_id: channel.category_id,
+                guild_id: id,
+                kind: channel.kind,
+                last_message_id: None,
+                last_pin_timestamp: None,
+                member: None,
+                member_count: None,
+                message_count: None,
+                name: channel.name,
+                owner_id: None,
+                permission_overwrites: channel.permission_overwrites,
+                position: channel.position,
+                rate_limit_per_user: channel.rate_limit_per_user,
+                rtc_region: channel.rtc_region.into(),
+                status: None,
+                thread_metadata: None,
+                topic: channel.topic,
+                user_limit: channel.user_limit,
+                nsfw: channel.nsfw,
+            }))
+            .collect::<IdMap<_, _>>();
+
+        let emojis = snapshot.emojis
+            .into_iter()
+            .map(|emoji| (emoji.id, Emoji {
+                id: emoji.id,
+                guild_id: Some(id),
+                name: emoji.name,
+                managed: emoji.managed,
+                require_colons: emoji.require_colons,
+                roles: emoji.roles,
+                user: None,
+            }))
+            .collect::<IdMap<_, _>>();
+
+        let roles = snapshot.roles
+            .into_iter()
+            .map(|role| (role.id, Role {
+                id: role.id,
+                #[cfg(feature = "utils")]
+                colour: Colour(role.colour),
+                #[cfg(not(feature = "utils"))]
+                colour: role.colour,
+                hoist: role.hoist,
+                icon: role.icon,
+                managed: role.managed,
+                mentionable: role.mentionable,
+                name: role.name,
+                permissions: role.permissions,
+                position: role.position,
+                tags: None,
+                unicode_emoji: role.unicode_emoji,
+            }))
+            .collect::<IdMap<_, _>>();
+
+        Guild {
+            afk_channel_id: settings.afk_channel_id,
+            afk_timeout: settings.afk_timeout,
+            banner: settings.banner,
+            bans: None,
+            channels: channels,
+            default_message_notifications: settings.default_message_notifications,
+            emojis: emojis,
+            features: settings.features,
+            icon: settings.icon,
+            id: id,
+            incidents_data: None,
+            joined_at: epoch(),
+            large: false,
+            member_count: 0,
+            members: IdMap::default(),
+            mfa_level: settings.mfa_level,
+            name: settings.name,
+            owner_id: settings.owner_id,
+            preferred_locale: settings.preferred_locale,
+            premium_tier: PremiumTier::None,
+            presences: IdMap::default(),
+            region: settings.region,
+            roles: roles,
+            splash: settings.splash,
+            system_channel_flags: settings.system_channel_flags,
+            system_channel_id: settings.system_channel_id,
+            verification_level: settings.verification_level,
+            voice_states: IdMap::default(),
+        }
+    }
+}
+
+fn epoch() -> DateTime<FixedOffset> { FixedOffset::east(0).timestamp(0, 0) }
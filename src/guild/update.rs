@@ -0,0 +1,327 @@
+use super::super::event::{
+    ChannelCreateEvent,
+    ChannelDeleteEvent,
+    ChannelUpdateEvent,
+    GuildBanAddEvent,
+    GuildBanRemoveEvent,
+    GuildEmojisUpdateEvent,
+    GuildMemberAddEvent,
+    GuildMemberRemoveEvent,
+    GuildMemberUpdateEvent,
+    GuildMembersChunkEvent,
+    GuildRoleCreateEvent,
+    GuildRoleDeleteEvent,
+    GuildRoleUpdateEvent,
+    GuildUpdateEvent,
+};
+use super::super::{Channel, GuildChannel};
+use super::{Ban, Guild, Member, Role};
+
+/// Methods for applying gateway events to a guild, so that state kept across
+/// events does not need to know how each event is merged.
+///
+/// Events for other guilds are ignored. Methods which replace or remove an
+/// item return the previous version of it, if there was one.
+impl Guild {
+    /// Adds a ban from a [`GuildBanAddEvent`] to the guild's bans, if they
+    /// have been populated.
+    ///
+    /// The gateway does not include the reason for the ban.
+    ///
+    /// [`GuildBanAddEvent`]: event/struct.GuildBanAddEvent.html
+    pub fn apply_ban_add(&mut self, event: &GuildBanAddEvent) -> Option<Ban> {
+        if event.guild_id != self.id {
+            return None;
+        }
+
+        self.bans.as_mut()?.insert(event.user.id, Ban {
+            reason: None,
+            user: event.user.clone(),
+        })
+    }
+
+    /// Removes a ban from a [`GuildBanRemoveEvent`] from the guild's bans, if
+    /// they have been populated.
+    ///
+    /// [`GuildBanRemoveEvent`]: event/struct.GuildBanRemoveEvent.html
+    pub fn apply_ban_remove(&mut self, event: &GuildBanRemoveEvent) -> Option<Ban> {
+        if event.guild_id != self.id {
+            return None;
+        }
+
+        self.bans.as_mut()?.remove(&event.user.id)
+    }
+
+    /// Adds a channel from a [`ChannelCreateEvent`] to the guild.
+    ///
+    /// [`ChannelCreateEvent`]: event/struct.ChannelCreateEvent.html
+    pub fn apply_channel_create(&mut self, event: &ChannelCreateEvent) -> Option<GuildChannel> {
+        self.insert_channel(&event.channel)
+    }
+
+    /// Removes a channel from a [`ChannelDeleteEvent`] from the guild.
+    ///
+    /// [`ChannelDeleteEvent`]: event/struct.ChannelDeleteEvent.html
+    pub fn apply_channel_delete(&mut self, event: &ChannelDeleteEvent) -> Option<GuildChannel> {
+        match event.channel {
+            Channel::Category(ref category) if category.guild_id == self.id => {
+                self.channels.remove(&category.id)
+            },
+            Channel::Guild(ref channel) if channel.guild_id == self.id => {
+                self.channels.remove(&channel.id)
+            },
+            _ => None,
+        }
+    }
+
+    /// Replaces a channel from a [`ChannelUpdateEvent`] in the guild.
+    ///
+    /// [`ChannelUpdateEvent`]: event/struct.ChannelUpdateEvent.html
+    pub fn apply_channel_update(&mut self, event: &ChannelUpdateEvent) -> Option<GuildChannel> {
+        self.insert_channel(&event.channel)
+    }
+
+    /// Replaces the guild's emojis with those from a
+    /// [`GuildEmojisUpdateEvent`].
+    ///
+    /// [`GuildEmojisUpdateEvent`]: event/struct.GuildEmojisUpdateEvent.html
+    pub fn apply_emojis_update(&mut self, event: &GuildEmojisUpdateEvent) {
+        if event.guild_id == self.id {
+            self.emojis.clone_from(&event.emojis);
+        }
+    }
+
+    /// Adds a member from a [`GuildMemberAddEvent`] to the guild, incrementing
+    /// the member count.
+    ///
+    /// [`GuildMemberAddEvent`]: event/struct.GuildMemberAddEvent.html
+    pub fn apply_member_add(&mut self, event: &GuildMemberAddEvent) -> Option<Member> {
+        if event.guild_id != self.id {
+            return None;
+        }
+
+        let old = self.members.insert(event.member.user.id, event.member.clone());
+
+        if old.is_none() {
+            self.member_count += 1;
+        }
+
+        old
+    }
+
+    /// Removes a member from a [`GuildMemberRemoveEvent`] from the guild,
+    /// decrementing the member count.
+    ///
+    /// [`GuildMemberRemoveEvent`]: event/struct.GuildMemberRemoveEvent.html
+    pub fn apply_member_remove(&mut self, event: &GuildMemberRemoveEvent) -> Option<Member> {
+        if event.guild_id != self.id {
+            return None;
+        }
+
+        self.member_count = self.member_count.saturating_sub(1);
+
+        self.members.remove(&event.user.id)
+    }
+
+    /// Updates a member from a [`GuildMemberUpdateEvent`], adding them to the
+    /// guild if they were not known.
+    ///
+    /// [`GuildMemberUpdateEvent`]: event/struct.GuildMemberUpdateEvent.html
+    pub fn apply_member_update(&mut self, event: &GuildMemberUpdateEvent) -> Option<Member> {
+        if event.guild_id != self.id {
+            return None;
+        }
+
+        if let Some(member) = self.members.get_mut(&event.user.id) {
+            let old = member.clone();
+
+            member.communication_disabled_until = event.communication_disabled_until;
+            member.flags = event.flags;
+            member.nick.clone_from(&event.nick);
+            member.pending = event.pending;
+            member.premium_since = event.premium_since;
+            member.roles.clone_from(&event.roles);
+            member.user.clone_from(&event.user);
+
+            return Some(old);
+        }
+
+        self.members.insert(event.user.id, Member {
+            communication_disabled_until: event.communication_disabled_until,
+            deaf: false,
+            flags: event.flags,
+            guild_id: self.id,
+            joined_at: None,
+            mute: false,
+            nick: event.nick.clone(),
+            pending: event.pending,
+            premium_since: event.premium_since,
+            roles: event.roles.clone(),
+            user: event.user.clone(),
+        });
+
+        None
+    }
+
+    /// Adds the members from a [`GuildMembersChunkEvent`] to the guild.
+    ///
+    /// [`GuildMembersChunkEvent`]: event/struct.GuildMembersChunkEvent.html
+    pub fn apply_members_chunk(&mut self, event: &GuildMembersChunkEvent) {
+        if event.guild_id == self.id {
+            self.members.extend(event.members.clone());
+        }
+    }
+
+    /// Adds a role from a [`GuildRoleCreateEvent`] to the guild.
+    ///
+    /// [`GuildRoleCreateEvent`]: event/struct.GuildRoleCreateEvent.html
+    pub fn apply_role_create(&mut self, event: &GuildRoleCreateEvent) -> Option<Role> {
+        if event.guild_id != self.id {
+            return None;
+        }
+
+        self.roles.insert(event.role.id, event.role.clone())
+    }
+
+    /// Removes a role from a [`GuildRoleDeleteEvent`] from the guild, and
+    /// from each of the guild's members.
+    ///
+    /// [`GuildRoleDeleteEvent`]: event/struct.GuildRoleDeleteEvent.html
+    pub fn apply_role_delete(&mut self, event: &GuildRoleDeleteEvent) -> Option<Role> {
+        if event.guild_id != self.id {
+            return None;
+        }
+
+        for member in self.members.values_mut() {
+            member.roles.retain(|role_id| *role_id != event.role_id);
+        }
+
+        self.roles.remove(&event.role_id)
+    }
+
+    /// Replaces a role from a [`GuildRoleUpdateEvent`] in the guild.
+    ///
+    /// [`GuildRoleUpdateEvent`]: event/struct.GuildRoleUpdateEvent.html
+    pub fn apply_role_update(&mut self, event: &GuildRoleUpdateEvent) -> Option<Role> {
+        if event.guild_id != self.id {
+            return None;
+        }
+
+        self.roles.insert(event.role.id, event.role.clone())
+    }
+
+    /// Merges the guild information from a [`GuildUpdateEvent`] into the
+    /// guild.
+    ///
+    /// Information only available on a full guild, such as the channels,
+    /// members, and voice states, is left unchanged.
+    ///
+    /// [`GuildUpdateEvent`]: event/struct.GuildUpdateEvent.html
+    pub fn apply_update(&mut self, event: &GuildUpdateEvent) {
+        let guild = &event.guild;
+
+        if guild.id != self.id {
+            return;
+        }
+
+        self.afk_channel_id = guild.afk_channel_id;
+        self.afk_timeout = guild.afk_timeout;
+        self.banner.clone_from(&guild.banner);
+        self.default_message_notifications = guild.default_message_notifications;
+        self.emojis.clone_from(&guild.emojis);
+        self.features.clone_from(&guild.features);
+        self.icon.clone_from(&guild.icon);
+        self.incidents_data.clone_from(&guild.incidents_data);
+        self.mfa_level = guild.mfa_level;
+        self.name.clone_from(&guild.name);
+        self.owner_id = guild.owner_id;
+        self.preferred_locale.clone_from(&guild.preferred_locale);
+        self.premium_tier = guild.premium_tier;
+        self.region.clone_from(&guild.region);
+        self.roles.clone_from(&guild.roles);
+        self.splash.clone_from(&guild.splash);
+        self.system_channel_flags = guild.system_channel_flags;
+        self.system_channel_id = guild.system_channel_id;
+        self.verification_level = guild.verification_level;
+    }
+
+    /// Inserts a channel into the guild, including categories, which are
+    /// stored alongside the guild's other channels.
+    fn insert_channel(&mut self, channel: &Channel) -> Option<GuildChannel> {
+        match *channel {
+            Channel::Category(ref category) if category.guild_id == self.id => {
+                self.channels.insert(category.id, GuildChannel::from(category.clone()))
+            },
+            Channel::Guild(ref channel) if channel.guild_id == self.id => {
+                self.channels.insert(channel.id, channel.clone())
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::super::super::event::{
+        ChannelCreateEvent,
+        ChannelDeleteEvent,
+        ChannelUpdateEvent,
+        GuildUpdateEvent,
+    };
+    use super::super::super::fixtures::support::guild;
+    use super::super::super::fixtures::GUILD_CREATE;
+    use super::super::super::{Channel, ChannelId, ChannelType, PartialGuild};
+
+    fn category(name: &str) -> Channel {
+        serde_json::from_str(&format!(r#"{{
+            "id": "381880193700069400",
+            "guild_id": "381880193251409931",
+            "name": "{}",
+            "parent_id": null,
+            "permission_overwrites": [],
+            "position": 0,
+            "type": 4
+        }}"#, name)).unwrap()
+    }
+
+    #[test]
+    fn category_create_update_delete() {
+        let mut guild = guild();
+        let id = ChannelId(381880193700069400);
+
+        assert!(guild.apply_channel_create(&ChannelCreateEvent {
+            channel: category("games"),
+        }).is_none());
+        assert_eq!(guild.channels[&id].kind, ChannelType::Category);
+
+        let old = guild.apply_channel_update(&ChannelUpdateEvent {
+            channel: category("more games"),
+        });
+        assert_eq!(old.map(|channel| channel.name), Some("games".to_string()));
+        assert_eq!(guild.channels[&id].name, "more games");
+
+        assert!(guild.apply_channel_delete(&ChannelDeleteEvent {
+            channel: category("more games"),
+        }).is_some());
+        assert!(!guild.channels.contains_key(&id));
+    }
+
+    #[test]
+    fn update_incidents_data() {
+        let mut guild = guild();
+        let mut value = serde_json::from_str::<serde_json::Value>(GUILD_CREATE).unwrap();
+        value["incidents_data"] = serde_json::from_str(r#"{
+            "invites_disabled_until": "2024-01-01T00:00:00+00:00"
+        }"#).unwrap();
+        value["embed_enabled"] = serde_json::Value::Bool(false);
+
+        assert!(guild.incidents_data.is_none());
+
+        guild.apply_update(&GuildUpdateEvent {
+            guild: serde_json::from_value::<PartialGuild>(value).unwrap(),
+        });
+
+        assert!(guild.incidents_data.is_some());
+    }
+}
@@ -0,0 +1,28 @@
+use super::super::{ChannelId, EmojiId};
+
+/// The screen shown to new members of a community guild, highlighting some of
+/// its channels.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WelcomeScreen {
+    /// The description of the guild shown on the screen.
+    pub description: Option<String>,
+    /// The channels shown on the screen, up to 5.
+    #[serde(default)]
+    pub welcome_channels: Vec<WelcomeScreenChannel>,
+}
+
+/// A channel shown on a [`WelcomeScreen`].
+///
+/// [`WelcomeScreen`]: struct.WelcomeScreen.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WelcomeScreenChannel {
+    /// The Id of the channel.
+    pub channel_id: ChannelId,
+    /// The description shown for the channel.
+    pub description: String,
+    /// The Id of the custom emoji shown beside the channel, if it has one.
+    pub emoji_id: Option<EmojiId>,
+    /// The name of the emoji shown beside the channel, or the emoji itself if
+    /// it is a unicode emoji.
+    pub emoji_name: Option<String>,
+}
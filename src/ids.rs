@@ -0,0 +1,299 @@
+//! A collection of newtypes defining type-strong IDs.
+
+use chrono::NaiveDateTime;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::result::Result as StdResult;
+use std::str::FromStr;
+use super::misc::Mentionable;
+use super::utils::{Snowflake, SnowflakeParts, U64Visitor};
+use super::{
+    ApplicationCommand,
+    CommandAutocompleteInteraction,
+    CurrentUser,
+    CurrentUserGuild,
+    Emoji,
+    Guild,
+    GuildChannel,
+    GuildInfo,
+    GuildScheduledEvent,
+    Integration,
+    InviteGuild,
+    Member,
+    Message,
+    PartialGuild,
+    PrivateChannel,
+    Role,
+    StickerItem,
+    User,
+};
+
+/// The hasher used by [`IdMap`].
+///
+/// Snowflakes are not attacker-controlled in a way that benefits from
+/// SipHash's DoS resistance, so the much faster FxHash is used instead.
+///
+/// [`IdMap`]: type.IdMap.html
+pub type IdBuildHasher = ::fxhash::FxBuildHasher;
+
+/// A map keyed by Ids, as used for the collections within models such as
+/// [`Guild`].
+///
+/// [`Guild`]: struct.Guild.html
+pub type IdMap<K, V> = HashMap<K, V, IdBuildHasher>;
+
+/// Defines an Id newtype over a snowflake.
+///
+/// Ids are ordered by their snowflakes, which sorts them by when they were
+/// created, and so can key ordered collections such as a `BTreeMap`. They are
+/// displayed as the raw snowflake, while [`Mentionable::mention`] gives the
+/// markup for the Ids that can be mentioned.
+///
+/// An Id may optionally be given a mention prefix in square brackets, which is
+/// used to implement [`Mentionable`] and to accept the mention form when
+/// parsing. Models listed in braces, alongside the path to their Id, receive
+/// `From` implementations for both owned values and references.
+///
+/// [`Mentionable`]: trait.Mentionable.html
+/// [`Mentionable::mention`]: trait.Mentionable.html#tymethod.mention
+macro_rules! id_type {
+    ($(
+        #[$attr:meta]
+        $name:ident $([$prefix:expr])* {
+            $($from:ident => $($field:ident).+,)*
+        }
+    )*) => {
+        $(
+            #[$attr]
+            #[cfg_attr(feature = "cargo-clippy", allow(derive_hash_xor_eq))]
+            #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
+            pub struct $name(pub u64);
+
+            impl $name {
+                /// Creates an Id from a raw snowflake.
+                ///
+                /// This can be used in constant contexts, such as for static
+                /// configuration tables.
+                #[inline]
+                pub const fn new(id: u64) -> Self { $name(id) }
+
+                /// Retrieves the raw snowflake of the Id.
+                #[inline]
+                pub const fn get(&self) -> u64 { self.0 }
+
+                /// Retrieves the time that the Id was created at.
+                pub fn created_at(&self) -> NaiveDateTime {
+                    let offset = (self.0 >> 22) / 1000;
+
+                    NaiveDateTime::from_timestamp(1_420_070_400 + offset as i64, 0)
+                }
+
+                /// Splits the Id into the individual parts of its snowflake.
+                #[inline]
+                pub fn decompose(&self) -> SnowflakeParts { Snowflake(self.0).decompose() }
+            }
+
+            // This is a hack so that functions can accept `IntoIterator<Item=IdType>`, and or `IntoIterator<Item=&IdType>`
+            impl AsRef<$name> for $name {
+                fn as_ref(&self) -> &Self {
+                    self
+                }
+            }
+
+            impl From<u64> for $name {
+                fn from(id_as_u64: u64) -> $name {
+                    $name(id_as_u64)
+                }
+            }
+
+            impl From<$name> for Snowflake {
+                fn from(id: $name) -> Snowflake { Snowflake(id.0) }
+            }
+
+            impl PartialEq for $name {
+                fn eq(&self, other: &Self) -> bool {
+                    self.0 == other.0
+                }
+            }
+
+            impl PartialEq<u64> for $name {
+                fn eq(&self, u: &u64) -> bool {
+                    self.0 == *u
+                }
+            }
+
+            impl PartialOrd<u64> for $name {
+                fn partial_cmp(&self, u: &u64) -> Option<Ordering> {
+                    self.0.partial_cmp(u)
+                }
+            }
+
+            impl Display for $name {
+                fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                    Display::fmt(&self.0, f)
+                }
+            }
+
+            impl FromStr for $name {
+                type Err = IdParseError;
+
+                fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+                    let prefix: Option<&str> = None$(.or(Some($prefix)))*;
+
+                    parse_snowflake(s, prefix).map($name).ok_or(IdParseError::InvalidFormat)
+                }
+            }
+
+            // Deserialization accepts both strings and numbers.
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+                    deserializer.deserialize_any(U64Visitor).map($name)
+                }
+            }
+
+            // Ids are serialized as strings, as snowflakes can not be
+            // represented losslessly by JavaScript-compatible tooling.
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+                    serializer.collect_str(&self.0)
+                }
+            }
+
+            $(
+                impl Mentionable for $name {
+                    fn mention(&self) -> String { format!("<{}{}>", $prefix, self.0) }
+                }
+            )*
+
+            $(
+                impl From<$from> for $name {
+                    fn from(value: $from) -> $name { value$(.$field)+ }
+                }
+
+                impl<'a> From<&'a $from> for $name {
+                    fn from(value: &$from) -> $name { value$(.$field)+ }
+                }
+            )*
+        )*
+    }
+}
+
+id_type! {
+    /// An identifier for a Channel
+    ChannelId ["#"] {
+        GuildChannel => id,
+        PrivateChannel => id,
+    }
+    /// An identifier for an Emoji
+    EmojiId {
+        Emoji => id,
+    }
+    /// An identifier for a Guild
+    GuildId {
+        CurrentUserGuild => id,
+        Guild => id,
+        GuildInfo => id,
+        InviteGuild => id,
+        PartialGuild => id,
+    }
+    /// An identifier for an Integration
+    IntegrationId {
+        Integration => id,
+    }
+    /// An identifier for a Message
+    MessageId {
+        Message => id,
+    }
+    /// An identifier for a Role
+    RoleId ["@&"] {
+        Role => id,
+    }
+    /// An identifier for a [`StickerItem`](struct.StickerItem.html).
+    StickerId {
+        StickerItem => id,
+    }
+    /// An identifier for a User
+    UserId ["@"] {
+        CurrentUser => id,
+        Member => user.id,
+        User => id,
+    }
+    /// An identifier for a [`GuildScheduledEvent`](struct.GuildScheduledEvent.html).
+    ScheduledEventId {
+        GuildScheduledEvent => id,
+    }
+    /// An identifier for a [`Webhook`](struct.Webhook.html).
+    WebhookId {}
+    /// An identifier for an audit log entry.
+    AuditLogEntryId {}
+    /// An identifier for an application.
+    ApplicationId {}
+    /// An identifier for an application command.
+    CommandId {
+        ApplicationCommand => id,
+    }
+    /// An identifier for an interaction.
+    InteractionId {
+        CommandAutocompleteInteraction => id,
+    }
+}
+
+/// An error returned when parsing an Id from a string fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IdParseError {
+    /// The string was neither a snowflake nor a mention of the Id's kind.
+    InvalidFormat,
+}
+
+impl Display for IdParseError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(self.description()) }
+}
+
+impl StdError for IdParseError {
+    fn description(&self) -> &str {
+        match *self {
+            IdParseError::InvalidFormat => "invalid id format",
+        }
+    }
+}
+
+/// An error returned when parsing a [`UserId`] from a string fails.
+///
+/// [`UserId`]: struct.UserId.html
+#[deprecated(since = "0.1.0", note = "use `IdParseError` instead")]
+pub type UserIdParseError = IdParseError;
+
+/// An error returned when parsing a [`RoleId`] from a string fails.
+///
+/// [`RoleId`]: struct.RoleId.html
+#[deprecated(since = "0.1.0", note = "use `IdParseError` instead")]
+pub type RoleIdParseError = IdParseError;
+
+/// Parses either a raw snowflake or, if the Id is mentionable, a mention of
+/// the form `<PREFIX ID>`. User mentions may additionally carry a `!` marking
+/// a nickname mention.
+fn parse_snowflake(s: &str, prefix: Option<&str>) -> Option<u64> {
+    if let Some(prefix) = prefix {
+        if s.starts_with('<') && s.ends_with('>') && s.len() > 2 {
+            let inner = &s[1..s.len() - 1];
+
+            if !inner.starts_with(prefix) {
+                return None;
+            }
+
+            let mut id = &inner[prefix.len()..];
+
+            if prefix == "@" && id.starts_with('!') {
+                id = &id[1..];
+            }
+
+            return id.parse::<u64>().ok();
+        }
+    }
+
+    s.parse::<u64>().ok()
+}
@@ -0,0 +1,1403 @@
+//! Models for interactions, which are sent when a user uses an application
+//! command or one of its features.
+
+use chrono::{DateTime, FixedOffset};
+use serde_json;
+use serenity_common::prelude::Value;
+use std::error::Error as StdError;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::result::Result as StdResult;
+use super::permissions::Permissions;
+use super::utils::deserialize_localizations;
+use super::{
+    ApplicationId,
+    ChannelId,
+    ChannelType,
+    CommandId,
+    GuildId,
+    IdMap,
+    InteractionId,
+    Locale,
+    LocalizationMap,
+    Message,
+    MessageId,
+    Role,
+    RoleId,
+    Snowflake,
+    ThreadMetadata,
+    User,
+    UserId,
+};
+
+#[cfg(feature = "model")]
+use http;
+#[cfg(feature = "model")]
+use internal::prelude::*;
+#[cfg(feature = "model")]
+use super::ModelError;
+
+/// The maximum number of choices that can be given in response to an
+/// autocomplete interaction.
+pub const MAX_AUTOCOMPLETE_CHOICES: usize = 25;
+
+/// The maximum number of fixed choices that an option of an application
+/// command may have.
+pub const MAX_COMMAND_CHOICES: usize = 25;
+
+/// The maximum length of the description of an application command or
+/// option.
+pub const MAX_COMMAND_DESCRIPTION_LENGTH: usize = 100;
+
+/// The maximum length of the name of an application command or option.
+pub const MAX_COMMAND_NAME_LENGTH: usize = 32;
+
+/// The maximum number of options that an application command, subcommand, or
+/// subcommand group may have.
+pub const MAX_COMMAND_OPTIONS: usize = 25;
+
+/// The maximum number of [`ChatInput`] commands that can be registered
+/// globally, or to a single guild.
+///
+/// [`ChatInput`]: enum.CommandType.html#variant.ChatInput
+pub const MAX_CHAT_INPUT_COMMANDS: usize = 100;
+
+/// The maximum number of [`User`] or [`Message`] commands, each, that can be
+/// registered globally, or to a single guild.
+///
+/// [`Message`]: enum.CommandType.html#variant.Message
+/// [`User`]: enum.CommandType.html#variant.User
+pub const MAX_CONTEXT_MENU_COMMANDS: usize = 5;
+
+enum_number!(
+    #[doc="The type of an interaction."]
+    InteractionType {
+        /// A check sent by Discord to verify an interactions endpoint.
+        Ping = 1,
+        /// An application command was used.
+        ApplicationCommand = 2,
+        /// A message component was used.
+        MessageComponent = 3,
+        /// An option of an application command is being filled in.
+        ApplicationCommandAutocomplete = 4,
+        /// A modal was submitted.
+        ModalSubmit = 5,
+    }
+);
+
+enum_number!(
+    #[doc="The type of a response to an interaction."]
+    InteractionResponseType {
+        #[doc="Acknowledges a [`Ping`].
+
+[`Ping`]: enum.InteractionType.html#variant.Ping"]
+        Pong = 1,
+        /// Responds with a message.
+        ChannelMessageWithSource = 4,
+        /// Acknowledges the interaction, showing a loading state until a message is sent.
+        DeferredChannelMessageWithSource = 5,
+        /// Acknowledges a component interaction, to edit its message later.
+        DeferredUpdateMessage = 6,
+        /// Edits the message of a component interaction.
+        UpdateMessage = 7,
+        /// Responds to an autocomplete interaction with choices.
+        ApplicationCommandAutocompleteResult = 8,
+        /// Responds with a modal.
+        Modal = 9,
+    }
+);
+
+enum_number!(
+    #[doc="The type of an option of an application command."]
+    CommandOptionType {
+        /// A subcommand, which has its own options.
+        SubCommand = 1,
+        /// A group of subcommands.
+        SubCommandGroup = 2,
+        /// A string.
+        String = 3,
+        /// An integer.
+        Integer = 4,
+        /// A boolean.
+        Boolean = 5,
+        /// A user, given by Id.
+        User = 6,
+        /// A channel, given by Id.
+        Channel = 7,
+        /// A role, given by Id.
+        Role = 8,
+        /// A user or role, given by Id.
+        Mentionable = 9,
+        /// A floating point number.
+        Number = 10,
+        /// An attachment, given by Id.
+        Attachment = 11,
+    }
+);
+
+enum_number!(
+    #[doc="The type of an application command, which determines how it is used."]
+    CommandType {
+        /// A slash command, used by typing it in the message box.
+        ChatInput = 1,
+        /// A context menu command, used by right clicking a user.
+        User = 2,
+        /// A context menu command, used by right clicking a message.
+        Message = 3,
+    }
+);
+
+impl Default for CommandType {
+    fn default() -> Self { CommandType::ChatInput }
+}
+
+/// An application command registered by an application, either globally or
+/// for a single guild.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ApplicationCommand {
+    /// The Id of the command.
+    pub id: CommandId,
+    /// The Id of the application that the command belongs to.
+    pub application_id: ApplicationId,
+    /// The set of permissions required to use the command by default.
+    pub default_member_permissions: Option<Permissions>,
+    /// The description of the command.
+    ///
+    /// This is empty for [`User`] and [`Message`] commands.
+    ///
+    /// [`Message`]: enum.CommandType.html#variant.Message
+    /// [`User`]: enum.CommandType.html#variant.User
+    pub description: String,
+    /// The localized descriptions of the command.
+    #[serde(default, deserialize_with = "deserialize_localizations")]
+    pub description_localizations: Option<LocalizationMap>,
+    /// The Id of the guild the command is registered to, if it is not a
+    /// global command.
+    pub guild_id: Option<GuildId>,
+    /// The type of the command.
+    #[serde(default, rename = "type")]
+    pub kind: CommandType,
+    /// The name of the command.
+    pub name: String,
+    /// The localized names of the command.
+    #[serde(default, deserialize_with = "deserialize_localizations")]
+    pub name_localizations: Option<LocalizationMap>,
+    /// The options of the command.
+    ///
+    /// This is always empty for [`User`] and [`Message`] commands.
+    ///
+    /// [`Message`]: enum.CommandType.html#variant.Message
+    /// [`User`]: enum.CommandType.html#variant.User
+    #[serde(default)]
+    pub options: Vec<CommandOption>,
+}
+
+impl ApplicationCommand {
+    /// Whether the command is a context menu command, used by right clicking
+    /// a user or message.
+    pub fn is_context_menu(&self) -> bool { self.kind != CommandType::ChatInput }
+}
+
+/// An option of an [`ApplicationCommand`].
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+#[derive(Clone, Debug, Deserialize)]
+pub struct CommandOption {
+    /// Whether choices are suggested through autocomplete interactions.
+    #[serde(default)]
+    pub autocomplete: bool,
+    /// The types of channels that can be given, for [`Channel`] options.
+    ///
+    /// [`Channel`]: enum.CommandOptionType.html#variant.Channel
+    #[serde(default)]
+    pub channel_types: Vec<ChannelType>,
+    /// The fixed choices of the option, if any.
+    #[serde(default)]
+    pub choices: Vec<AutocompleteChoice>,
+    /// The description of the option.
+    pub description: String,
+    /// The localized descriptions of the option.
+    #[serde(default, deserialize_with = "deserialize_localizations")]
+    pub description_localizations: Option<LocalizationMap>,
+    /// The type of the option.
+    #[serde(rename = "type")]
+    pub kind: CommandOptionType,
+    /// The name of the option.
+    pub name: String,
+    /// The localized names of the option.
+    #[serde(default, deserialize_with = "deserialize_localizations")]
+    pub name_localizations: Option<LocalizationMap>,
+    /// The options of a subcommand or subcommand group.
+    #[serde(default)]
+    pub options: Vec<CommandOption>,
+    /// Whether the option must be given.
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// The data of an interaction sent when an application command is used.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CommandInteractionData {
+    /// The Id of the command.
+    pub id: CommandId,
+    /// The Id of the guild the command is registered to, if it is not a
+    /// global command.
+    pub guild_id: Option<GuildId>,
+    /// The type of the command.
+    #[serde(default, rename = "type")]
+    pub kind: CommandType,
+    /// The name of the command.
+    pub name: String,
+    /// The options given by the user.
+    #[serde(default)]
+    pub options: Vec<CommandDataOption>,
+    /// The entities referenced by the options or the target.
+    #[serde(default)]
+    pub resolved: ResolvedData,
+    /// The Id of the user or message targeted by a context menu command.
+    ///
+    /// Use [`target_user`] or [`target_message`] to retrieve the target
+    /// itself.
+    ///
+    /// [`target_message`]: #method.target_message
+    /// [`target_user`]: #method.target_user
+    pub target_id: Option<Snowflake>,
+}
+
+impl CommandInteractionData {
+    /// Retrieves the guild member targeted by a [`User`] command, when used
+    /// in a guild.
+    ///
+    /// [`User`]: enum.CommandType.html#variant.User
+    pub fn target_member(&self) -> Option<&ResolvedMember> {
+        if self.kind != CommandType::User {
+            return None;
+        }
+
+        self.resolved.members.get(&UserId(self.target_id?.0))
+    }
+
+    /// Retrieves the message targeted by a [`Message`] command.
+    ///
+    /// [`Message`]: enum.CommandType.html#variant.Message
+    pub fn target_message(&self) -> Option<&Message> {
+        if self.kind != CommandType::Message {
+            return None;
+        }
+
+        self.resolved.messages.get(&MessageId(self.target_id?.0))
+    }
+
+    /// Retrieves the user targeted by a [`User`] command.
+    ///
+    /// [`User`]: enum.CommandType.html#variant.User
+    pub fn target_user(&self) -> Option<&User> {
+        if self.kind != CommandType::User {
+            return None;
+        }
+
+        self.resolved.users.get(&UserId(self.target_id?.0))
+    }
+}
+
+/// An interaction sent while a user is filling in an option of an application
+/// command which has autocomplete enabled.
+///
+/// The interaction's token is redacted from the `Debug` output, and can only be
+/// accessed via [`expose_token`].
+///
+/// [`expose_token`]: #method.expose_token
+#[derive(Clone, Deserialize)]
+pub struct CommandAutocompleteInteraction {
+    /// The Id of the interaction.
+    pub id: InteractionId,
+    /// The Id of the application that the command belongs to.
+    pub application_id: ApplicationId,
+    /// The Id of the channel that the command is being used in.
+    pub channel_id: Option<ChannelId>,
+    /// The command being used, and the options filled in so far.
+    pub data: CommandAutocompleteData,
+    /// The Id of the guild that the command is being used in, if any.
+    pub guild_id: Option<GuildId>,
+    /// The type of the interaction, which is always
+    /// [`ApplicationCommandAutocomplete`].
+    ///
+    /// [`ApplicationCommandAutocomplete`]: enum.InteractionType.html#variant.ApplicationCommandAutocomplete
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// The locale of the user using the command.
+    pub locale: Option<String>,
+    /// The member using the command, when used in a guild.
+    pub member: Option<InteractionMember>,
+    token: String,
+    /// The user using the command, when used outside of a guild.
+    pub user: Option<User>,
+    /// The version of the interaction format, which is always `1`.
+    pub version: u64,
+}
+
+impl CommandAutocompleteInteraction {
+    /// Retrieves the user using the command, whether or not it is used in a
+    /// guild.
+    pub fn author(&self) -> Option<&User> {
+        self.member.as_ref().map(|member| &member.user).or_else(|| self.user.as_ref())
+    }
+
+    /// Retrieves the interaction's token, used to respond to it.
+    #[inline]
+    pub fn expose_token(&self) -> &str { &self.token }
+}
+
+#[cfg(feature = "model")]
+impl CommandAutocompleteInteraction {
+    /// Responds to the interaction with the choices to suggest to the user.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// // assumes an `interaction` has already been bound
+    /// interaction.respond(|r| r
+    ///     .add_choice(AutocompleteChoice::new("Red", "red"))
+    ///     .add_choice(AutocompleteChoice::new("Blue", "blue")))?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyChoices`] if more than
+    /// [`MAX_AUTOCOMPLETE_CHOICES`] choices are given.
+    ///
+    /// [`MAX_AUTOCOMPLETE_CHOICES`]: constant.MAX_AUTOCOMPLETE_CHOICES.html
+    /// [`ModelError::TooManyChoices`]: enum.ModelError.html#variant.TooManyChoices
+    pub fn respond<F>(&self, f: F) -> Result<()>
+        where F: FnOnce(CreateAutocompleteResponse) -> CreateAutocompleteResponse {
+        let response = f(CreateAutocompleteResponse::default());
+
+        if response.choices.len() > MAX_AUTOCOMPLETE_CHOICES {
+            let over = response.choices.len() - MAX_AUTOCOMPLETE_CHOICES;
+
+            return Err(Error::Model(ModelError::TooManyChoices(over as u64)));
+        }
+
+        let map = serde_json::to_value(&InteractionResponse {
+            data: &response,
+            kind: InteractionResponseType::ApplicationCommandAutocompleteResult,
+        })?;
+
+        http::create_interaction_response(self.id.0, self.expose_token(), &map)
+    }
+}
+
+impl Debug for CommandAutocompleteInteraction {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("CommandAutocompleteInteraction")
+            .field("id", &self.id)
+            .field("application_id", &self.application_id)
+            .field("channel_id", &self.channel_id)
+            .field("data", &self.data)
+            .field("guild_id", &self.guild_id)
+            .field("kind", &self.kind)
+            .field("locale", &self.locale)
+            .field("member", &self.member)
+            .field("token", &"[redacted]")
+            .field("user", &self.user)
+            .field("version", &self.version)
+            .finish()
+    }
+}
+
+/// The command of a [`CommandAutocompleteInteraction`].
+///
+/// [`CommandAutocompleteInteraction`]: struct.CommandAutocompleteInteraction.html
+#[derive(Clone, Debug, Deserialize)]
+pub struct CommandAutocompleteData {
+    /// The Id of the command.
+    pub id: CommandId,
+    /// The Id of the guild the command is registered to, if it is not a
+    /// global command.
+    pub guild_id: Option<GuildId>,
+    /// The name of the command.
+    pub name: String,
+    /// The options filled in so far.
+    #[serde(default)]
+    pub options: Vec<CommandDataOption>,
+}
+
+impl CommandAutocompleteData {
+    /// Retrieves the option that the user is currently filling in, searching
+    /// through subcommands.
+    pub fn focused_option(&self) -> Option<&CommandDataOption> {
+        find_focused(&self.options)
+    }
+}
+
+/// An option of a command, as filled in by a user.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CommandDataOption {
+    /// Whether the user is currently filling in the option.
+    #[serde(default)]
+    pub focused: bool,
+    /// The type of the option.
+    #[serde(rename = "type")]
+    pub kind: CommandOptionType,
+    /// The name of the option.
+    pub name: String,
+    /// The options of a subcommand or subcommand group.
+    #[serde(default)]
+    pub options: Vec<CommandDataOption>,
+    /// The value of the option.
+    ///
+    /// For a [`focused`] option, this is the partial input of the user and is
+    /// always a string, regardless of the option's type.
+    ///
+    /// [`focused`]: #structfield.focused
+    pub value: Option<Value>,
+}
+
+/// The member using an application command, along with their permissions in
+/// the channel it is used in.
+#[derive(Clone, Debug, Deserialize)]
+pub struct InteractionMember {
+    /// The time the member joined the guild.
+    pub joined_at: Option<DateTime<FixedOffset>>,
+    /// The member's nickname, if present.
+    pub nick: Option<String>,
+    /// The permissions of the member in the channel.
+    pub permissions: Permissions,
+    /// The Ids of the member's roles.
+    pub roles: Vec<RoleId>,
+    /// The user of the member.
+    pub user: User,
+}
+
+/// The entities referenced by an interaction, such as those selected in a
+/// select menu or targeted by a context menu command.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ResolvedData {
+    /// The referenced channels.
+    #[serde(default)]
+    pub channels: IdMap<ChannelId, ResolvedChannel>,
+    /// The guild members of the referenced users, when used in a guild.
+    #[serde(default)]
+    pub members: IdMap<UserId, ResolvedMember>,
+    /// The referenced messages.
+    #[serde(default)]
+    pub messages: IdMap<MessageId, Message>,
+    /// The referenced roles.
+    #[serde(default)]
+    pub roles: IdMap<RoleId, Role>,
+    /// The referenced users.
+    #[serde(default)]
+    pub users: IdMap<UserId, User>,
+}
+
+/// A partial channel referenced by an interaction.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ResolvedChannel {
+    /// The Id of the channel.
+    pub id: ChannelId,
+    /// The type of the channel.
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    /// The name of the channel.
+    pub name: Option<String>,
+    /// The Id of the category or, for threads, the channel that the channel
+    /// belongs to.
+    pub parent_id: Option<ChannelId>,
+    /// The permissions of the interacting user in the channel.
+    pub permissions: Permissions,
+    /// Thread-specific information, if the channel is a thread.
+    pub thread_metadata: Option<ThreadMetadata>,
+}
+
+/// A partial guild member of a user referenced by an interaction.
+///
+/// The user itself is available in [`ResolvedData::users`].
+///
+/// [`ResolvedData::users`]: struct.ResolvedData.html#structfield.users
+#[derive(Clone, Debug, Deserialize)]
+pub struct ResolvedMember {
+    /// The time until which the member is timed out, if they are.
+    #[serde(default)]
+    pub communication_disabled_until: Option<DateTime<FixedOffset>>,
+    /// The time the member joined the guild.
+    pub joined_at: Option<DateTime<FixedOffset>>,
+    /// The member's nickname, if present.
+    pub nick: Option<String>,
+    /// Whether the member has yet to complete membership screening.
+    #[serde(default)]
+    pub pending: bool,
+    /// The permissions of the member in the channel the interaction was sent
+    /// from.
+    pub permissions: Option<Permissions>,
+    /// The time the member started boosting the guild, if they are.
+    #[serde(default)]
+    pub premium_since: Option<DateTime<FixedOffset>>,
+    /// The Ids of the member's roles.
+    pub roles: Vec<RoleId>,
+}
+
+/// A choice suggested to the user in response to an autocomplete interaction.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AutocompleteChoice {
+    /// The name of the choice shown to the user.
+    pub name: String,
+    /// The localized names of the choice.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_localizations",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name_localizations: Option<LocalizationMap>,
+    /// The value used for the option if the choice is picked.
+    ///
+    /// This must match the option's type, being a string, integer, or number.
+    pub value: Value,
+}
+
+impl AutocompleteChoice {
+    /// Creates a choice with a name and a value.
+    pub fn new<N: ToString, V: Into<Value>>(name: N, value: V) -> AutocompleteChoice {
+        AutocompleteChoice {
+            name: name.to_string(),
+            name_localizations: None,
+            value: value.into(),
+        }
+    }
+
+    /// Sets the name of the choice shown to users of a locale.
+    pub fn localized_name<N: ToString>(mut self, locale: Locale, name: N) -> Self {
+        self.name_localizations
+            .get_or_insert_with(LocalizationMap::new)
+            .insert(locale, name.to_string());
+
+        self
+    }
+}
+
+/// The payload used to respond to an autocomplete interaction.
+///
+/// Up to [`MAX_AUTOCOMPLETE_CHOICES`] choices can be given.
+///
+/// [`MAX_AUTOCOMPLETE_CHOICES`]: constant.MAX_AUTOCOMPLETE_CHOICES.html
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateAutocompleteResponse {
+    choices: Vec<AutocompleteChoice>,
+}
+
+impl CreateAutocompleteResponse {
+    /// Adds a choice to the response.
+    pub fn add_choice(mut self, choice: AutocompleteChoice) -> Self {
+        self.choices.push(choice);
+
+        self
+    }
+
+    /// Sets the choices of the response, replacing any already added.
+    pub fn choices(mut self, choices: Vec<AutocompleteChoice>) -> Self {
+        self.choices = choices;
+
+        self
+    }
+}
+
+/// The payload used to create or overwrite an application command.
+///
+/// Call [`to_value`] to validate the command before it is sent, or
+/// [`commands_to_value`] to build the payload of a bulk overwrite.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use serenity_model::{CommandOptionType, CreateApplicationCommand};
+/// use serenity_model::CreateApplicationCommandOption as CreateOption;
+///
+/// let command = CreateApplicationCommand::new("ban", "Bans a member")
+///     .add_option(CreateOption::new(CommandOptionType::User, "member", "The member to ban")
+///         .required(true));
+///
+/// let value = command.to_value()?;
+/// ```
+///
+/// [`commands_to_value`]: fn.commands_to_value.html
+/// [`to_value`]: #method.to_value
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateApplicationCommand {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_member_permissions: Option<String>,
+    description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description_localizations: Option<LocalizationMap>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dm_permission: Option<bool>,
+    #[serde(rename = "type")]
+    kind: CommandType,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name_localizations: Option<LocalizationMap>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nsfw: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    options: Vec<CreateApplicationCommandOption>,
+}
+
+impl CreateApplicationCommand {
+    /// Creates a [`ChatInput`] command with a name and a description.
+    ///
+    /// [`ChatInput`]: enum.CommandType.html#variant.ChatInput
+    pub fn new<N: ToString, D: ToString>(name: N, description: D) -> Self {
+        CreateApplicationCommand::default()
+            .name(name)
+            .description(description)
+    }
+
+    /// Creates a context menu command of the given type, which has no
+    /// description or options.
+    pub fn context_menu<N: ToString>(kind: CommandType, name: N) -> Self {
+        CreateApplicationCommand::default()
+            .kind(kind)
+            .name(name)
+    }
+
+    /// Adds an option to the command.
+    pub fn add_option(mut self, option: CreateApplicationCommandOption) -> Self {
+        self.options.push(option);
+
+        self
+    }
+
+    /// Sets the set of permissions required to use the command by default.
+    ///
+    /// Giving an empty set hides the command from everyone but
+    /// administrators, until it is allowed for others from the guild's
+    /// settings.
+    pub fn default_member_permissions(mut self, permissions: Permissions) -> Self {
+        self.default_member_permissions = Some(permissions.bits().to_string());
+
+        self
+    }
+
+    /// Sets the description of the command, of up to
+    /// [`MAX_COMMAND_DESCRIPTION_LENGTH`] characters.
+    ///
+    /// This must be empty for context menu commands.
+    ///
+    /// [`MAX_COMMAND_DESCRIPTION_LENGTH`]: constant.MAX_COMMAND_DESCRIPTION_LENGTH.html
+    pub fn description<D: ToString>(mut self, description: D) -> Self {
+        self.description = description.to_string();
+
+        self
+    }
+
+    /// Sets whether a global command can be used in direct messages.
+    pub fn dm_permission(mut self, dm_permission: bool) -> Self {
+        self.dm_permission = Some(dm_permission);
+
+        self
+    }
+
+    /// Sets the type of the command.
+    ///
+    /// Defaults to [`ChatInput`].
+    ///
+    /// [`ChatInput`]: enum.CommandType.html#variant.ChatInput
+    pub fn kind(mut self, kind: CommandType) -> Self {
+        self.kind = kind;
+
+        self
+    }
+
+    /// Sets the description of the command shown to users of a locale.
+    pub fn localized_description<D: ToString>(mut self, locale: Locale, description: D) -> Self {
+        self.description_localizations
+            .get_or_insert_with(LocalizationMap::new)
+            .insert(locale, description.to_string());
+
+        self
+    }
+
+    /// Sets the name of the command shown to users of a locale.
+    pub fn localized_name<N: ToString>(mut self, locale: Locale, name: N) -> Self {
+        self.name_localizations
+            .get_or_insert_with(LocalizationMap::new)
+            .insert(locale, name.to_string());
+
+        self
+    }
+
+    /// Sets the name of the command.
+    ///
+    /// Refer to [`CommandError::InvalidName`] for the names that are allowed.
+    ///
+    /// [`CommandError::InvalidName`]: enum.CommandError.html#variant.InvalidName
+    pub fn name<N: ToString>(mut self, name: N) -> Self {
+        self.name = name.to_string();
+
+        self
+    }
+
+    /// Sets whether the command is age-restricted.
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = Some(nsfw);
+
+        self
+    }
+
+    /// Sets the options of the command, replacing any already added.
+    pub fn options(mut self, options: Vec<CreateApplicationCommandOption>) -> Self {
+        self.options = options;
+
+        self
+    }
+
+    /// Validates the command and converts it into a value suitable for
+    /// creating or editing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CommandError`] if the name or description of the command
+    /// or one of its options is invalid, if there are too many options or
+    /// choices, or if a context menu command has a description or options.
+    ///
+    /// [`CommandError`]: enum.CommandError.html
+    pub fn to_value(&self) -> StdResult<Value, CommandError> {
+        self.validate()?;
+
+        serde_json::to_value(self).map_err(|why| CommandError::Serialization(why.to_string()))
+    }
+
+    fn validate(&self) -> StdResult<(), CommandError> {
+        if self.kind == CommandType::ChatInput {
+            validate_name(&self.name, true)?;
+            validate_description(&self.description)?;
+            validate_options(&self.options, true)?;
+
+            for name in self.name_localizations.iter().flat_map(|map| map.values()) {
+                validate_name(name, true)?;
+            }
+        } else {
+            validate_name(&self.name, false)?;
+
+            if !self.description.is_empty() || !self.options.is_empty() {
+                return Err(CommandError::ContextMenuOptions);
+            }
+
+            for name in self.name_localizations.iter().flat_map(|map| map.values()) {
+                validate_name(name, false)?;
+            }
+        }
+
+        for description in self.description_localizations.iter().flat_map(|map| map.values()) {
+            validate_description(description)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An option of a [`CreateApplicationCommand`].
+///
+/// [`CreateApplicationCommand`]: struct.CreateApplicationCommand.html
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateApplicationCommandOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    autocomplete: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    channel_types: Vec<ChannelType>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    choices: Vec<AutocompleteChoice>,
+    description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description_localizations: Option<LocalizationMap>,
+    #[serde(rename = "type")]
+    kind: CommandOptionType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_value: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_value: Option<Value>,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name_localizations: Option<LocalizationMap>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    options: Vec<CreateApplicationCommandOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required: Option<bool>,
+}
+
+impl CreateApplicationCommandOption {
+    /// Creates an option of a type, with a name and a description.
+    pub fn new<N: ToString, D: ToString>(kind: CommandOptionType, name: N, description: D)
+        -> Self {
+        CreateApplicationCommandOption {
+            autocomplete: None,
+            channel_types: vec![],
+            choices: vec![],
+            description: description.to_string(),
+            description_localizations: None,
+            kind: kind,
+            max_length: None,
+            max_value: None,
+            min_length: None,
+            min_value: None,
+            name: name.to_string(),
+            name_localizations: None,
+            options: vec![],
+            required: None,
+        }
+    }
+
+    /// Adds a fixed choice to the option.
+    ///
+    /// Choices can only be given for [`String`], [`Integer`], and [`Number`]
+    /// options, and not alongside [`autocomplete`].
+    ///
+    /// [`autocomplete`]: #method.autocomplete
+    /// [`Integer`]: enum.CommandOptionType.html#variant.Integer
+    /// [`Number`]: enum.CommandOptionType.html#variant.Number
+    /// [`String`]: enum.CommandOptionType.html#variant.String
+    pub fn add_choice(mut self, choice: AutocompleteChoice) -> Self {
+        self.choices.push(choice);
+
+        self
+    }
+
+    /// Adds a nested option, to a [`SubCommand`] or [`SubCommandGroup`].
+    ///
+    /// [`SubCommand`]: enum.CommandOptionType.html#variant.SubCommand
+    /// [`SubCommandGroup`]: enum.CommandOptionType.html#variant.SubCommandGroup
+    pub fn add_option(mut self, option: CreateApplicationCommandOption) -> Self {
+        self.options.push(option);
+
+        self
+    }
+
+    /// Sets whether choices are suggested through autocomplete interactions.
+    pub fn autocomplete(mut self, autocomplete: bool) -> Self {
+        self.autocomplete = Some(autocomplete);
+
+        self
+    }
+
+    /// Sets the types of channels that can be given, for [`Channel`]
+    /// options.
+    ///
+    /// [`Channel`]: enum.CommandOptionType.html#variant.Channel
+    pub fn channel_types(mut self, channel_types: Vec<ChannelType>) -> Self {
+        self.channel_types = channel_types;
+
+        self
+    }
+
+    /// Sets the fixed choices of the option, replacing any already added.
+    pub fn choices(mut self, choices: Vec<AutocompleteChoice>) -> Self {
+        self.choices = choices;
+
+        self
+    }
+
+    /// Sets the description of the option shown to users of a locale.
+    pub fn localized_description<D: ToString>(mut self, locale: Locale, description: D) -> Self {
+        self.description_localizations
+            .get_or_insert_with(LocalizationMap::new)
+            .insert(locale, description.to_string());
+
+        self
+    }
+
+    /// Sets the name of the option shown to users of a locale.
+    pub fn localized_name<N: ToString>(mut self, locale: Locale, name: N) -> Self {
+        self.name_localizations
+            .get_or_insert_with(LocalizationMap::new)
+            .insert(locale, name.to_string());
+
+        self
+    }
+
+    /// Sets the maximum length of a [`String`] option, of up to 6000.
+    ///
+    /// [`String`]: enum.CommandOptionType.html#variant.String
+    pub fn max_length(mut self, max_length: u16) -> Self {
+        self.max_length = Some(max_length);
+
+        self
+    }
+
+    /// Sets the maximum value of an [`Integer`] or [`Number`] option.
+    ///
+    /// [`Integer`]: enum.CommandOptionType.html#variant.Integer
+    /// [`Number`]: enum.CommandOptionType.html#variant.Number
+    pub fn max_value<V: Into<Value>>(mut self, max_value: V) -> Self {
+        self.max_value = Some(max_value.into());
+
+        self
+    }
+
+    /// Sets the minimum length of a [`String`] option.
+    ///
+    /// [`String`]: enum.CommandOptionType.html#variant.String
+    pub fn min_length(mut self, min_length: u16) -> Self {
+        self.min_length = Some(min_length);
+
+        self
+    }
+
+    /// Sets the minimum value of an [`Integer`] or [`Number`] option.
+    ///
+    /// [`Integer`]: enum.CommandOptionType.html#variant.Integer
+    /// [`Number`]: enum.CommandOptionType.html#variant.Number
+    pub fn min_value<V: Into<Value>>(mut self, min_value: V) -> Self {
+        self.min_value = Some(min_value.into());
+
+        self
+    }
+
+    /// Sets the nested options of a [`SubCommand`] or [`SubCommandGroup`],
+    /// replacing any already added.
+    ///
+    /// [`SubCommand`]: enum.CommandOptionType.html#variant.SubCommand
+    /// [`SubCommandGroup`]: enum.CommandOptionType.html#variant.SubCommandGroup
+    pub fn options(mut self, options: Vec<CreateApplicationCommandOption>) -> Self {
+        self.options = options;
+
+        self
+    }
+
+    /// Sets whether the option must be given.
+    ///
+    /// Required options must come before optional ones.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+
+        self
+    }
+
+    fn validate(&self, top_level: bool) -> StdResult<(), CommandError> {
+        validate_name(&self.name, true)?;
+        validate_description(&self.description)?;
+
+        for name in self.name_localizations.iter().flat_map(|map| map.values()) {
+            validate_name(name, true)?;
+        }
+
+        for description in self.description_localizations.iter().flat_map(|map| map.values()) {
+            validate_description(description)?;
+        }
+
+        if self.choices.len() > MAX_COMMAND_CHOICES {
+            return Err(CommandError::TooManyChoices(self.choices.len()));
+        }
+
+        if !self.choices.is_empty() {
+            if self.autocomplete == Some(true) {
+                return Err(CommandError::AutocompleteWithChoices);
+            }
+
+            match self.kind {
+                CommandOptionType::Integer
+                | CommandOptionType::Number
+                | CommandOptionType::String => {},
+                _ => return Err(CommandError::UnexpectedChoices),
+            }
+        }
+
+        match self.kind {
+            CommandOptionType::SubCommandGroup => {
+                if !top_level {
+                    return Err(CommandError::NestedTooDeep);
+                }
+
+                validate_options(&self.options, false)?;
+
+                if self.options.iter().any(|option| option.kind != CommandOptionType::SubCommand) {
+                    return Err(CommandError::MixedSubCommands);
+                }
+
+                Ok(())
+            },
+            CommandOptionType::SubCommand => {
+                if self.options.iter().any(|option| option.is_subcommand()) {
+                    return Err(CommandError::NestedTooDeep);
+                }
+
+                validate_options(&self.options, false)
+            },
+            _ if !self.options.is_empty() => Err(CommandError::UnexpectedOptions),
+            _ => Ok(()),
+        }
+    }
+
+    fn is_required(&self) -> bool { self.required.unwrap_or(false) }
+
+    fn is_subcommand(&self) -> bool {
+        match self.kind {
+            CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup => true,
+            _ => false,
+        }
+    }
+}
+
+/// Validates a set of commands and converts them into a value suitable for
+/// bulk overwriting the commands of an application, either globally or for a
+/// single guild.
+///
+/// Commands that are registered but not given are deleted.
+///
+/// # Errors
+///
+/// Returns a [`CommandError`] if one of the commands is invalid, if two
+/// commands of the same type share a name, or if more commands of a type are
+/// given than can be registered.
+///
+/// [`CommandError`]: enum.CommandError.html
+pub fn commands_to_value(commands: &[CreateApplicationCommand])
+    -> StdResult<Value, CommandError> {
+    for kind in &[CommandType::ChatInput, CommandType::User, CommandType::Message] {
+        let limit = if *kind == CommandType::ChatInput {
+            MAX_CHAT_INPUT_COMMANDS
+        } else {
+            MAX_CONTEXT_MENU_COMMANDS
+        };
+        let count = commands.iter().filter(|command| command.kind == *kind).count();
+
+        if count > limit {
+            return Err(CommandError::TooManyCommands(*kind, count));
+        }
+    }
+
+    for (index, command) in commands.iter().enumerate() {
+        command.validate()?;
+
+        let duplicate = commands[..index]
+            .iter()
+            .any(|other| other.kind == command.kind && other.name == command.name);
+
+        if duplicate {
+            return Err(CommandError::DuplicateName(command.name.clone()));
+        }
+    }
+
+    serde_json::to_value(commands).map_err(|why| CommandError::Serialization(why.to_string()))
+}
+
+/// An error returned when validating a [`CreateApplicationCommand`] before it
+/// is sent.
+///
+/// [`CreateApplicationCommand`]: struct.CreateApplicationCommand.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum CommandError {
+    /// An option was given both fixed choices and autocomplete.
+    AutocompleteWithChoices,
+    /// A context menu command was given a description or options.
+    ContextMenuOptions,
+    /// Two commands of the same type, or two options of the same command,
+    /// share a name. The name is given.
+    DuplicateName(String),
+    /// A description was empty, or longer than
+    /// [`MAX_COMMAND_DESCRIPTION_LENGTH`] characters. The description is
+    /// given.
+    ///
+    /// [`MAX_COMMAND_DESCRIPTION_LENGTH`]: constant.MAX_COMMAND_DESCRIPTION_LENGTH.html
+    InvalidDescription(String),
+    /// A name was invalid. The name is given.
+    ///
+    /// Names must be between 1 and [`MAX_COMMAND_NAME_LENGTH`] characters
+    /// long. Names of [`ChatInput`] commands and of options may only contain
+    /// lowercase letters, numbers, Devanagari and Thai characters, `-`, `_`,
+    /// and `'`, while names of context menu commands may also contain
+    /// uppercase letters and spaces.
+    ///
+    /// [`ChatInput`]: enum.CommandType.html#variant.ChatInput
+    /// [`MAX_COMMAND_NAME_LENGTH`]: constant.MAX_COMMAND_NAME_LENGTH.html
+    InvalidName(String),
+    /// A subcommand group contained options other than subcommands, or
+    /// subcommands were given alongside other options.
+    MixedSubCommands,
+    /// Subcommands were nested deeper than allowed, being a subcommand group
+    /// below the top level or a subcommand below a subcommand group.
+    NestedTooDeep,
+    /// A required option was given after an optional one.
+    RequiredAfterOptional,
+    /// The command could not be serialized. The error message is given.
+    Serialization(String),
+    /// An option was given more than [`MAX_COMMAND_CHOICES`] choices. The
+    /// number of choices is given.
+    ///
+    /// [`MAX_COMMAND_CHOICES`]: constant.MAX_COMMAND_CHOICES.html
+    TooManyChoices(usize),
+    /// More commands of a type were given than can be registered. The type
+    /// and number of commands are given.
+    TooManyCommands(CommandType, usize),
+    /// More than [`MAX_COMMAND_OPTIONS`] options were given. The number of
+    /// options is given.
+    ///
+    /// [`MAX_COMMAND_OPTIONS`]: constant.MAX_COMMAND_OPTIONS.html
+    TooManyOptions(usize),
+    /// Choices were given to an option whose type does not support them.
+    UnexpectedChoices,
+    /// Nested options were given to an option that is not a subcommand or
+    /// subcommand group.
+    UnexpectedOptions,
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(self.description()) }
+}
+
+impl StdError for CommandError {
+    fn description(&self) -> &str {
+        match *self {
+            CommandError::AutocompleteWithChoices => {
+                "Command option has both choices and autocomplete"
+            },
+            CommandError::ContextMenuOptions => {
+                "Context menu command has a description or options"
+            },
+            CommandError::DuplicateName(_) => "Command or option name is used more than once",
+            CommandError::InvalidDescription(_) => "Invalid command or option description",
+            CommandError::InvalidName(_) => "Invalid command or option name",
+            CommandError::MixedSubCommands => "Subcommands are mixed with other options",
+            CommandError::NestedTooDeep => "Subcommands are nested too deeply",
+            CommandError::RequiredAfterOptional => "Required option follows an optional one",
+            CommandError::Serialization(_) => "Command could not be serialized",
+            CommandError::TooManyChoices(_) => "Command option has more than 25 choices",
+            CommandError::TooManyCommands(_, _) => "Too many commands of one type",
+            CommandError::TooManyOptions(_) => "Command has more than 25 options",
+            CommandError::UnexpectedChoices => "Command option type does not support choices",
+            CommandError::UnexpectedOptions => "Command option type does not support options",
+        }
+    }
+}
+
+/// The body of a response to an interaction, wrapping the payload of the
+/// response type.
+#[cfg(feature = "model")]
+#[derive(Serialize)]
+struct InteractionResponse<'a, T: 'a> {
+    data: &'a T,
+    #[serde(rename = "type")]
+    kind: InteractionResponseType,
+}
+
+fn find_focused(options: &[CommandDataOption]) -> Option<&CommandDataOption> {
+    options.iter().filter_map(|option| {
+        if option.focused {
+            Some(option)
+        } else {
+            find_focused(&option.options)
+        }
+    }).next()
+}
+
+fn validate_description(description: &str) -> StdResult<(), CommandError> {
+    let length = description.chars().count();
+
+    if length == 0 || length > MAX_COMMAND_DESCRIPTION_LENGTH {
+        return Err(CommandError::InvalidDescription(description.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Checks a name against the pattern Discord requires, being
+/// `^[-_'\p{L}\p{N}\p{sc=Deva}\p{sc=Thai}]{1,32}$` and lowercase for chat
+/// input commands and options, and additionally allowing spaces and uppercase
+/// letters for context menu commands.
+fn validate_name(name: &str, chat_input: bool) -> StdResult<(), CommandError> {
+    let length = name.chars().count();
+    let valid = length > 0 && length <= MAX_COMMAND_NAME_LENGTH && name.chars().all(|c| {
+        // The Devanagari and Thai blocks are allowed whole, as their
+        // combining marks are neither letters nor numbers.
+        let allowed = match c {
+            '-' | '_' | '\'' | '\u{0900}'..='\u{097F}' | '\u{0E00}'..='\u{0E7F}' => true,
+            _ => c.is_alphanumeric(),
+        };
+
+        if chat_input {
+            allowed && !c.is_uppercase()
+        } else {
+            allowed || c == ' '
+        }
+    });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(CommandError::InvalidName(name.to_string()))
+    }
+}
+
+fn validate_options(options: &[CreateApplicationCommandOption], top_level: bool)
+    -> StdResult<(), CommandError> {
+    if options.len() > MAX_COMMAND_OPTIONS {
+        return Err(CommandError::TooManyOptions(options.len()));
+    }
+
+    let subcommands = options.iter().filter(|option| option.is_subcommand()).count();
+
+    if subcommands != 0 && subcommands != options.len() {
+        return Err(CommandError::MixedSubCommands);
+    }
+
+    let mut seen_optional = false;
+
+    for (index, option) in options.iter().enumerate() {
+        option.validate(top_level)?;
+
+        if option.is_required() && seen_optional {
+            return Err(CommandError::RequiredAfterOptional);
+        }
+
+        seen_optional |= !option.is_required();
+
+        if options[..index].iter().any(|other| other.name == option.name) {
+            return Err(CommandError::DuplicateName(option.name.clone()));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        commands_to_value,
+        validate_name,
+        AutocompleteChoice,
+        CommandError,
+        CommandOptionType,
+        CommandType,
+        CreateApplicationCommand,
+        CreateApplicationCommandOption,
+        MAX_COMMAND_CHOICES,
+        MAX_COMMAND_DESCRIPTION_LENGTH,
+        MAX_COMMAND_OPTIONS,
+    };
+
+    fn option(name: &str) -> CreateApplicationCommandOption {
+        CreateApplicationCommandOption::new(CommandOptionType::String, name, "An option")
+    }
+
+    #[test]
+    fn chat_input_names() {
+        assert!(validate_name("ban-user_2", true).is_ok());
+        assert!(validate_name("don't", true).is_ok());
+        // Devanagari and Thai names, both of which use combining marks.
+        assert!(validate_name("नमस्ते", true).is_ok());
+        assert!(validate_name("สวัสดี", true).is_ok());
+
+        assert!(validate_name(&"a".repeat(32), true).is_ok());
+
+        assert!(validate_name("", true).is_err());
+        assert!(validate_name("Ban", true).is_err());
+        assert!(validate_name("ban user", true).is_err());
+        assert!(validate_name(&"a".repeat(33), true).is_err());
+    }
+
+    #[test]
+    fn context_menu_names() {
+        assert!(validate_name("Ban User", false).is_ok());
+        assert!(validate_name("User's Info", false).is_ok());
+
+        assert!(validate_name("ban!", false).is_err());
+    }
+
+    #[test]
+    fn names_through_to_value() {
+        assert!(CreateApplicationCommand::new("don't", "Apostrophe").to_value().is_ok());
+        assert!(CreateApplicationCommand::new("नमस्ते", "Devanagari").to_value().is_ok());
+        assert!(CreateApplicationCommand::context_menu(CommandType::User, "नमस्ते Info")
+            .to_value()
+            .is_ok());
+
+        assert_eq!(
+            CreateApplicationCommand::new("ban!", "Invalid").to_value().unwrap_err(),
+            CommandError::InvalidName("ban!".to_string()),
+        );
+    }
+
+    #[test]
+    fn description_limits() {
+        let description = |length| CreateApplicationCommand::new("ban", "é".repeat(length))
+            .to_value();
+
+        assert!(description(MAX_COMMAND_DESCRIPTION_LENGTH).is_ok());
+        assert_eq!(description(0).unwrap_err(), CommandError::InvalidDescription(String::new()));
+        assert_eq!(
+            description(MAX_COMMAND_DESCRIPTION_LENGTH + 1).unwrap_err(),
+            CommandError::InvalidDescription("é".repeat(MAX_COMMAND_DESCRIPTION_LENGTH + 1)),
+        );
+    }
+
+    #[test]
+    fn option_limits() {
+        let options = |count| {
+            let options = (0..count).map(|i| option(&format!("option-{}", i))).collect();
+
+            CreateApplicationCommand::new("ban", "Bans a member").options(options).to_value()
+        };
+
+        assert!(options(MAX_COMMAND_OPTIONS).is_ok());
+        assert_eq!(
+            options(MAX_COMMAND_OPTIONS + 1).unwrap_err(),
+            CommandError::TooManyOptions(MAX_COMMAND_OPTIONS + 1),
+        );
+    }
+
+    #[test]
+    fn choice_limits() {
+        let choices = |count| {
+            let choices = (0..count)
+                .map(|i| AutocompleteChoice::new(format!("Choice {}", i), i))
+                .collect();
+
+            CreateApplicationCommand::new("ban", "Bans a member")
+                .add_option(option("reason").choices(choices))
+                .to_value()
+        };
+
+        assert!(choices(MAX_COMMAND_CHOICES).is_ok());
+        assert_eq!(
+            choices(MAX_COMMAND_CHOICES + 1).unwrap_err(),
+            CommandError::TooManyChoices(MAX_COMMAND_CHOICES + 1),
+        );
+    }
+
+    #[test]
+    fn duplicate_names() {
+        let command = CreateApplicationCommand::new("ban", "Bans a member")
+            .add_option(option("reason"))
+            .add_option(option("reason"));
+        assert_eq!(
+            command.to_value().unwrap_err(),
+            CommandError::DuplicateName("reason".to_string()),
+        );
+
+        let subcommand = CreateApplicationCommandOption::new(
+            CommandOptionType::SubCommand,
+            "user",
+            "Bans a user",
+        ).add_option(option("reason")).add_option(option("reason"));
+        let command = CreateApplicationCommand::new("ban", "Bans a member").add_option(subcommand);
+        assert_eq!(
+            command.to_value().unwrap_err(),
+            CommandError::DuplicateName("reason".to_string()),
+        );
+
+        let commands = [
+            CreateApplicationCommand::new("ban", "Bans a member"),
+            CreateApplicationCommand::context_menu(CommandType::User, "ban"),
+        ];
+        assert!(commands_to_value(&commands).is_ok());
+
+        let commands = [
+            CreateApplicationCommand::new("ban", "Bans a member"),
+            CreateApplicationCommand::new("ban", "Bans a member again"),
+        ];
+        assert_eq!(
+            commands_to_value(&commands).unwrap_err(),
+            CommandError::DuplicateName("ban".to_string()),
+        );
+    }
+}
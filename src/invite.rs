@@ -1,5 +1,6 @@
-use chrono::{DateTime, FixedOffset};
-use super::*;
+use chrono::{DateTime, Duration, FixedOffset, TimeZone};
+use super::guild::icon_url;
+use super::{ChannelId, ChannelType, GuildId, User, VerificationLevel, WelcomeScreen};
 
 #[cfg(feature = "model")]
 use builder::CreateInvite;
@@ -52,11 +53,22 @@ pub struct InviteChannel {
 #[derive(Clone, Debug, Deserialize)]
 pub struct InviteGuild {
     pub id: GuildId,
+    #[serde(default)]
+    pub features: Vec<String>,
     pub icon: Option<String>,
     pub name: String,
     pub splash_hash: Option<String>,
     pub text_channel_count: Option<u64>,
+    pub verification_level: Option<VerificationLevel>,
     pub voice_channel_count: Option<u64>,
+    pub welcome_screen: Option<WelcomeScreen>,
+}
+
+impl InviteGuild {
+    /// Returns the formatted URL of the guild's icon, if the guild has an icon.
+    pub fn icon_url(&self) -> Option<String> {
+        self.icon.as_ref().map(|icon| icon_url(self.id, icon))
+    }
 }
 
 /// Detailed information about an invite.
@@ -99,3 +111,39 @@ pub struct RichInvite {
     /// The amount of times that an invite has been used.
     pub uses: u64,
 }
+
+impl RichInvite {
+    /// The time at which the invite expires, calculated from when it was
+    /// created and its [`max_age`].
+    ///
+    /// Returns `None` if the invite never expires.
+    ///
+    /// [`max_age`]: #structfield.max_age
+    pub fn expires_at(&self) -> Option<DateTime<FixedOffset>> {
+        if self.max_age == 0 {
+            None
+        } else {
+            Some(self.created_at + Duration::seconds(self.max_age as i64))
+        }
+    }
+
+    /// Whether the invite has expired at the given time, either from its age
+    /// or from having been used the maximum number of times.
+    pub fn is_expired<Tz: TimeZone>(&self, now: DateTime<Tz>) -> bool {
+        self.remaining_uses() == Some(0)
+            || self.expires_at().map_or(false, |expires_at| expires_at <= now)
+    }
+
+    /// The number of times that the invite can still be used.
+    ///
+    /// Returns `None` if the invite has no [`max_uses`].
+    ///
+    /// [`max_uses`]: #structfield.max_uses
+    pub fn remaining_uses(&self) -> Option<u64> {
+        if self.max_uses == 0 {
+            None
+        } else {
+            Some(self.max_uses.saturating_sub(self.uses))
+        }
+    }
+}
@@ -6,135 +6,92 @@
 
 #[macro_use] extern crate bitflags;
 #[macro_use] extern crate log;
+#[cfg_attr(feature = "etf", macro_use)] extern crate serde;
 #[macro_use] extern crate serde_derive;
 
 extern crate chrono;
-extern crate serde;
+#[cfg(feature = "etf")]
+extern crate eetf;
+#[cfg(feature = "compression")]
+extern crate flate2;
+extern crate fxhash;
+#[cfg(feature = "persist")]
+extern crate rmp_serde;
 extern crate serde_json;
 extern crate serenity_common;
 
 #[macro_use] mod internal;
 #[macro_use] mod utils;
 
+#[cfg(feature = "etf")]
+pub mod etf;
 pub mod event;
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
+pub mod limits;
 pub mod permissions;
+pub mod persist;
+pub mod prelude;
+#[cfg(feature = "test-helpers")]
+pub mod test_utils;
 
+mod application;
 mod channel;
+mod component;
 mod error;
 mod gateway;
 mod guild;
+mod ids;
+mod interaction;
 mod invite;
+mod locale;
 mod misc;
 mod user;
 mod voice;
 mod webhook;
 
+pub use self::application::*;
 pub use self::channel::*;
+pub use self::component::*;
 pub use self::error::Error as ModelError;
 pub use self::gateway::*;
 pub use self::guild::*;
+pub use self::ids::*;
+pub use self::interaction::*;
 pub use self::invite::*;
+pub use self::locale::*;
 pub use self::misc::*;
 pub use self::permissions::Permissions;
 pub use self::user::*;
+pub use self::utils::{
+    DISCORD_EPOCH,
+    MAX_MESSAGE_LENGTH,
+    Snowflake,
+    SnowflakeGenerator,
+    SnowflakeParts,
+    parse_emoji,
+    parse_emojis,
+    parse_quotes,
+    parse_username,
+    split_message,
+};
 pub use self::voice::*;
 pub use self::webhook::*;
 
-use chrono::NaiveDateTime;
-use serenity_common::prelude::*;
-use self::utils::*;
-use serde::de::{Deserialize, Deserializer, Visitor};
-use std::collections::HashMap;
-use std::fmt::{Display, Formatter, Result as FmtResult};
-use std::result::Result as StdResult;
-
-#[cfg(feature = "utils")]
-use utils::Colour;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
 
 fn default_true() -> bool { true }
 
-macro_rules! id_u64 {
-    ($(#[$attr:meta] $name:ident;)*) => {
-        $(
-            #[$attr]
-            #[cfg_attr(feature = "cargo-clippy", allow(derive_hash_xor_eq))]
-            #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord, Serialize)]
-            pub struct $name(pub u64);
-
-            impl $name {
-                /// Retrieves the time that the Id was created at.
-                pub fn created_at(&self) -> NaiveDateTime {
-                    let offset = (self.0 >> 22) / 1000;
-
-                    NaiveDateTime::from_timestamp(1_420_070_400 + offset as i64, 0)
-                }
-            }
-
-            // This is a hack so that functions can accept `IntoIterator<Item=IdType>`, and or `IntoIterator<Item=&IdType>`
-            impl AsRef<$name> for $name {
-                fn as_ref(&self) -> &Self {
-                    self
-                }
-            }
-
-            impl From<u64> for $name {
-                fn from(id_as_u64: u64) -> $name {
-                    $name(id_as_u64)
-                }
-            }
-
-            impl PartialEq for $name {
-                fn eq(&self, other: &Self) -> bool {
-                    self.0 == other.0
-                }
-            }
-
-            impl PartialEq<u64> for $name {
-                fn eq(&self, u: &u64) -> bool {
-                    self.0 == *u
-                }
-            }
-
-            impl Display for $name {
-                fn fmt(&self, f: &mut Formatter) -> FmtResult {
-                    Display::fmt(&self.0, f)
-                }
-            }
-
-            impl<'de> Deserialize<'de> for $name {
-                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
-                    deserializer.deserialize_u64(U64Visitor).map($name)
-                }
-            }
-        )*
-    }
-}
-
-id_u64! {
-    /// An identifier for a Channel
-    ChannelId;
-    /// An identifier for an Emoji
-    EmojiId;
-    /// An identifier for a Guild
-    GuildId;
-    /// An identifier for an Integration
-    IntegrationId;
-    /// An identifier for a Message
-    MessageId;
-    /// An identifier for a Role
-    RoleId;
-    /// An identifier for a User
-    UserId;
-    /// An identifier for a [`Webhook`](struct.Webhook.html).
-    WebhookId;
-    /// An identifier for an audit log entry.
-    AuditLogEntryId;
-}
-
 /// A container for guilds.
 ///
 /// This is used to differentiate whether a guild itself can be used or whether
 /// a guild needs to be retrieved from the cache.
+///
+/// **Note**: This requires ownership of the guild. Prefer [`GuildRef`], which
+/// borrows it instead.
+///
+/// [`GuildRef`]: enum.GuildRef.html
+#[deprecated(since = "0.1.0", note = "use `GuildRef` instead")]
 #[cfg_attr(feature = "cargo-clippy", allow(large_enum_variant))]
 #[derive(Clone, Debug)]
 pub enum GuildContainer {
@@ -144,9 +101,28 @@ pub enum GuildContainer {
     Id(GuildId),
 }
 
+#[allow(deprecated)]
+impl From<PartialGuild> for GuildContainer {
+    fn from(guild: PartialGuild) -> GuildContainer { GuildContainer::Guild(guild) }
+}
+
+#[allow(deprecated)]
+impl From<GuildId> for GuildContainer {
+    fn from(guild_id: GuildId) -> GuildContainer { GuildContainer::Id(guild_id) }
+}
+
+#[allow(deprecated)]
+impl From<u64> for GuildContainer {
+    fn from(id: u64) -> GuildContainer { GuildContainer::Id(GuildId(id)) }
+}
+
 /// Information about a user's application. An application does not necessarily
 /// have an associated bot user.
-#[derive(Clone, Debug, Deserialize)]
+///
+/// The application's [`secret`] is redacted from the `Debug` output.
+///
+/// [`secret`]: #structfield.secret
+#[derive(Clone, Deserialize)]
 pub struct ApplicationInfo {
     /// The bot user associated with the application. See [`BotApplication`] for
     /// more information.
@@ -187,7 +163,11 @@ pub struct ApplicationInfo {
 }
 
 /// Information about an application with an application's bot user.
-#[derive(Clone, Debug, Deserialize)]
+///
+/// The bot user's [`token`] is redacted from the `Debug` output.
+///
+/// [`token`]: #structfield.token
+#[derive(Clone, Deserialize)]
 pub struct BotApplication {
     /// The unique Id of the bot user.
     pub id: UserId,
@@ -212,6 +192,37 @@ pub struct BotApplication {
     pub token: String,
 }
 
+impl Debug for ApplicationInfo {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("ApplicationInfo")
+            .field("bot", &self.bot)
+            .field("bot_public", &self.bot_public)
+            .field("bot_require_code_grant", &self.bot_require_code_grant)
+            .field("description", &self.description)
+            .field("flags", &self.flags)
+            .field("icon", &self.icon)
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("redirect_uris", &self.redirect_uris)
+            .field("rpc_origins", &self.rpc_origins)
+            .field("secret", &"[redacted]")
+            .finish()
+    }
+}
+
+impl Debug for BotApplication {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("BotApplication")
+            .field("id", &self.id)
+            .field("avatar", &self.avatar)
+            .field("bot", &self.bot)
+            .field("discriminator", &self.discriminator)
+            .field("name", &self.name)
+            .field("token", &"[redacted]")
+            .finish()
+    }
+}
+
 /// Information about the current application and its owner.
 #[derive(Clone, Debug, Deserialize)]
 pub struct CurrentApplicationInfo {
@@ -0,0 +1,127 @@
+//! Limits that Discord places on the values of channels, guilds, and roles.
+//!
+//! The edit builders, such as [`EditChannel`], check their values against
+//! these limits before they are sent, returning a [`LimitError`] for values
+//! that Discord would reject.
+//!
+//! [`EditChannel`]: ../struct.EditChannel.html
+//! [`LimitError`]: enum.LimitError.html
+
+use std::error::Error as StdError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The timeouts, in seconds, that a guild's AFK timeout can be set to.
+pub const AFK_TIMEOUTS: &[u64] = &[60, 300, 900, 1800, 3600];
+
+/// The maximum bitrate of a voice channel, in bits per second.
+///
+/// Bitrates above 96kbps are only available to boosted guilds.
+pub const MAX_BITRATE: u64 = 384_000;
+
+/// The maximum length of the name of a channel, in characters.
+pub const MAX_CHANNEL_NAME_LENGTH: usize = 100;
+
+/// The maximum length of the topic of a channel, in characters.
+pub const MAX_CHANNEL_TOPIC_LENGTH: usize = 1024;
+
+/// The maximum length of the name of a guild, in characters.
+pub const MAX_GUILD_NAME_LENGTH: usize = 100;
+
+/// The maximum length of the name of a role, in characters.
+pub const MAX_ROLE_NAME_LENGTH: usize = 100;
+
+/// The maximum slowmode of a channel, in seconds.
+pub const MAX_SLOWMODE: u64 = 21_600;
+
+/// The maximum number of members allowed in a voice channel, where 0 means
+/// that the number is unlimited.
+pub const MAX_USER_LIMIT: u64 = 99;
+
+/// The minimum bitrate of a voice channel, in bits per second.
+pub const MIN_BITRATE: u64 = 8000;
+
+/// The minimum length of the name of a guild, in characters.
+pub const MIN_GUILD_NAME_LENGTH: usize = 2;
+
+/// An error returned when a value given to an edit builder is outside of the
+/// limits that Discord allows.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum LimitError {
+    /// The AFK timeout is not one of the [`AFK_TIMEOUTS`]. The timeout is
+    /// given.
+    ///
+    /// [`AFK_TIMEOUTS`]: constant.AFK_TIMEOUTS.html
+    AfkTimeout(u64),
+    /// The bitrate is below [`MIN_BITRATE`] or above [`MAX_BITRATE`]. The
+    /// bitrate is given.
+    ///
+    /// [`MAX_BITRATE`]: constant.MAX_BITRATE.html
+    /// [`MIN_BITRATE`]: constant.MIN_BITRATE.html
+    Bitrate(u64),
+    /// The name of a channel is empty or longer than
+    /// [`MAX_CHANNEL_NAME_LENGTH`]. The length of the name is given.
+    ///
+    /// [`MAX_CHANNEL_NAME_LENGTH`]: constant.MAX_CHANNEL_NAME_LENGTH.html
+    ChannelNameLength(usize),
+    /// The topic of a channel is longer than [`MAX_CHANNEL_TOPIC_LENGTH`]. The
+    /// length of the topic is given.
+    ///
+    /// [`MAX_CHANNEL_TOPIC_LENGTH`]: constant.MAX_CHANNEL_TOPIC_LENGTH.html
+    ChannelTopicLength(usize),
+    /// The name of a guild is shorter than [`MIN_GUILD_NAME_LENGTH`] or longer
+    /// than [`MAX_GUILD_NAME_LENGTH`]. The length of the name is given.
+    ///
+    /// [`MAX_GUILD_NAME_LENGTH`]: constant.MAX_GUILD_NAME_LENGTH.html
+    /// [`MIN_GUILD_NAME_LENGTH`]: constant.MIN_GUILD_NAME_LENGTH.html
+    GuildNameLength(usize),
+    /// A role was given both an image icon and a unicode emoji icon.
+    RoleIconConflict,
+    /// The name of a role is longer than [`MAX_ROLE_NAME_LENGTH`]. The length
+    /// of the name is given.
+    ///
+    /// [`MAX_ROLE_NAME_LENGTH`]: constant.MAX_ROLE_NAME_LENGTH.html
+    RoleNameLength(usize),
+    /// The value could not be serialized. The error message is given.
+    Serialization(String),
+    /// The slowmode is above [`MAX_SLOWMODE`]. The slowmode is given.
+    ///
+    /// [`MAX_SLOWMODE`]: constant.MAX_SLOWMODE.html
+    Slowmode(u64),
+    /// The user limit is above [`MAX_USER_LIMIT`]. The user limit is given.
+    ///
+    /// [`MAX_USER_LIMIT`]: constant.MAX_USER_LIMIT.html
+    UserLimit(u64),
+}
+
+impl Display for LimitError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(self.description()) }
+}
+
+impl StdError for LimitError {
+    fn description(&self) -> &str {
+        match *self {
+            LimitError::AfkTimeout(_) => "AFK timeout is not an allowed value",
+            LimitError::Bitrate(_) => "Bitrate is not between 8kbps and 384kbps",
+            LimitError::ChannelNameLength(_) => "Channel name is not between 1 and 100 characters",
+            LimitError::ChannelTopicLength(_) => "Channel topic is over 1024 characters",
+            LimitError::GuildNameLength(_) => "Guild name is not between 2 and 100 characters",
+            LimitError::RoleIconConflict => "Role has both an icon and a unicode emoji",
+            LimitError::RoleNameLength(_) => "Role name is over 100 characters",
+            LimitError::Serialization(_) => "Value could not be serialized",
+            LimitError::Slowmode(_) => "Slowmode is over 6 hours",
+            LimitError::UserLimit(_) => "User limit is over 99",
+        }
+    }
+}
+
+/// Checks that the length of a value, in characters, is within a range.
+pub(crate) fn check_length<F>(value: &str, min: usize, max: usize, error: F)
+    -> Result<(), LimitError> where F: FnOnce(usize) -> LimitError {
+    let length = value.chars().count();
+
+    if length < min || length > max {
+        return Err(error(length));
+    }
+
+    Ok(())
+}
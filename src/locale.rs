@@ -0,0 +1,153 @@
+//! The locales supported by Discord, and maps of localized strings.
+
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde::ser::{Serialize, Serializer};
+use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::result::Result as StdResult;
+use std::str::FromStr;
+
+/// A map of localized strings, such as the names of an application command
+/// in each language it has been translated to.
+pub type LocalizationMap = HashMap<Locale, String>;
+
+macro_rules! locales {
+    ($($(#[$attr:meta])* $variant:ident => $code:expr,)*) => {
+        /// A locale supported by Discord, used for the language of the client
+        /// and to localize application commands.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub enum Locale {
+            $($(#[$attr])* $variant,)*
+        }
+
+        impl Locale {
+            /// Every locale supported by Discord.
+            pub const ALL: &'static [Locale] = &[$(Locale::$variant,)*];
+
+            /// Retrieves the code of the locale, as used by Discord.
+            pub fn code(&self) -> &'static str {
+                match *self {
+                    $(Locale::$variant => $code,)*
+                }
+            }
+        }
+
+        impl FromStr for Locale {
+            type Err = LocaleParseError;
+
+            fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+                match s {
+                    $($code => Ok(Locale::$variant),)*
+                    _ => Err(LocaleParseError::UnknownLocale),
+                }
+            }
+        }
+    }
+}
+
+locales! {
+    /// Indonesian.
+    Indonesian => "id",
+    /// Danish.
+    Danish => "da",
+    /// German.
+    German => "de",
+    /// English, as used in the United Kingdom.
+    EnglishGb => "en-GB",
+    /// English, as used in the United States.
+    EnglishUs => "en-US",
+    /// Spanish, as used in Spain.
+    SpanishEs => "es-ES",
+    /// Spanish, as used in Latin America.
+    SpanishLatam => "es-419",
+    /// French.
+    French => "fr",
+    /// Croatian.
+    Croatian => "hr",
+    /// Italian.
+    Italian => "it",
+    /// Lithuanian.
+    Lithuanian => "lt",
+    /// Hungarian.
+    Hungarian => "hu",
+    /// Dutch.
+    Dutch => "nl",
+    /// Norwegian.
+    Norwegian => "no",
+    /// Polish.
+    Polish => "pl",
+    /// Portuguese, as used in Brazil.
+    PortugueseBr => "pt-BR",
+    /// Romanian.
+    Romanian => "ro",
+    /// Finnish.
+    Finnish => "fi",
+    /// Swedish.
+    Swedish => "sv-SE",
+    /// Vietnamese.
+    Vietnamese => "vi",
+    /// Turkish.
+    Turkish => "tr",
+    /// Czech.
+    Czech => "cs",
+    /// Greek.
+    Greek => "el",
+    /// Bulgarian.
+    Bulgarian => "bg",
+    /// Russian.
+    Russian => "ru",
+    /// Ukrainian.
+    Ukrainian => "uk",
+    /// Hindi.
+    Hindi => "hi",
+    /// Thai.
+    Thai => "th",
+    /// Chinese, as used in China.
+    ChineseCn => "zh-CN",
+    /// Japanese.
+    Japanese => "ja",
+    /// Chinese, as used in Taiwan.
+    ChineseTw => "zh-TW",
+    /// Korean.
+    Korean => "ko",
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(self.code()) }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+
+        code.parse().map_err(|_| DeError::custom(format!("unknown locale {}", code)))
+    }
+}
+
+impl Serialize for Locale {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+/// An error returned when parsing a [`Locale`] from a string fails.
+///
+/// [`Locale`]: enum.Locale.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LocaleParseError {
+    /// The string was not the code of a locale supported by Discord.
+    UnknownLocale,
+}
+
+impl Display for LocaleParseError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(self.description()) }
+}
+
+impl StdError for LocaleParseError {
+    fn description(&self) -> &str {
+        match *self {
+            LocaleParseError::UnknownLocale => "unknown locale",
+        }
+    }
+}
@@ -1,13 +1,23 @@
-use super::*;
-
-#[cfg(all(feature = "model", feature = "utils"))]
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde::ser::{Serialize, Serializer};
 use std::error::Error as StdError;
-#[cfg(all(feature = "model", feature = "utils"))]
+use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::result::Result as StdResult;
-#[cfg(all(feature = "model", feature = "utils"))]
 use std::str::FromStr;
+use super::{Channel, Emoji, EmojiId, Member, Message, Role, User};
+
+#[cfg(feature = "utils")]
+use utils::Colour;
+
+#[cfg(all(feature = "model", feature = "utils"))]
+use internal::prelude::*;
 #[cfg(all(feature = "model", feature = "utils"))]
 use std::fmt;
+#[cfg(all(feature = "model", feature = "utils"))]
+use super::UserId;
+#[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
+use super::{ChannelId, RoleId};
 #[cfg(all(feature = "model", any(feature = "cache", feature = "utils")))]
 use utils;
 
@@ -18,10 +28,6 @@ pub trait Mentionable {
     fn mention(&self) -> String;
 }
 
-impl Mentionable for ChannelId {
-    fn mention(&self) -> String { format!("<#{}>", self.0) }
-}
-
 impl Mentionable for Channel {
     fn mention(&self) -> String {
         match *self {
@@ -41,18 +47,10 @@ impl Mentionable for Member {
     fn mention(&self) -> String { format!("<@{}>", self.user.id.0) }
 }
 
-impl Mentionable for RoleId {
-    fn mention(&self) -> String { format!("<@&{}>", self.0) }
-}
-
 impl Mentionable for Role {
     fn mention(&self) -> String { format!("<@&{}>", self.id.0) }
 }
 
-impl Mentionable for UserId {
-    fn mention(&self) -> String { format!("<@{}>", self.0) }
-}
-
 impl Mentionable for User {
     fn mention(&self) -> String { format!("<@{}>", self.id.0) }
 }
@@ -88,47 +86,13 @@ impl FromStr for User {
     fn from_str(s: &str) -> StdResult<Self, Self::Err> {
         match utils::parse_username(s) {
             Some(x) => UserId(x as u64)
-                .get()
+                .to_user()
                 .map_err(|e| UserParseError::Rest(Box::new(e))),
             _ => Err(UserParseError::InvalidUsername),
         }
     }
 }
 
-#[cfg(all(feature = "model", feature = "utils"))]
-#[derive(Debug)]
-pub enum UserIdParseError {
-    InvalidFormat,
-}
-
-#[cfg(all(feature = "model", feature = "utils"))]
-impl fmt::Display for UserIdParseError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.description()) }
-}
-
-#[cfg(all(feature = "model", feature = "utils"))]
-impl StdError for UserIdParseError {
-    fn description(&self) -> &str {
-        use self::UserIdParseError::*;
-
-        match *self {
-            InvalidFormat => "invalid user id format",
-        }
-    }
-}
-
-#[cfg(all(feature = "model", feature = "utils"))]
-impl FromStr for UserId {
-    type Err = UserIdParseError;
-
-    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
-        Ok(match utils::parse_username(s) {
-            Some(id) => UserId(id),
-            None => s.parse::<u64>().map(UserId).map_err(|_| UserIdParseError::InvalidFormat)?,
-        })
-    }
-}
-
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
 #[derive(Debug)]
 pub enum RoleParseError {
@@ -168,43 +132,11 @@ impl FromStr for Role {
     }
 }
 
-#[cfg(all(feature = "model", feature = "utils"))]
-#[derive(Debug)]
-pub enum RoleIdParseError {
-    InvalidFormat,
-}
-
-#[cfg(all(feature = "model", feature = "utils"))]
-impl fmt::Display for RoleIdParseError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.description()) }
-}
-
-#[cfg(all(feature = "model", feature = "utils"))]
-impl StdError for RoleIdParseError {
-    fn description(&self) -> &str {
-        use self::RoleIdParseError::*;
-
-        match *self {
-            InvalidFormat => "invalid role id format",
-        }
-    }
-}
-
-#[cfg(all(feature = "model", feature = "utils"))]
-impl FromStr for RoleId {
-    type Err = RoleIdParseError;
-
-    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
-        Ok(match utils::parse_role(s) {
-            Some(id) => RoleId(id),
-            None => s.parse::<u64>().map(RoleId).map_err(|_| RoleIdParseError::InvalidFormat)?,
-        })
-    }
-}
-
 /// A version of an emoji used only when solely the Id and name are known.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct EmojiIdentifier {
+    /// Whether the emoji is animated.
+    pub animated: bool,
     /// The Id of the emoji.
     pub id: EmojiId,
     /// The name of the emoji. It must be at least 2 characters long and can
@@ -214,9 +146,13 @@ pub struct EmojiIdentifier {
 
 #[cfg(all(feature = "model", feature = "utils"))]
 impl EmojiIdentifier {
-    /// Generates a URL to the emoji's image.
-    #[inline]
-    pub fn url(&self) -> String { format!(cdn!("/emojis/{}.png"), self.id) }
+    /// Generates a URL to the emoji's image, which is a GIF if the emoji is
+    /// animated.
+    pub fn url(&self) -> String {
+        let extension = if self.animated { "gif" } else { "png" };
+
+        format!(cdn!("/emojis/{}.{}"), self.id, extension)
+    }
 }
 
 #[cfg(all(feature = "model", feature = "utils"))]
@@ -226,15 +162,6 @@ impl FromStr for EmojiIdentifier {
     fn from_str(s: &str) -> StdResult<Self, ()> { utils::parse_emoji(s).ok_or_else(|| ()) }
 }
 
-#[cfg(all(feature = "model", feature = "utils"))]
-impl FromStr for ChannelId {
-    type Err = ();
-
-    fn from_str(s: &str) -> StdResult<Self, ()> {
-        utils::parse_channel(s).ok_or_else(|| ()).map(ChannelId)
-    }
-}
-
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
 impl FromStr for Channel {
     type Err = ();
@@ -250,6 +177,149 @@ impl FromStr for Channel {
     }
 }
 
+/// A point in time to show on an embed, such as when an event happened.
+///
+/// This can be made from a date time, a number of seconds since the Unix
+/// epoch through [`from_unix`], or a [`Message`], in which case the time the
+/// message was sent is used. It is sent to Discord in the ISO 8601 format.
+///
+/// [`Message`]: struct.Message.html
+/// [`from_unix`]: #method.from_unix
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Timestamp(pub DateTime<FixedOffset>);
+
+impl Timestamp {
+    /// Creates a timestamp from a number of seconds since the Unix epoch.
+    ///
+    /// Returns `None` if the number of seconds is out of the range of dates
+    /// that can be represented.
+    pub fn from_unix(seconds: i64) -> Option<Timestamp> {
+        NaiveDateTime::from_timestamp_opt(seconds, 0)
+            .map(|date_time| Timestamp::from(DateTime::<Utc>::from_utc(date_time, Utc)))
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(&self.0.to_rfc3339()) }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_rfc3339())
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let timestamp = String::deserialize(deserializer)?;
+
+        DateTime::parse_from_rfc3339(&timestamp)
+            .map(Timestamp)
+            .map_err(D::Error::custom)
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for Timestamp {
+    fn from(date_time: DateTime<Tz>) -> Timestamp {
+        Timestamp(date_time.with_timezone(&FixedOffset::east(0)))
+    }
+}
+
+impl<'a> From<&'a Message> for Timestamp {
+    fn from(message: &'a Message) -> Timestamp { Timestamp(message.timestamp) }
+}
+
+/// A colour given as its red, green, and blue components, such as for an
+/// embed or a role.
+///
+/// This can be made from a tuple of components, or parsed from a hex string
+/// such as `"#7289DA"`, with or without the leading `#`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RgbColour(pub u32);
+
+impl RgbColour {
+    /// The red component of the colour.
+    pub fn r(&self) -> u8 { (self.0 >> 16) as u8 }
+
+    /// The green component of the colour.
+    pub fn g(&self) -> u8 { (self.0 >> 8) as u8 }
+
+    /// The blue component of the colour.
+    pub fn b(&self) -> u8 { self.0 as u8 }
+}
+
+impl Display for RgbColour {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { write!(f, "#{:06X}", self.0) }
+}
+
+impl From<(u8, u8, u8)> for RgbColour {
+    fn from((r, g, b): (u8, u8, u8)) -> RgbColour {
+        RgbColour(u32::from(r) << 16 | u32::from(g) << 8 | u32::from(b))
+    }
+}
+
+impl From<u32> for RgbColour {
+    fn from(colour: u32) -> RgbColour { RgbColour(colour) }
+}
+
+#[cfg(feature = "utils")]
+impl From<Colour> for RgbColour {
+    fn from(colour: Colour) -> RgbColour { RgbColour(colour.0) }
+}
+
+impl From<RgbColour> for u32 {
+    fn from(colour: RgbColour) -> u32 { colour.0 }
+}
+
+#[cfg(feature = "utils")]
+impl From<RgbColour> for Colour {
+    fn from(colour: RgbColour) -> Colour { Colour(colour.0) }
+}
+
+impl FromStr for RgbColour {
+    type Err = ColourParseError;
+
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        let hex = if s.starts_with('#') { &s[1..] } else { s };
+
+        if !hex.chars().all(|c| c.is_digit(16)) {
+            return Err(ColourParseError::InvalidDigit);
+        }
+
+        if hex.len() != 6 {
+            return Err(ColourParseError::InvalidLength);
+        }
+
+        u32::from_str_radix(hex, 16)
+            .map(RgbColour)
+            .map_err(|_| ColourParseError::InvalidDigit)
+    }
+}
+
+/// An error returned when parsing an [`RgbColour`] from a hex string.
+///
+/// [`RgbColour`]: struct.RgbColour.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ColourParseError {
+    /// The string contained a character that is not a hex digit.
+    InvalidDigit,
+    /// The string was not 6 hex digits long.
+    InvalidLength,
+}
+
+impl Display for ColourParseError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(self.description()) }
+}
+
+impl StdError for ColourParseError {
+    fn description(&self) -> &str {
+        match *self {
+            ColourParseError::InvalidDigit => "colour contains a non-hex digit",
+            ColourParseError::InvalidLength => "colour is not 6 hex digits long",
+        }
+    }
+}
+
 /// A component that was affected during a service incident.
 ///
 /// This is pulled from the Discord status page.
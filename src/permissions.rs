@@ -45,9 +45,18 @@
 //! [Manage Webhooks]: constant.MANAGE_WEBHOOKS.html
 
 use serde::de::{Error as DeError, Visitor};
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::{Formatter, Result as FmtResult};
 use std::result::Result as StdResult;
+use super::{
+    ChannelId,
+    ChannelType,
+    GuildId,
+    PermissionOverwrite,
+    PermissionOverwriteType,
+    RoleId,
+    UserId,
+};
 
 /// Returns a set of permissions with the original @everyone permissions set
 /// to true.
@@ -92,9 +101,7 @@ use std::result::Result as StdResult;
 /// [Speak]: constant.SPEAK.html
 /// [Use External Emojis]: constant.USE_EXTERNAL_EMOJIS.html
 /// [Use VAD]: constant.USE_VAD.html
-pub const PRESET_GENERAL: Permissions = Permissions {
-    bits: 0b0000_0110_0011_0111_1101_1100_0100_0001,
-};
+pub const PRESET_GENERAL: Permissions = Permissions::PRESET_GENERAL;
 
 /// Returns a set of text-only permissions with the original `@everyone`
 /// permissions set to true.
@@ -125,9 +132,7 @@ pub const PRESET_GENERAL: Permissions = Permissions {
 /// [Send Messages]: constant.SEND_MESSAGES.html
 /// [Send TTS Messages]: constant.SEND_TTS_MESSAGES.html
 /// [Use External Emojis]: constant.USE_EXTERNAL_EMOJIS.html
-pub const PRESET_TEXT: Permissions = Permissions {
-    bits: 0b0000_0000_0000_0111_1111_1100_0100_0000,
-};
+pub const PRESET_TEXT: Permissions = Permissions::PRESET_TEXT;
 
 /// Returns a set of voice-only permissions with the original `@everyone`
 /// permissions set to true.
@@ -142,8 +147,62 @@ pub const PRESET_TEXT: Permissions = Permissions {
 /// [Connect]: constant.CONNECT.html
 /// [Speak]: constant.SPEAK.html
 /// [Use VAD]: constant.USE_VAD.html
-pub const PRESET_VOICE: Permissions = Permissions {
-    bits: 0b0000_0011_1111_0000_0000_0000_0000_0000,
+pub const PRESET_VOICE: Permissions = Permissions::PRESET_VOICE;
+
+/// Permissions which only apply to the guild as a whole, and so are not
+/// affected by whether a channel can be viewed.
+const GUILD_ONLY: Permissions = Permissions {
+    bits: Permissions::ADMINISTRATOR.bits
+        | Permissions::BAN_MEMBERS.bits
+        | Permissions::CHANGE_NICKNAME.bits
+        | Permissions::KICK_MEMBERS.bits
+        | Permissions::MANAGE_EMOJIS.bits
+        | Permissions::MANAGE_GUILD.bits
+        | Permissions::MANAGE_NICKNAMES.bits,
+};
+
+/// Permissions which are implicitly denied without [Read Messages].
+///
+/// This is every permission other than the guild-wide ones, so that newly
+/// added channel permissions are covered without needing to be listed.
+///
+/// [Read Messages]: constant.READ_MESSAGES.html
+const VIEW_DEPENDENT: Permissions = Permissions {
+    bits: !GUILD_ONLY.bits,
+};
+
+/// Permissions which are implicitly denied without [Send Messages].
+///
+/// [Send Messages]: constant.SEND_MESSAGES.html
+const SEND_DEPENDENT: Permissions = Permissions {
+    bits: Permissions::ATTACH_FILES.bits
+        | Permissions::EMBED_LINKS.bits
+        | Permissions::MENTION_EVERYONE.bits
+        | Permissions::SEND_TTS_MESSAGES.bits,
+};
+
+/// Permissions which only apply to voice channels, and so are removed in text
+/// channels and threads.
+const VOICE: Permissions = Permissions {
+    bits: Permissions::CONNECT.bits
+        | Permissions::DEAFEN_MEMBERS.bits
+        | Permissions::MOVE_MEMBERS.bits
+        | Permissions::MUTE_MEMBERS.bits
+        | Permissions::SPEAK.bits
+        | Permissions::USE_VAD.bits,
+};
+
+/// Permissions which are implicitly denied in a voice channel without
+/// [Connect].
+///
+/// [Connect]: constant.CONNECT.html
+const CONNECT_DEPENDENT: Permissions = Permissions {
+    bits: VOICE.bits & !Permissions::CONNECT.bits,
+};
+
+/// Permissions which a timed out member keeps.
+const TIMED_OUT: Permissions = Permissions {
+    bits: Permissions::READ_MESSAGES.bits | Permissions::READ_MESSAGE_HISTORY.bits,
 };
 
 bitflags! {
@@ -250,6 +309,81 @@ bitflags! {
         ///
         /// [`Integration`]: ../struct.Integration.html
         const MANAGE_EMOJIS = 0b0100_0000_0000_0000_0000_0000_0000_0000;
+        /// Allows the creation, editing, and deleting of scheduled events.
+        const MANAGE_EVENTS = 0b0000_0010_0000_0000_0000_0000_0000_0000_0000_0000;
+        /// Allows the archiving, deleting, and editing of threads, as well as
+        /// viewing all private threads.
+        const MANAGE_THREADS = 0b0000_0100_0000_0000_0000_0000_0000_0000_0000_0000;
+        /// Allows the creation of public and announcement threads.
+        const CREATE_PUBLIC_THREADS = 0b0000_1000_0000_0000_0000_0000_0000_0000_0000_0000;
+        /// Allows the creation of private threads.
+        const CREATE_PRIVATE_THREADS = 0b0001_0000_0000_0000_0000_0000_0000_0000_0000_0000;
+        /// Allows sending messages in threads.
+        const SEND_MESSAGES_IN_THREADS = 0b0100_0000_0000_0000_0000_0000_0000_0000_0000_0000;
+    }
+}
+
+impl Permissions {
+    /// The permissions of the `@everyone` role in a newly created guild.
+    ///
+    /// This is the union of [`PRESET_TEXT`] and [`PRESET_VOICE`]. Refer to
+    /// the [module-level constant] for the full list.
+    ///
+    /// [`PRESET_TEXT`]: #associatedconstant.PRESET_TEXT
+    /// [`PRESET_VOICE`]: #associatedconstant.PRESET_VOICE
+    /// [module-level constant]: constant.PRESET_GENERAL.html
+    pub const PRESET_GENERAL: Permissions = Permissions {
+        bits: Self::PRESET_TEXT.bits | Self::PRESET_VOICE.bits,
+    };
+
+    /// The text permissions of the `@everyone` role in a newly created guild.
+    ///
+    /// Refer to the [module-level constant] for the full list.
+    ///
+    /// [module-level constant]: constant.PRESET_TEXT.html
+    pub const PRESET_TEXT: Permissions = Permissions {
+        bits: Self::ADD_REACTIONS.bits
+            | Self::ATTACH_FILES.bits
+            | Self::CHANGE_NICKNAME.bits
+            | Self::CREATE_INVITE.bits
+            | Self::EMBED_LINKS.bits
+            | Self::MENTION_EVERYONE.bits
+            | Self::READ_MESSAGE_HISTORY.bits
+            | Self::READ_MESSAGES.bits
+            | Self::SEND_MESSAGES.bits
+            | Self::SEND_TTS_MESSAGES.bits
+            | Self::USE_EXTERNAL_EMOJIS.bits,
+    };
+
+    /// The voice permissions of the `@everyone` role in a newly created guild:
+    /// [Connect], [Speak], and [Use VAD].
+    ///
+    /// [Connect]: constant.CONNECT.html
+    /// [Speak]: constant.SPEAK.html
+    /// [Use VAD]: constant.USE_VAD.html
+    pub const PRESET_VOICE: Permissions = Permissions {
+        bits: Self::CONNECT.bits | Self::SPEAK.bits | Self::USE_VAD.bits,
+    };
+
+    /// The permissions that a user has in a [`PrivateChannel`] or [`Group`].
+    ///
+    /// Direct messages have no roles or overwrites, so this is every text
+    /// permission that does not involve moderation.
+    ///
+    /// [`Group`]: ../struct.Group.html
+    /// [`PrivateChannel`]: ../struct.PrivateChannel.html
+    pub const fn dm_permissions() -> Permissions {
+        Permissions {
+            bits: Self::ADD_REACTIONS.bits
+                | Self::ATTACH_FILES.bits
+                | Self::EMBED_LINKS.bits
+                | Self::MENTION_EVERYONE.bits
+                | Self::READ_MESSAGE_HISTORY.bits
+                | Self::READ_MESSAGES.bits
+                | Self::SEND_MESSAGES.bits
+                | Self::SEND_TTS_MESSAGES.bits
+                | Self::USE_EXTERNAL_EMOJIS.bits,
+        }
     }
 }
 
@@ -296,6 +430,18 @@ impl Permissions {
     /// [Create Invite]: constant.CREATE_INVITE.html
     pub fn create_invite(&self) -> bool { self.contains(Self::CREATE_INVITE) }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Create Private Threads] permission.
+    ///
+    /// [Create Private Threads]: constant.CREATE_PRIVATE_THREADS.html
+    pub fn create_private_threads(&self) -> bool { self.contains(Self::CREATE_PRIVATE_THREADS) }
+
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Create Public Threads] permission.
+    ///
+    /// [Create Public Threads]: constant.CREATE_PUBLIC_THREADS.html
+    pub fn create_public_threads(&self) -> bool { self.contains(Self::CREATE_PUBLIC_THREADS) }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Deafen Members] permission.
     ///
@@ -332,6 +478,12 @@ impl Permissions {
     /// [Manage Emojis]: constant.MANAGE_EMOJIS.html
     pub fn manage_emojis(&self) -> bool { self.contains(Self::MANAGE_EMOJIS) }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Manage Events] permission.
+    ///
+    /// [Manage Events]: constant.MANAGE_EVENTS.html
+    pub fn manage_events(&self) -> bool { self.contains(Self::MANAGE_EVENTS) }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Manage Guild] permission.
     ///
@@ -356,6 +508,12 @@ impl Permissions {
     /// [Manage Roles]: constant.MANAGE_ROLES.html
     pub fn manage_roles(&self) -> bool { self.contains(Self::MANAGE_ROLES) }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Manage Threads] permission.
+    ///
+    /// [Manage Threads]: constant.MANAGE_THREADS.html
+    pub fn manage_threads(&self) -> bool { self.contains(Self::MANAGE_THREADS) }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Manage Webhooks] permission.
     ///
@@ -398,6 +556,12 @@ impl Permissions {
     /// [Send Messages]: constant.SEND_MESSAGES.html
     pub fn send_messages(&self) -> bool { self.contains(Self::SEND_MESSAGES) }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Send Messages in Threads] permission.
+    ///
+    /// [Send Messages in Threads]: constant.SEND_MESSAGES_IN_THREADS.html
+    pub fn send_messages_in_threads(&self) -> bool { self.contains(Self::SEND_MESSAGES_IN_THREADS) }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Send TTS Messages] permission.
     ///
@@ -423,21 +587,206 @@ impl Permissions {
     pub fn use_vad(&self) -> bool { self.contains(Self::USE_VAD) }
 }
 
+/// Calculates a member's permissions from raw data, without needing a full
+/// [`Guild`].
+///
+/// This can be fed from any source, such as interaction payloads or partial
+/// caches. [`Guild::member_permissions`] and [`Guild::permissions_in`] use it
+/// internally.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use serenity_model::permissions::{self, PermissionCalculator};
+///
+/// let mut calculator = PermissionCalculator::new(guild_id, user_id, permissions::PRESET_GENERAL);
+/// calculator.member_roles = vec![(role_id, role_permissions)];
+/// calculator.channel_id = Some(channel.id);
+/// calculator.channel_kind = Some(channel.kind);
+/// calculator.overwrites = &channel.permission_overwrites;
+///
+/// let permissions = calculator.calculate();
+/// ```
+///
+/// [`Guild`]: ../struct.Guild.html
+/// [`Guild::member_permissions`]: ../struct.Guild.html#method.member_permissions
+/// [`Guild::permissions_in`]: ../struct.Guild.html#method.permissions_in
+#[derive(Clone, Debug)]
+pub struct PermissionCalculator<'a> {
+    /// The Id of the guild.
+    pub guild_id: GuildId,
+    /// The Id of the user whose permissions are being calculated.
+    pub user_id: UserId,
+    /// Whether the user owns the guild, granting them all permissions.
+    pub is_owner: bool,
+    /// Whether the member is timed out.
+    pub is_timed_out: bool,
+    /// The permissions of the guild's `@everyone` role.
+    pub everyone: Permissions,
+    /// The Ids and permissions of the member's roles, excluding `@everyone`.
+    pub member_roles: Vec<(RoleId, Permissions)>,
+    /// The Id of the channel, if calculating permissions in a channel.
+    pub channel_id: Option<ChannelId>,
+    /// The type of the channel, if calculating permissions in a channel.
+    pub channel_kind: Option<ChannelType>,
+    /// The channel's permission overwrites.
+    pub overwrites: &'a [PermissionOverwrite],
+}
+
+impl<'a> PermissionCalculator<'a> {
+    /// Creates a calculator for a member with no roles, outside of any
+    /// channel.
+    pub fn new(guild_id: GuildId, user_id: UserId, everyone: Permissions) -> Self {
+        PermissionCalculator {
+            guild_id: guild_id,
+            user_id: user_id,
+            is_owner: false,
+            is_timed_out: false,
+            everyone: everyone,
+            member_roles: vec![],
+            channel_id: None,
+            channel_kind: None,
+            overwrites: &[],
+        }
+    }
+
+    /// Calculates the member's guild-wide permissions, from the `@everyone`
+    /// role and their own roles.
+    ///
+    /// Channel information is ignored.
+    pub fn base(&self) -> Permissions {
+        if self.is_owner {
+            return Permissions::all();
+        }
+
+        let permissions = self.member_roles
+            .iter()
+            .fold(self.everyone, |permissions, &(_, role)| permissions | role);
+
+        // Administrators have all permissions in any channel.
+        if permissions.contains(Permissions::ADMINISTRATOR) {
+            return Permissions::all();
+        }
+
+        permissions
+    }
+
+    /// Calculates the member's permissions in the channel, applying its
+    /// overwrites on top of the [`base`] permissions.
+    ///
+    /// Permissions which depend on another permission are implicitly denied
+    /// without it, such as message permissions without [Read Messages], and
+    /// a timed out member only keeps [Read Messages] and
+    /// [Read Message History].
+    ///
+    /// [`base`]: #method.base
+    /// [Read Message History]: constant.READ_MESSAGE_HISTORY.html
+    /// [Read Messages]: constant.READ_MESSAGES.html
+    pub fn calculate(&self) -> Permissions {
+        let mut permissions = self.base();
+
+        if permissions == Permissions::all() {
+            return permissions;
+        }
+
+        // Apply the channel's permission overwrites in the order that Discord
+        // does: first the `@everyone` overwrite, then the overwrites of all of
+        // the member's roles together, and then the member's own overwrite.
+        // Within each step, denies are applied before allows, so an allow
+        // from any role wins over a deny from another.
+        let everyone_role = self.guild_id.everyone_role();
+        let mut role_allow = Permissions::empty();
+        let mut role_deny = Permissions::empty();
+        let mut member_overwrite = None;
+
+        for overwrite in self.overwrites {
+            match overwrite.kind {
+                PermissionOverwriteType::Role(role) if role == everyone_role => {
+                    permissions = (permissions & !overwrite.deny) | overwrite.allow;
+                },
+                PermissionOverwriteType::Role(role) if self.has_role(role) => {
+                    role_allow |= overwrite.allow;
+                    role_deny |= overwrite.deny;
+                },
+                PermissionOverwriteType::Member(id) if id == self.user_id => {
+                    member_overwrite = Some(overwrite);
+                },
+                _ => {},
+            }
+        }
+
+        permissions = (permissions & !role_deny) | role_allow;
+
+        if let Some(overwrite) = member_overwrite {
+            permissions = (permissions & !overwrite.deny) | overwrite.allow;
+        }
+
+        // The default channel is always readable.
+        if self.channel_id.map_or(false, |id| id.0 == self.guild_id.0) {
+            permissions |= Permissions::READ_MESSAGES;
+        }
+
+        // Timed out members can only view channels and read their history,
+        // regardless of any overwrites.
+        if self.is_timed_out {
+            permissions &= TIMED_OUT;
+        }
+
+        if let Some(kind) = self.channel_kind {
+            let is_text = kind == ChannelType::Text
+                || kind == ChannelType::News
+                || kind.is_thread();
+
+            // Voice permissions have no meaning in text channels, and the
+            // rest of them require being able to connect in voice channels.
+            if is_text {
+                permissions &= !VOICE;
+            } else if !permissions.contains(Permissions::CONNECT) {
+                permissions &= !CONNECT_DEPENDENT;
+            }
+        }
+
+        // If the member can not send messages, then they can not perform any
+        // of the actions that are part of sending one.
+        if !permissions.contains(Permissions::SEND_MESSAGES) {
+            permissions &= !SEND_DEPENDENT;
+        }
+
+        // If the member can not view the channel, then only the guild-wide
+        // permissions remain.
+        if !permissions.contains(Permissions::READ_MESSAGES) {
+            permissions &= !VIEW_DEPENDENT;
+        }
+
+        permissions
+    }
+
+    fn has_role(&self, role_id: RoleId) -> bool {
+        self.member_roles.iter().any(|&(id, _)| id == role_id)
+    }
+}
+
 impl<'de> Deserialize<'de> for Permissions {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
         Ok(Permissions::from_bits_truncate(
-            deserializer.deserialize_u64(U64Visitor)?,
+            deserializer.deserialize_any(U64Visitor)?,
         ))
     }
 }
 
+impl Serialize for Permissions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
 struct U64Visitor;
 
 impl<'de> Visitor<'de> for U64Visitor {
     type Value = u64;
 
     fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
-        formatter.write_str("an unsigned 64-bit integer")
+        formatter.write_str("an unsigned 64-bit integer or its string")
     }
 
     fn visit_i32<E: DeError>(self, value: i32) -> StdResult<u64, E> { Ok(value as u64) }
@@ -447,4 +796,224 @@ impl<'de> Visitor<'de> for U64Visitor {
     fn visit_u32<E: DeError>(self, value: u32) -> StdResult<u64, E> { Ok(u64::from(value)) }
 
     fn visit_u64<E: DeError>(self, value: u64) -> StdResult<u64, E> { Ok(value) }
+
+    fn visit_str<E: DeError>(self, value: &str) -> StdResult<u64, E> {
+        value.parse().map_err(|_| E::custom(format!("invalid permissions: {}", value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        ChannelId,
+        ChannelType,
+        GuildId,
+        PermissionOverwrite,
+        PermissionOverwriteType,
+        RoleId,
+        UserId,
+    };
+    use super::{PermissionCalculator, Permissions};
+
+    const GUILD: GuildId = GuildId(1);
+    const USER: UserId = UserId(2);
+    const ROLE_A: RoleId = RoleId(3);
+    const ROLE_B: RoleId = RoleId(4);
+    const CHANNEL: ChannelId = ChannelId(5);
+
+    fn overwrite(kind: PermissionOverwriteType, allow: Permissions, deny: Permissions)
+        -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow: allow,
+            deny: deny,
+            kind: kind,
+        }
+    }
+
+    fn text_channel<'a>(overwrites: &'a [PermissionOverwrite]) -> PermissionCalculator<'a> {
+        let everyone = Permissions::READ_MESSAGES | Permissions::SEND_MESSAGES;
+        let mut calculator = PermissionCalculator::new(GUILD, USER, everyone);
+        calculator.channel_id = Some(CHANNEL);
+        calculator.channel_kind = Some(ChannelType::Text);
+        calculator.overwrites = overwrites;
+
+        calculator
+    }
+
+    #[test]
+    fn owner_has_all_permissions() {
+        let mut calculator = PermissionCalculator::new(GUILD, USER, Permissions::empty());
+        calculator.is_owner = true;
+
+        assert_eq!(calculator.base(), Permissions::all());
+        assert_eq!(calculator.calculate(), Permissions::all());
+    }
+
+    #[test]
+    fn administrator_ignores_overwrites() {
+        let overwrites = [overwrite(
+            PermissionOverwriteType::Role(GUILD.everyone_role()),
+            Permissions::empty(),
+            Permissions::READ_MESSAGES,
+        )];
+        let mut calculator = text_channel(&overwrites);
+        calculator.member_roles = vec![(ROLE_A, Permissions::ADMINISTRATOR)];
+
+        assert_eq!(calculator.calculate(), Permissions::all());
+    }
+
+    #[test]
+    fn base_combines_roles() {
+        let mut calculator = PermissionCalculator::new(GUILD, USER, Permissions::READ_MESSAGES);
+        calculator.member_roles = vec![
+            (ROLE_A, Permissions::KICK_MEMBERS),
+            (ROLE_B, Permissions::BAN_MEMBERS),
+        ];
+
+        assert_eq!(
+            calculator.base(),
+            Permissions::READ_MESSAGES | Permissions::KICK_MEMBERS | Permissions::BAN_MEMBERS,
+        );
+    }
+
+    #[test]
+    fn everyone_overwrite_denies() {
+        let overwrites = [overwrite(
+            PermissionOverwriteType::Role(GUILD.everyone_role()),
+            Permissions::empty(),
+            Permissions::SEND_MESSAGES,
+        )];
+
+        assert_eq!(text_channel(&overwrites).calculate(), Permissions::READ_MESSAGES);
+    }
+
+    #[test]
+    fn role_allow_wins_over_role_deny() {
+        let overwrites = [
+            overwrite(
+                PermissionOverwriteType::Role(ROLE_A),
+                Permissions::empty(),
+                Permissions::SEND_MESSAGES,
+            ),
+            overwrite(
+                PermissionOverwriteType::Role(ROLE_B),
+                Permissions::SEND_MESSAGES,
+                Permissions::empty(),
+            ),
+        ];
+        let mut calculator = text_channel(&overwrites);
+        calculator.member_roles = vec![
+            (ROLE_A, Permissions::empty()),
+            (ROLE_B, Permissions::empty()),
+        ];
+
+        assert!(calculator.calculate().send_messages());
+    }
+
+    #[test]
+    fn overwrites_of_other_roles_are_ignored() {
+        let overwrites = [overwrite(
+            PermissionOverwriteType::Role(ROLE_B),
+            Permissions::empty(),
+            Permissions::SEND_MESSAGES,
+        )];
+        let mut calculator = text_channel(&overwrites);
+        calculator.member_roles = vec![(ROLE_A, Permissions::empty())];
+
+        assert!(calculator.calculate().send_messages());
+    }
+
+    #[test]
+    fn member_overwrite_wins_over_roles() {
+        let overwrites = [
+            overwrite(
+                PermissionOverwriteType::Member(USER),
+                Permissions::empty(),
+                Permissions::SEND_MESSAGES,
+            ),
+            overwrite(
+                PermissionOverwriteType::Role(ROLE_A),
+                Permissions::SEND_MESSAGES,
+                Permissions::empty(),
+            ),
+        ];
+        let mut calculator = text_channel(&overwrites);
+        calculator.member_roles = vec![(ROLE_A, Permissions::empty())];
+
+        assert!(!calculator.calculate().send_messages());
+    }
+
+    #[test]
+    fn default_channel_is_readable() {
+        let overwrites = [overwrite(
+            PermissionOverwriteType::Role(GUILD.everyone_role()),
+            Permissions::empty(),
+            Permissions::READ_MESSAGES,
+        )];
+        let mut calculator = text_channel(&overwrites);
+        calculator.channel_id = Some(ChannelId(GUILD.0));
+
+        assert!(calculator.calculate().read_messages());
+    }
+
+    #[test]
+    fn timed_out_member_can_only_read() {
+        let mut calculator = text_channel(&[]);
+        calculator.everyone |= Permissions::READ_MESSAGE_HISTORY | Permissions::ADD_REACTIONS;
+        calculator.is_timed_out = true;
+
+        assert_eq!(
+            calculator.calculate(),
+            Permissions::READ_MESSAGES | Permissions::READ_MESSAGE_HISTORY,
+        );
+    }
+
+    #[test]
+    fn text_channel_drops_voice_permissions() {
+        let mut calculator = text_channel(&[]);
+        calculator.everyone |= Permissions::CONNECT | Permissions::SPEAK;
+
+        assert_eq!(
+            calculator.calculate(),
+            Permissions::READ_MESSAGES | Permissions::SEND_MESSAGES,
+        );
+    }
+
+    #[test]
+    fn voice_permissions_require_connect() {
+        let everyone = Permissions::READ_MESSAGES | Permissions::SPEAK | Permissions::USE_VAD;
+        let mut calculator = PermissionCalculator::new(GUILD, USER, everyone);
+        calculator.channel_kind = Some(ChannelType::Voice);
+
+        assert_eq!(calculator.calculate(), Permissions::READ_MESSAGES);
+
+        calculator.everyone |= Permissions::CONNECT;
+
+        assert_eq!(calculator.calculate(), everyone | Permissions::CONNECT);
+    }
+
+    #[test]
+    fn send_dependent_permissions_require_send() {
+        let overwrites = [overwrite(
+            PermissionOverwriteType::Role(GUILD.everyone_role()),
+            Permissions::ATTACH_FILES | Permissions::EMBED_LINKS,
+            Permissions::SEND_MESSAGES,
+        )];
+
+        assert_eq!(text_channel(&overwrites).calculate(), Permissions::READ_MESSAGES);
+    }
+
+    #[test]
+    fn hidden_channel_keeps_guild_permissions() {
+        let overwrites = [overwrite(
+            PermissionOverwriteType::Role(GUILD.everyone_role()),
+            Permissions::empty(),
+            Permissions::READ_MESSAGES,
+        )];
+        let mut calculator = text_channel(&overwrites);
+        let role = Permissions::KICK_MEMBERS | Permissions::MANAGE_MESSAGES;
+        calculator.member_roles = vec![(ROLE_A, role)];
+
+        assert_eq!(calculator.calculate(), Permissions::KICK_MEMBERS);
+    }
 }
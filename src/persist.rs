@@ -0,0 +1,321 @@
+//! A versioned format for persisting models, such as to warm-start a cache
+//! from disk after a restart, or to back up a guild as a [`GuildSnapshot`].
+//!
+//! Models are wrapped alongside the [`ModelVersion`] they were written with.
+//! When the layout of a model changes, [`MODEL_VERSION`] is incremented and
+//! payloads written by older versions are migrated up to it by
+//! [`Persist::migrate`] when loaded.
+//!
+//! Payloads can be written as JSON with [`to_value`], or, with the `persist`
+//! feature enabled, as [MessagePack] with [`to_bytes`]:
+//!
+//! ```rust,ignore
+//! use serenity_model::persist;
+//! use serenity_model::Guild;
+//!
+//! let bytes = persist::to_bytes(&guilds)?;
+//! // ...
+//! let guilds = persist::from_bytes::<Vec<Guild>>(&bytes)?;
+//! ```
+//!
+//! The models rely on self-describing formats to deserialize, as Discord
+//! sends some fields in several encodings, and so formats such as bincode are
+//! not supported.
+//!
+//! [MessagePack]: https://msgpack.org
+//! [`GuildSnapshot`]: ../struct.GuildSnapshot.html
+//! [`MODEL_VERSION`]: constant.MODEL_VERSION.html
+//! [`ModelVersion`]: struct.ModelVersion.html
+//! [`Persist::migrate`]: trait.Persist.html#method.migrate
+//! [`to_bytes`]: fn.to_bytes.html
+//! [`to_value`]: fn.to_value.html
+
+use serde::de::DeserializeOwned;
+use serde::ser::Serialize;
+use serde_json::{self, Value};
+use std::error::Error as StdError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::result::Result as StdResult;
+use super::{Channel, Guild, GuildSnapshot, Member, User};
+
+#[cfg(feature = "persist")]
+use rmp_serde::{self, decode, encode};
+
+/// The version of the persisted format written by this version of the
+/// library.
+pub const MODEL_VERSION: ModelVersion = ModelVersion(1);
+
+/// The version of the format that a persisted payload was written with.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct ModelVersion(pub u64);
+
+impl Display for ModelVersion {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { Display::fmt(&self.0, f) }
+}
+
+/// A model that can be persisted via [`to_value`] or [`to_bytes`], and
+/// loaded again via [`from_value`] or [`from_bytes`].
+///
+/// [`from_bytes`]: fn.from_bytes.html
+/// [`from_value`]: fn.from_value.html
+/// [`to_bytes`]: fn.to_bytes.html
+/// [`to_value`]: fn.to_value.html
+pub trait Persist: Serialize + DeserializeOwned {
+    /// Upgrades the data of a payload in place from the given version of the
+    /// format to the version after it.
+    ///
+    /// This is called once for each version between the one the payload was
+    /// written with and [`MODEL_VERSION`]. Models whose layout changes in a
+    /// version should convert from the previous layout here; by default, the
+    /// data is left as it is.
+    ///
+    /// [`MODEL_VERSION`]: constant.MODEL_VERSION.html
+    fn migrate(_data: &mut Value, _from: ModelVersion) {}
+}
+
+impl Persist for Channel {}
+impl Persist for Guild {}
+impl Persist for GuildSnapshot {}
+impl Persist for Member {}
+impl Persist for User {}
+
+impl<T: Persist> Persist for Vec<T> {
+    fn migrate(data: &mut Value, from: ModelVersion) {
+        if let Value::Array(ref mut values) = *data {
+            for value in values {
+                T::migrate(value, from);
+            }
+        }
+    }
+}
+
+/// An error returned when persisting or loading a model.
+#[derive(Debug)]
+pub enum PersistError {
+    /// The payload was not valid MessagePack.
+    #[cfg(feature = "persist")]
+    Decode(decode::Error),
+    /// The model could not be encoded.
+    #[cfg(feature = "persist")]
+    Encode(encode::Error),
+    /// The payload did not match the shape of the model.
+    Model(serde_json::Error),
+    /// The payload was written by a newer version of the library.
+    UnsupportedVersion(ModelVersion),
+}
+
+impl Display for PersistError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            #[cfg(feature = "persist")]
+            PersistError::Decode(ref inner) => Display::fmt(inner, f),
+            #[cfg(feature = "persist")]
+            PersistError::Encode(ref inner) => Display::fmt(inner, f),
+            PersistError::Model(ref inner) => Display::fmt(inner, f),
+            PersistError::UnsupportedVersion(version) => {
+                write!(f, "unsupported model version {}", version)
+            },
+        }
+    }
+}
+
+impl StdError for PersistError {
+    fn description(&self) -> &str {
+        match *self {
+            #[cfg(feature = "persist")]
+            PersistError::Decode(ref inner) => inner.description(),
+            #[cfg(feature = "persist")]
+            PersistError::Encode(ref inner) => inner.description(),
+            PersistError::Model(ref inner) => inner.description(),
+            PersistError::UnsupportedVersion(_) => "unsupported model version",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct EnvelopeRef<'a, T: 'a> {
+    version: ModelVersion,
+    data: &'a T,
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    version: ModelVersion,
+    data: Value,
+}
+
+/// Converts a model to JSON, tagged with the current [`MODEL_VERSION`].
+///
+/// # Errors
+///
+/// Returns [`PersistError::Model`] if the model could not be serialized.
+///
+/// [`MODEL_VERSION`]: constant.MODEL_VERSION.html
+/// [`PersistError::Model`]: enum.PersistError.html#variant.Model
+pub fn to_value<T: Persist>(model: &T) -> StdResult<Value, PersistError> {
+    let envelope = EnvelopeRef {
+        version: MODEL_VERSION,
+        data: model,
+    };
+
+    serde_json::to_value(&envelope).map_err(PersistError::Model)
+}
+
+/// Loads a model written by [`to_value`], migrating it from an older
+/// [`ModelVersion`] if needed.
+///
+/// # Errors
+///
+/// Returns [`PersistError::UnsupportedVersion`] if the payload was written
+/// with a version newer than [`MODEL_VERSION`].
+///
+/// [`MODEL_VERSION`]: constant.MODEL_VERSION.html
+/// [`ModelVersion`]: struct.ModelVersion.html
+/// [`PersistError::UnsupportedVersion`]: enum.PersistError.html#variant.UnsupportedVersion
+/// [`to_value`]: fn.to_value.html
+pub fn from_value<T: Persist>(value: Value) -> StdResult<T, PersistError> {
+    let Envelope { version, data } = serde_json::from_value(value)
+        .map_err(PersistError::Model)?;
+
+    load(version, data)
+}
+
+/// Encodes a model as MessagePack, tagged with the current
+/// [`MODEL_VERSION`].
+///
+/// # Errors
+///
+/// Returns [`PersistError::Encode`] if the model could not be encoded.
+///
+/// [`MODEL_VERSION`]: constant.MODEL_VERSION.html
+/// [`PersistError::Encode`]: enum.PersistError.html#variant.Encode
+#[cfg(feature = "persist")]
+pub fn to_bytes<T: Persist>(model: &T) -> StdResult<Vec<u8>, PersistError> {
+    let envelope = EnvelopeRef {
+        version: MODEL_VERSION,
+        data: model,
+    };
+
+    rmp_serde::to_vec_named(&envelope).map_err(PersistError::Encode)
+}
+
+/// Decodes a model written by [`to_bytes`], migrating it from an older
+/// [`ModelVersion`] if needed.
+///
+/// # Errors
+///
+/// Returns [`PersistError::UnsupportedVersion`] if the payload was written
+/// with a version newer than [`MODEL_VERSION`].
+///
+/// [`MODEL_VERSION`]: constant.MODEL_VERSION.html
+/// [`ModelVersion`]: struct.ModelVersion.html
+/// [`PersistError::UnsupportedVersion`]: enum.PersistError.html#variant.UnsupportedVersion
+/// [`to_bytes`]: fn.to_bytes.html
+#[cfg(feature = "persist")]
+pub fn from_bytes<T: Persist>(bytes: &[u8]) -> StdResult<T, PersistError> {
+    let Envelope { version, data } = rmp_serde::from_slice(bytes)
+        .map_err(PersistError::Decode)?;
+
+    load(version, data)
+}
+
+/// Migrates the data of a payload from the version it was written with, and
+/// deserializes the model from it.
+fn load<T: Persist>(mut version: ModelVersion, mut data: Value) -> StdResult<T, PersistError> {
+    if version > MODEL_VERSION {
+        return Err(PersistError::UnsupportedVersion(version));
+    }
+
+    while version < MODEL_VERSION {
+        T::migrate(&mut data, version);
+        version.0 += 1;
+    }
+
+    serde_json::from_value(data).map_err(PersistError::Model)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::super::fixtures::support::{self, guild};
+    use super::super::{Channel, Guild, Member};
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Renamed {
+        name: String,
+    }
+
+    impl Persist for Renamed {
+        // Version 0 called the field `title`.
+        fn migrate(data: &mut Value, from: ModelVersion) {
+            if let (ModelVersion(0), Some(map)) = (from, data.as_object_mut()) {
+                if let Some(title) = map.remove("title") {
+                    map.insert("name".to_string(), title);
+                }
+            }
+        }
+    }
+
+    /// Persists a model and loads it again, as both JSON and MessagePack.
+    fn assert_round_trip<T: Persist>(model: &T) {
+        support::assert_round_trip(model, |model| from_value(to_value(model).unwrap()).unwrap());
+
+        #[cfg(feature = "persist")]
+        support::assert_round_trip(model, |model| from_bytes(&to_bytes(model).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn guild_round_trip() { assert_round_trip(&guild()); }
+
+    #[test]
+    fn member_round_trip() {
+        let members = guild().members.values().cloned().collect::<Vec<Member>>();
+
+        assert_eq!(members.len(), 2);
+        assert_round_trip(&members);
+    }
+
+    #[test]
+    fn channel_round_trip() {
+        let channels = guild()
+            .channels
+            .values()
+            .map(|channel| Channel::Guild(channel.clone()))
+            .collect::<Vec<Channel>>();
+
+        assert_eq!(channels.len(), 2);
+        assert_round_trip(&channels);
+    }
+
+    #[test]
+    fn snapshot_round_trip() {
+        let snapshot = guild().to_snapshot();
+
+        assert_eq!(snapshot.channels.len(), 2);
+        assert_round_trip(&snapshot);
+    }
+
+    #[test]
+    fn older_version_is_migrated() {
+        let value = serde_json::from_str(r#"{"version": 0, "data": [{"title": "general"}]}"#);
+        let loaded = from_value::<Vec<Renamed>>(value.unwrap()).unwrap();
+
+        assert_eq!(loaded, vec![Renamed {
+            name: "general".to_string(),
+        }]);
+    }
+
+    #[test]
+    fn newer_version_is_rejected() {
+        let mut value = to_value(&guild()).unwrap();
+        value["version"] = Value::from(MODEL_VERSION.0 + 1);
+
+        match from_value::<Guild>(value) {
+            Err(PersistError::UnsupportedVersion(version)) => {
+                assert_eq!(version, ModelVersion(MODEL_VERSION.0 + 1));
+            },
+            other => panic!("expected an unsupported version, got {:?}", other.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! A set of exports of the most commonly used models.
+//!
+//! This can be glob imported to bring the Ids, channel and guild models, and
+//! [`Permissions`] into scope without importing each individually:
+//!
+//! ```rust,ignore
+//! use model::prelude::*;
+//! ```
+//!
+//! [`Permissions`]: ../permissions/struct.Permissions.html
+
+pub use super::channel::{
+    Channel,
+    ChannelCategory,
+    ChannelType,
+    Group,
+    GuildChannel,
+    Message,
+    PermissionOverwrite,
+    PermissionOverwriteType,
+    PrivateChannel,
+    Reaction,
+    ReactionType,
+};
+pub use super::error::Error as ModelError;
+pub use super::guild::{Emoji, Guild, GuildRef, Member, PartialGuild, Role};
+pub use super::misc::Mentionable;
+pub use super::permissions::Permissions;
+pub use super::user::{CurrentUser, OnlineStatus, User};
+pub use super::{
+    AuditLogEntryId,
+    ChannelId,
+    EmojiId,
+    GuildId,
+    IdMap,
+    IntegrationId,
+    MessageId,
+    RoleId,
+    ScheduledEventId,
+    UserId,
+    WebhookId,
+};
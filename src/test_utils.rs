@@ -0,0 +1,241 @@
+//! Builders for populated models, for use in the unit tests of downstream
+//! crates.
+//!
+//! Each function produces a model that is valid as if it had been received
+//! from the API, with sensible defaults for every field. Fields can then be
+//! overwritten as needed by the test:
+//!
+//! ```rust,ignore
+//! use serenity_model::test_utils::*;
+//!
+//! let mut guild = mock_guild(1);
+//! let member = mock_member(&guild, 2);
+//! guild.members.insert(member.user.id, member);
+//!
+//! let message = mock_message(guild.id.0, mock_user(2), "hello");
+//! ```
+//!
+//! This module is only available with the `test-helpers` feature enabled.
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use super::permissions::{self, Permissions};
+use super::*;
+
+#[cfg(feature = "utils")]
+use utils::Colour;
+
+static NEXT_SNOWFLAKE: AtomicUsize = AtomicUsize::new(0);
+
+/// Generates a unique snowflake, for models whose Id is not given.
+///
+/// Snowflakes are generated from a fixed point in time, so that their
+/// creation dates are stable across test runs.
+pub fn mock_snowflake() -> u64 {
+    // 2018-01-01T00:00:00Z, as a Unix timestamp in milliseconds.
+    const BASE: u64 = 1_514_764_800_000;
+
+    let count = NEXT_SNOWFLAKE.fetch_add(1, Ordering::Relaxed) as u64;
+
+    Snowflake::from_parts(SnowflakeParts {
+        timestamp: BASE + (count >> 12),
+        worker_id: 0,
+        process_id: 0,
+        increment: (count & 0xFFF) as u16,
+    }).0
+}
+
+/// Creates a text channel in a guild, with no permission overwrites.
+pub fn mock_channel<G, C>(guild_id: G, channel_id: C, name: &str) -> GuildChannel
+    where G: Into<GuildId>, C: Into<ChannelId> {
+    GuildChannel {
+        id: channel_id.into(),
+        bitrate: None,
+        category_id: None,
+        guild_id: guild_id.into(),
+        kind: ChannelType::Text,
+        last_message_id: None,
+        last_pin_timestamp: None,
+        member: None,
+        member_count: None,
+        message_count: None,
+        name: name.to_string(),
+        owner_id: None,
+        permission_overwrites: vec![],
+        position: 0,
+        rate_limit_per_user: Some(0),
+        rtc_region: AutoOrRegion::Automatic,
+        status: None,
+        thread_metadata: None,
+        topic: None,
+        user_limit: None,
+        nsfw: false,
+    }
+}
+
+/// Creates a guild named `"Test Guild"`.
+///
+/// The guild contains:
+///
+/// - an `@everyone` role with the [general permissions preset];
+/// - a text channel named `"general"`, sharing the guild's Id;
+/// - its owner, a user sharing the guild's Id, as its only member.
+///
+/// [general permissions preset]: ../permissions/constant.PRESET_GENERAL.html
+pub fn mock_guild<G: Into<GuildId>>(guild_id: G) -> Guild {
+    let id = guild_id.into();
+    let owner_id = UserId(id.0);
+
+    let mut channels = IdMap::default();
+    channels.insert(ChannelId(id.0), mock_channel(id, id.0, "general"));
+
+    let mut members = IdMap::default();
+    members.insert(owner_id, Member {
+        communication_disabled_until: None,
+        deaf: false,
+        flags: MemberFlags::empty(),
+        guild_id: id,
+        joined_at: Some(timestamp(id.created_at())),
+        mute: false,
+        nick: None,
+        pending: false,
+        premium_since: None,
+        roles: vec![],
+        user: mock_user(owner_id),
+    });
+
+    let mut everyone = mock_role(id.everyone_role(), "@everyone");
+    everyone.permissions = permissions::PRESET_GENERAL;
+
+    let mut roles = IdMap::default();
+    roles.insert(everyone.id, everyone);
+
+    Guild {
+        afk_channel_id: None,
+        afk_timeout: 300,
+        banner: None,
+        bans: Some(IdMap::default()),
+        channels: channels,
+        default_message_notifications: 0,
+        emojis: IdMap::default(),
+        features: vec![],
+        icon: None,
+        id: id,
+        incidents_data: None,
+        joined_at: timestamp(id.created_at()),
+        large: false,
+        member_count: 1,
+        members: members,
+        mfa_level: 0,
+        name: "Test Guild".to_string(),
+        owner_id: owner_id,
+        preferred_locale: Some("en-US".to_string()),
+        premium_tier: PremiumTier::None,
+        presences: IdMap::default(),
+        region: "us-east".to_string(),
+        roles: roles,
+        splash: None,
+        system_channel_flags: SystemChannelFlags::empty(),
+        system_channel_id: Some(ChannelId(id.0)),
+        verification_level: VerificationLevel::None,
+        voice_states: IdMap::default(),
+    }
+}
+
+/// Creates a member of the guild for a [`mock_user`], with no roles.
+///
+/// The member is not added to the guild.
+///
+/// [`mock_user`]: fn.mock_user.html
+pub fn mock_member<U: Into<UserId>>(guild: &Guild, user_id: U) -> Member {
+    let user = mock_user(user_id);
+
+    Member {
+        communication_disabled_until: None,
+        deaf: false,
+        flags: MemberFlags::empty(),
+        guild_id: guild.id,
+        joined_at: Some(timestamp(user.id.created_at())),
+        mute: false,
+        nick: None,
+        pending: false,
+        premium_since: None,
+        roles: vec![],
+        user: user,
+    }
+}
+
+/// Creates a regular message sent by the author in a channel, with a unique
+/// Id generated by [`mock_snowflake`].
+///
+/// The message is treated as a direct message; set its [`guild_id`] for a
+/// message sent in a guild.
+///
+/// [`guild_id`]: ../struct.Message.html#structfield.guild_id
+/// [`mock_snowflake`]: fn.mock_snowflake.html
+pub fn mock_message<C: Into<ChannelId>>(channel_id: C, author: User, content: &str) -> Message {
+    let id = MessageId(mock_snowflake());
+
+    Message {
+        id: id,
+        attachments: vec![],
+        author: author,
+        channel_id: channel_id.into(),
+        content: content.to_string(),
+        edited_timestamp: None,
+        embeds: vec![],
+        flags: None,
+        guild_id: None,
+        kind: MessageType::Regular,
+        mention_everyone: false,
+        mention_roles: vec![],
+        mentions: vec![],
+        message_reference: None,
+        nonce: Nonce::None,
+        pinned: false,
+        reactions: vec![],
+        role_subscription_data: None,
+        sticker_items: vec![],
+        timestamp: timestamp(id.created_at()),
+        tts: false,
+        webhook_id: None,
+    }
+}
+
+/// Creates a role with no permissions at position `0`.
+pub fn mock_role<R: Into<RoleId>>(role_id: R, name: &str) -> Role {
+    Role {
+        id: role_id.into(),
+        #[cfg(feature = "utils")]
+        colour: Colour(0),
+        #[cfg(not(feature = "utils"))]
+        colour: 0,
+        hoist: false,
+        icon: None,
+        managed: false,
+        mentionable: false,
+        name: name.to_string(),
+        permissions: Permissions::empty(),
+        position: 0,
+        tags: None,
+        unicode_emoji: None,
+    }
+}
+
+/// Creates a user, named after their Id, with a discriminator of `0001`.
+pub fn mock_user<U: Into<UserId>>(user_id: U) -> User {
+    let id = user_id.into();
+
+    User {
+        id: id,
+        avatar: None,
+        bot: false,
+        discriminator: 1,
+        name: format!("user{}", id.0),
+        primary_guild: None,
+    }
+}
+
+fn timestamp(datetime: NaiveDateTime) -> DateTime<FixedOffset> {
+    DateTime::from_utc(datetime, FixedOffset::east(0))
+}
@@ -1,7 +1,11 @@
+use serde::de::{Deserialize, Deserializer};
+use serde_json::Value;
 use std::fmt;
+use std::result::Result as StdResult;
+use super::permissions::Permissions;
 use super::utils::deserialize_u16;
-use super::*;
-use ::misc::Mentionable;
+use super::misc::Mentionable;
+use super::{GuildId, UserId};
 
 #[cfg(all(feature = "cache", feature = "model"))]
 use CACHE;
@@ -10,7 +14,9 @@ use builder::{CreateMessage, EditProfile};
 #[cfg(feature = "model")]
 use chrono::NaiveDateTime;
 #[cfg(feature = "model")]
-use http::{self, GuildPagination};
+use http;
+#[cfg(feature = "model")]
+use internal::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
 use parking_lot::RwLock;
 #[cfg(feature = "model")]
@@ -23,7 +29,12 @@ use std::sync::Arc;
 use utils;
 
 /// Information about the current user.
-#[derive(Clone, Default, Debug, Deserialize)]
+///
+/// The user's [`email`] is masked in the `Debug` output, to keep it out of
+/// logs.
+///
+/// [`email`]: #structfield.email
+#[derive(Clone, Default, Deserialize)]
 pub struct CurrentUser {
     pub id: UserId,
     pub avatar: Option<String>,
@@ -35,6 +46,66 @@ pub struct CurrentUser {
     pub verified: bool,
 }
 
+impl fmt::Debug for CurrentUser {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CurrentUser")
+            .field("id", &self.id)
+            .field("avatar", &self.avatar)
+            .field("bot", &self.bot)
+            .field("discriminator", &self.discriminator)
+            .field("email", &self.email.as_ref().map(|_| "[redacted]"))
+            .field("mfa_enabled", &self.mfa_enabled)
+            .field("name", &self.name)
+            .field("verified", &self.verified)
+            .finish()
+    }
+}
+
+/// A guild that the current user is a member of, as listed by
+/// [`CurrentUser::guilds`].
+///
+/// [`CurrentUser::guilds`]: struct.CurrentUser.html#method.guilds
+#[derive(Clone, Debug, Deserialize)]
+pub struct CurrentUserGuild {
+    /// The unique Id of the guild.
+    pub id: GuildId,
+    /// The features enabled for the guild.
+    #[serde(default)]
+    pub features: Vec<String>,
+    /// The hash of the guild's icon.
+    pub icon: Option<String>,
+    /// The name of the guild.
+    pub name: String,
+    /// Whether the current user is the owner of the guild.
+    pub owner: bool,
+    /// The permissions that the current user has in the guild, excluding
+    /// channel overwrites.
+    pub permissions: Permissions,
+}
+
+/// A cursor into the list of the current user's guilds, which are ordered by
+/// Id.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum GuildPagination {
+    /// The guilds with an Id after the given guild.
+    After(GuildId),
+    /// The guilds with an Id before the given guild.
+    Before(GuildId),
+}
+
+#[cfg(feature = "model")]
+impl CurrentUser {
+    /// Gets a page of the guilds that the current user is a member of.
+    ///
+    /// Up to `limit` guilds are returned, with a maximum of 200. If no
+    /// cursor is given, the guilds from the start of the list are returned.
+    #[inline]
+    pub fn guilds(&self, target: Option<GuildPagination>, limit: u64)
+        -> Result<Vec<CurrentUserGuild>> {
+        http::get_guilds(target, limit)
+    }
+}
+
 /// An enum that represents a default avatar.
 ///
 /// The default avatar is calculated via the result of `discriminator % 5`.
@@ -110,7 +181,7 @@ impl Default for OnlineStatus {
 }
 
 /// Information about a user.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct User {
     /// The unique Id of the user. Can be used to calculate the account's
     /// cration date.
@@ -130,6 +201,66 @@ pub struct User {
     /// change if the username+discriminator pair becomes non-unique.
     #[serde(rename = "username")]
     pub name: String,
+    /// The guild whose tag the user displays next to their name, if any.
+    ///
+    /// Malformed values are ignored rather than failing to deserialize the
+    /// user.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_primary_guild",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub primary_guild: Option<PrimaryGuild>,
+}
+
+/// The guild that a [`User`] has chosen to display the tag of.
+///
+/// [`User`]: struct.User.html
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PrimaryGuild {
+    /// The hash of the tag's badge.
+    #[serde(default)]
+    pub badge: Option<String>,
+    /// Whether the user is displaying the tag.
+    ///
+    /// This is `None` if the tag was cleared by a system change, such as the
+    /// guild disabling tags.
+    #[serde(default)]
+    pub identity_enabled: Option<bool>,
+    /// The Id of the guild the tag belongs to.
+    #[serde(default)]
+    pub identity_guild_id: Option<GuildId>,
+    /// The text of the tag, up to 4 characters long.
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+impl PrimaryGuild {
+    /// Retrieves the URL to the tag's badge, if it has one.
+    pub fn badge_url(&self) -> Option<String> {
+        match (self.identity_guild_id, self.badge.as_ref()) {
+            (Some(guild_id), Some(badge)) => Some(format!(
+                "https://cdn.discordapp.com/guild-tag-badges/{}/{}.png",
+                guild_id,
+                badge,
+            )),
+            _ => None,
+        }
+    }
+}
+
+fn deserialize_primary_guild<'de, D: Deserializer<'de>>(deserializer: D)
+    -> StdResult<Option<PrimaryGuild>, D::Error> {
+    let value = Option::<Value>::deserialize(deserializer)?;
+
+    Ok(value.and_then(|value| PrimaryGuild::deserialize(value).ok()))
+}
+
+#[cfg(feature = "model")]
+impl UserId {
+    /// Requests the user from the REST API.
+    #[inline]
+    pub fn to_user(&self) -> Result<User> { http::get_user(self.0) }
 }
 
 use std::hash::{Hash, Hasher};
@@ -155,33 +286,3 @@ impl fmt::Display for User {
         fmt::Display::fmt(&self.id.mention(), f)
     }
 }
-
-impl From<CurrentUser> for UserId {
-    /// Gets the Id of a `CurrentUser` struct.
-    fn from(current_user: CurrentUser) -> UserId { current_user.id }
-}
-
-impl<'a> From<&'a CurrentUser> for UserId {
-    /// Gets the Id of a `CurrentUser` struct.
-    fn from(current_user: &CurrentUser) -> UserId { current_user.id }
-}
-
-impl From<Member> for UserId {
-    /// Gets the Id of a `Member`.
-    fn from(member: Member) -> UserId { member.user.id }
-}
-
-impl<'a> From<&'a Member> for UserId {
-    /// Gets the Id of a `Member`.
-    fn from(member: &Member) -> UserId { member.user.id }
-}
-
-impl From<User> for UserId {
-    /// Gets the Id of a `User`.
-    fn from(user: User) -> UserId { user.id }
-}
-
-impl<'a> From<&'a User> for UserId {
-    /// Gets the Id of a `User`.
-    fn from(user: &User) -> UserId { user.id }
-}
\ No newline at end of file
@@ -1,6 +1,28 @@
-use serde::de::Error as DeError;
+use serde::de::{Deserialize, Deserializer, Error as DeError, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::cmp;
 use std::collections::HashMap;
-use super::*;
+use std::fmt::{Formatter, Result as FmtResult};
+use std::result::Result as StdResult;
+use std::time::{SystemTime, UNIX_EPOCH};
+use super::{
+    Channel,
+    ChannelId,
+    Emoji,
+    EmojiId,
+    EmojiIdentifier,
+    GuildChannel,
+    IdMap,
+    LocalizationMap,
+    Member,
+    Presence,
+    ReactionType,
+    Role,
+    RoleId,
+    User,
+    UserId,
+    VoiceState,
+};
 
 #[cfg(feature = "cache")]
 use internal::prelude::*;
@@ -8,13 +30,15 @@ use internal::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
 use super::permissions::Permissions;
 #[cfg(all(feature = "cache", feature = "model"))]
+use super::ModelError;
+#[cfg(all(feature = "cache", feature = "model"))]
 use CACHE;
 
 pub fn deserialize_emojis<'de, D: Deserializer<'de>>(
     deserializer: D)
-    -> StdResult<HashMap<EmojiId, Emoji>, D::Error> {
+    -> StdResult<IdMap<EmojiId, Emoji>, D::Error> {
     let vec: Vec<Emoji> = Deserialize::deserialize(deserializer)?;
-    let mut emojis = HashMap::new();
+    let mut emojis = IdMap::default();
 
     for emoji in vec {
         emojis.insert(emoji.id, emoji);
@@ -25,9 +49,9 @@ pub fn deserialize_emojis<'de, D: Deserializer<'de>>(
 
 pub fn deserialize_guild_channels<'de, D: Deserializer<'de>>(
     deserializer: D)
-    -> StdResult<HashMap<ChannelId, GuildChannel>, D::Error> {
+    -> StdResult<IdMap<ChannelId, GuildChannel>, D::Error> {
     let vec: Vec<GuildChannel> = Deserialize::deserialize(deserializer)?;
-    let mut map = HashMap::new();
+    let mut map = IdMap::default();
 
     for channel in vec {
         map.insert(channel.id, channel);
@@ -36,11 +60,25 @@ pub fn deserialize_guild_channels<'de, D: Deserializer<'de>>(
     Ok(map)
 }
 
+/// Deserializes an optional map of localized strings, skipping any locales
+/// which are not known, so that newly added locales do not break models.
+pub fn deserialize_localizations<'de, D: Deserializer<'de>>(
+    deserializer: D)
+    -> StdResult<Option<LocalizationMap>, D::Error> {
+    let map = Option::<HashMap<String, String>>::deserialize(deserializer)?;
+
+    Ok(map.map(|map| {
+        map.into_iter()
+            .filter_map(|(code, value)| code.parse().ok().map(|locale| (locale, value)))
+            .collect()
+    }))
+}
+
 pub fn deserialize_members<'de, D: Deserializer<'de>>(
     deserializer: D)
-    -> StdResult<HashMap<UserId, Member>, D::Error> {
+    -> StdResult<IdMap<UserId, Member>, D::Error> {
     let vec: Vec<Member> = Deserialize::deserialize(deserializer)?;
-    let mut members = HashMap::new();
+    let mut members = IdMap::default();
 
     for member in vec {
         let user_id = member.user.id;
@@ -53,9 +91,9 @@ pub fn deserialize_members<'de, D: Deserializer<'de>>(
 
 pub fn deserialize_presences<'de, D: Deserializer<'de>>(
     deserializer: D)
-    -> StdResult<HashMap<UserId, Presence>, D::Error> {
+    -> StdResult<IdMap<UserId, Presence>, D::Error> {
     let vec: Vec<Presence> = Deserialize::deserialize(deserializer)?;
-    let mut presences = HashMap::new();
+    let mut presences = IdMap::default();
 
     for presence in vec {
         presences.insert(presence.user_id, presence);
@@ -66,9 +104,9 @@ pub fn deserialize_presences<'de, D: Deserializer<'de>>(
 
 pub fn deserialize_private_channels<'de, D: Deserializer<'de>>(
     deserializer: D)
-    -> StdResult<HashMap<ChannelId, Channel>, D::Error> {
+    -> StdResult<IdMap<ChannelId, Channel>, D::Error> {
     let vec: Vec<Channel> = Deserialize::deserialize(deserializer)?;
-    let mut private_channels = HashMap::new();
+    let mut private_channels = IdMap::default();
 
     for private_channel in vec {
         let id = match private_channel {
@@ -86,9 +124,9 @@ pub fn deserialize_private_channels<'de, D: Deserializer<'de>>(
 
 pub fn deserialize_roles<'de, D: Deserializer<'de>>(
     deserializer: D)
-    -> StdResult<HashMap<RoleId, Role>, D::Error> {
+    -> StdResult<IdMap<RoleId, Role>, D::Error> {
     let vec: Vec<Role> = Deserialize::deserialize(deserializer)?;
-    let mut roles = HashMap::new();
+    let mut roles = IdMap::default();
 
     for role in vec {
         roles.insert(role.id, role);
@@ -111,18 +149,65 @@ pub fn deserialize_single_recipient<'de, D: Deserializer<'de>>(
 }
 
 pub fn deserialize_u16<'de, D: Deserializer<'de>>(deserializer: D) -> StdResult<u16, D::Error> {
-    deserializer.deserialize_u16(U16Visitor)
+    deserializer.deserialize_any(U16Visitor)
 }
 
 pub fn deserialize_u64<'de, D: Deserializer<'de>>(deserializer: D) -> StdResult<u64, D::Error> {
-    deserializer.deserialize_u64(U64Visitor)
+    deserializer.deserialize_any(U64Visitor)
+}
+
+pub fn deserialize_option_u64<'de, D: Deserializer<'de>>(deserializer: D)
+    -> StdResult<Option<u64>, D::Error> {
+    struct OptionU64Visitor;
+
+    impl<'de> Visitor<'de> for OptionU64Visitor {
+        type Value = Option<u64>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+            formatter.write_str("an optional integer or string of an integer")
+        }
+
+        fn visit_none<E: DeError>(self) -> StdResult<Self::Value, E> { Ok(None) }
+
+        fn visit_unit<E: DeError>(self) -> StdResult<Self::Value, E> { Ok(None) }
+
+        fn visit_some<D: Deserializer<'de>>(self, deserializer: D)
+            -> StdResult<Self::Value, D::Error> {
+            deserialize_u64(deserializer).map(Some)
+        }
+    }
+
+    deserializer.deserialize_option(OptionU64Visitor)
+}
+
+/// Serializes an emoji as the partial emoji object that Discord expects in
+/// components and onboarding prompts.
+pub fn serialize_emoji<S>(emoji: &Option<ReactionType>, serializer: S) -> StdResult<S::Ok, S::Error>
+    where S: Serializer {
+    let mut map = serializer.serialize_map(None)?;
+
+    match *emoji {
+        Some(ReactionType::Custom { id, ref name }) => {
+            map.serialize_entry("id", &id)?;
+            map.serialize_entry("name", name)?;
+        },
+        Some(ReactionType::Unicode(ref name)) => map.serialize_entry("name", name)?,
+        None => {},
+    }
+
+    map.end()
+}
+
+pub fn serialize_single_recipient<S: Serializer>(user: &User, serializer: S)
+    -> StdResult<S::Ok, S::Error> {
+    [user].serialize(serializer)
 }
 
 pub fn deserialize_voice_states<'de, D: Deserializer<'de>>(
     deserializer: D)
-    -> StdResult<HashMap<UserId, VoiceState>, D::Error> {
+    -> StdResult<IdMap<UserId, VoiceState>, D::Error> {
     let vec: Vec<VoiceState> = Deserialize::deserialize(deserializer)?;
-    let mut voice_states = HashMap::new();
+    let mut voice_states = IdMap::default();
 
     for voice_state in vec {
         voice_states.insert(voice_state.user_id, voice_state);
@@ -172,6 +257,520 @@ pub fn user_has_perms(channel_id: ChannelId, mut permissions: Permissions) -> Re
     Ok(permissions.is_empty())
 }
 
+/// Builds a data URI out of raw image bytes, as expected by the API for image
+/// uploads such as emojis and avatars.
+///
+/// The image's format is detected from its contents; `None` is returned if it
+/// is not a PNG, JPEG, GIF, or WebP image.
+#[cfg(feature = "model")]
+pub fn image_data_uri(bytes: &[u8]) -> Option<String> {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mime = if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        "image/png"
+    } else if bytes.starts_with(b"\xff\xd8\xff") {
+        "image/jpeg"
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        "image/gif"
+    } else if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        "image/webp"
+    } else {
+        return None;
+    };
+
+    let mut uri = String::with_capacity(mime.len() + 13 + (bytes.len() + 2) / 3 * 4);
+    uri.push_str("data:");
+    uri.push_str(mime);
+    uri.push_str(";base64,");
+
+    for chunk in bytes.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                uri.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                uri.push('=');
+            }
+        }
+    }
+
+    Some(uri)
+}
+
+/// Parses a custom emoji, in the form `<:NAME:ID>` or `<a:NAME:ID>` for
+/// animated emojis, from the entirety of a string.
+pub fn parse_emoji(s: &str) -> Option<EmojiIdentifier> {
+    match parse_emoji_prefix(s) {
+        Some((emoji, len)) if len == s.len() => Some(emoji),
+        _ => None,
+    }
+}
+
+/// Finds every custom emoji within a string, such as a message's content, in
+/// the order that they occur.
+///
+/// Both static and animated emojis are found. Unicode emojis are not.
+pub fn parse_emojis(content: &str) -> Vec<EmojiIdentifier> {
+    let mut emojis = Vec::new();
+    let mut rest = content;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+
+        match parse_emoji_prefix(rest) {
+            Some((emoji, len)) => {
+                emojis.push(emoji);
+                rest = &rest[len..];
+            },
+            None => rest = &rest[1..],
+        }
+    }
+
+    emojis
+}
+
+/// Parses a user mention, in the form `<@ID>` or `<@!ID>` for mentions of a
+/// member's nickname, from the entirety of a string, returning the user's Id.
+pub fn parse_username(s: &str) -> Option<u64> {
+    if !s.starts_with("<@") || !s.ends_with('>') || s.len() < 4 {
+        return None;
+    }
+
+    let id = &s[2..s.len() - 1];
+    let id = if id.starts_with('!') { &id[1..] } else { id };
+
+    if id.is_empty() || !id.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    id.parse().ok()
+}
+
+/// Splits content, such as the arguments of a command, into whitespace
+/// separated arguments, in the manner of a shell.
+///
+/// Text within double quotes is kept as one argument, even if it contains
+/// whitespace, and an empty pair of quotes is an empty argument. A backslash
+/// escapes the character after it, such as a quote or another backslash. An
+/// unterminated quote runs to the end of the content.
+///
+/// ```rust,ignore
+/// let args = parse_quotes(r#"ban "some user" spamming \"links\""#);
+///
+/// assert_eq!(args, ["ban", "some user", "spamming", "\"links\""]);
+/// ```
+pub fn parse_quotes(content: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    // Whether an argument has been started, which may be empty if quoted.
+    let mut in_arg = false;
+    let mut in_quotes = false;
+    let mut chars = content.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+
+                in_arg = true;
+            },
+            '"' => {
+                in_quotes = !in_quotes;
+                in_arg = true;
+            },
+            c if c.is_whitespace() && !in_quotes => {
+                if in_arg {
+                    args.push(current.clone());
+                    current.clear();
+                    in_arg = false;
+                }
+            },
+            c => {
+                current.push(c);
+                in_arg = true;
+            },
+        }
+    }
+
+    if in_arg {
+        args.push(current);
+    }
+
+    args
+}
+
+/// Parses a custom emoji at the start of a string, returning it along with the
+/// length of its mention.
+fn parse_emoji_prefix(s: &str) -> Option<(EmojiIdentifier, usize)> {
+    if !s.starts_with('<') {
+        return None;
+    }
+
+    let end = s.find('>')?;
+    let inner = &s[1..end];
+
+    let (animated, inner) = if inner.starts_with("a:") {
+        (true, &inner[2..])
+    } else if inner.starts_with(':') {
+        (false, &inner[1..])
+    } else {
+        return None;
+    };
+
+    let colon = inner.find(':')?;
+    let (name, id) = (&inner[..colon], &inner[colon + 1..]);
+
+    let valid_name = name.len() >= 2
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    let valid_id = !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit());
+
+    if !valid_name || !valid_id {
+        return None;
+    }
+
+    let emoji = EmojiIdentifier {
+        animated: animated,
+        id: EmojiId(id.parse().ok()?),
+        name: name.to_string(),
+    };
+
+    Some((emoji, end + 1))
+}
+
+/// The maximum number of characters in the content of a message.
+pub const MAX_MESSAGE_LENGTH: usize = 2000;
+
+/// Splits content into chunks of at most `limit` characters, such as
+/// [`MAX_MESSAGE_LENGTH`], so that it can be sent over multiple messages.
+///
+/// Content is split between lines where possible, and lines longer than the
+/// limit are split between words. A code block that is split across chunks
+/// is closed at the end of one chunk and reopened, with the same language, at
+/// the start of the next, so that each chunk renders on its own.
+///
+/// The limit should be comfortably longer than the opening line of any code
+/// block in the content, as that must fit within each chunk it spans.
+///
+/// [`MAX_MESSAGE_LENGTH`]: constant.MAX_MESSAGE_LENGTH.html
+pub fn split_message(content: &str, limit: usize) -> Vec<String> {
+    let mut chunker = MessageChunker {
+        chunks: Vec::new(),
+        current: String::new(),
+        current_len: 0,
+        fence: None,
+        has_content: false,
+        limit: limit,
+        trailing_fence: None,
+    };
+
+    for line in content.split('\n') {
+        chunker.push_line(line);
+    }
+
+    chunker.finish()
+}
+
+/// Accumulates the lines of content into chunks for [`split_message`].
+///
+/// While a code block is open, room is always kept at the end of `current` to
+/// close it, so that a chunk never exceeds the limit once it is closed.
+///
+/// [`split_message`]: fn.split_message.html
+struct MessageChunker {
+    chunks: Vec<String>,
+    current: String,
+    /// The length of `current`, in characters.
+    current_len: usize,
+    /// The opening line of the code block that is open at the end of
+    /// `current`.
+    fence: Option<String>,
+    /// Whether `current` has any content beyond a reopened code block.
+    has_content: bool,
+    limit: usize,
+    /// The length of `current`, in bytes, before the opening line of a code
+    /// block that nothing has been added after yet.
+    trailing_fence: Option<usize>,
+}
+
+impl MessageChunker {
+    fn push_line(&mut self, line: &str) {
+        let is_fence = line.trim().starts_with("```");
+        let is_closing_fence = is_fence && self.fence.is_some();
+        // Room is kept to close the code block that is open after the line.
+        let reserved = if self.fence.is_some() != is_fence { 4 } else { 0 };
+        let mut rest = line;
+
+        loop {
+            let separator = if self.current.is_empty() && !self.has_content { 0 } else { 1 };
+            let used = self.current_len + separator + reserved;
+
+            if used + rest.chars().count() <= self.limit {
+                let before = self.current.len();
+                self.append(rest, separator);
+
+                if is_fence && !is_closing_fence {
+                    self.trailing_fence = Some(before);
+                }
+
+                break;
+            }
+
+            if self.has_content {
+                self.flush();
+
+                // The code block has just been closed, so rather than
+                // reopening it only to close it again, the next chunk starts
+                // with any text following the fence.
+                if is_closing_fence {
+                    self.current.clear();
+                    self.current_len = 0;
+                    self.fence = None;
+
+                    let text = line.trim()[3..].trim();
+
+                    if !text.is_empty() {
+                        self.push_line(text);
+                    }
+
+                    return;
+                }
+
+                continue;
+            }
+
+            let available = self.limit.saturating_sub(used);
+            let (piece, remainder) = split_words(rest, cmp::max(available, 1));
+            self.append(piece, separator);
+            self.flush();
+
+            if remainder.is_empty() {
+                break;
+            }
+
+            rest = remainder;
+        }
+
+        if is_fence {
+            self.fence = match self.fence {
+                Some(_) => None,
+                None => Some(line.trim().to_string()),
+            };
+        }
+    }
+
+    fn append(&mut self, text: &str, separator: usize) {
+        if separator == 1 {
+            self.current.push('\n');
+        }
+
+        self.current.push_str(text);
+        self.current_len += separator + text.chars().count();
+        self.has_content = true;
+        self.trailing_fence = None;
+    }
+
+    fn flush(&mut self) {
+        // A code block opened at the very end of the chunk is moved to the
+        // next chunk, rather than being left empty.
+        if let Some(len) = self.trailing_fence.take() {
+            self.current.truncate(len);
+        } else if self.fence.is_some() {
+            self.current.push_str("\n```");
+        }
+
+        if !self.current.trim().is_empty() {
+            self.chunks.push(self.current.clone());
+        }
+
+        self.current.clear();
+        self.current_len = 0;
+        self.has_content = false;
+
+        if let Some(ref fence) = self.fence {
+            self.current.push_str(fence);
+            self.current_len = fence.chars().count();
+        }
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        if self.has_content && !self.current.trim().is_empty() {
+            self.chunks.push(self.current);
+        }
+
+        self.chunks
+    }
+}
+
+/// Splits a line at or before the given number of characters, preferring to
+/// split at whitespace, which is dropped.
+fn split_words(line: &str, max: usize) -> (&str, &str) {
+    let end = line.char_indices().nth(max).map_or(line.len(), |(index, _)| index);
+    // Whitespace just past the end still lets the whole of `max` be used.
+    let split_at = if line[end..].starts_with(char::is_whitespace) {
+        Some(end)
+    } else {
+        line[..end].rfind(char::is_whitespace)
+    };
+
+    match split_at {
+        Some(index) if index > 0 => {
+            let whitespace_len = line[index..].chars().next().map_or(1, char::len_utf8);
+
+            (&line[..index], &line[index + whitespace_len..])
+        },
+        _ => (&line[..end], &line[end..]),
+    }
+}
+
+/// The Unix timestamp, in milliseconds, that snowflake timestamps are
+/// relative to: the first second of 2015.
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// A raw snowflake, the 64-bit identifier format used for all Ids.
+///
+/// Each snowflake is made up of, from most to least significant bits:
+///
+/// - 42 bits: milliseconds since the [`DISCORD_EPOCH`];
+/// - 5 bits: the internal worker Id;
+/// - 5 bits: the internal process Id;
+/// - 12 bits: an increment for snowflakes generated within the same
+/// millisecond.
+///
+/// [`DISCORD_EPOCH`]: constant.DISCORD_EPOCH.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Snowflake(pub u64);
+
+/// The individual parts of a [`Snowflake`], as returned by
+/// [`Snowflake::decompose`].
+///
+/// [`Snowflake`]: struct.Snowflake.html
+/// [`Snowflake::decompose`]: struct.Snowflake.html#method.decompose
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SnowflakeParts {
+    /// The Unix timestamp, in milliseconds, that the snowflake was created at.
+    pub timestamp: u64,
+    /// The internal worker Id. Only the lower 5 bits are used.
+    pub worker_id: u8,
+    /// The internal process Id. Only the lower 5 bits are used.
+    pub process_id: u8,
+    /// The increment. Only the lower 12 bits are used.
+    pub increment: u16,
+}
+
+impl Snowflake {
+    /// Assembles a snowflake from its parts.
+    ///
+    /// Parts which are out of range are truncated to the number of bits
+    /// available to them, and timestamps before the [`DISCORD_EPOCH`] are
+    /// clamped to it.
+    ///
+    /// [`DISCORD_EPOCH`]: constant.DISCORD_EPOCH.html
+    pub fn from_parts(parts: SnowflakeParts) -> Self {
+        let timestamp = parts.timestamp.saturating_sub(DISCORD_EPOCH) & 0x3FF_FFFF_FFFF;
+
+        Snowflake(
+            (timestamp << 22)
+                | (u64::from(parts.worker_id & 0x1F) << 17)
+                | (u64::from(parts.process_id & 0x1F) << 12)
+                | u64::from(parts.increment & 0xFFF),
+        )
+    }
+
+    /// Splits the snowflake into its individual parts.
+    pub fn decompose(&self) -> SnowflakeParts {
+        SnowflakeParts {
+            timestamp: (self.0 >> 22) + DISCORD_EPOCH,
+            worker_id: ((self.0 >> 17) & 0x1F) as u8,
+            process_id: ((self.0 >> 12) & 0x1F) as u8,
+            increment: (self.0 & 0xFFF) as u16,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Snowflake {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        deserializer.deserialize_any(U64Visitor).map(Snowflake)
+    }
+}
+
+impl From<u64> for Snowflake {
+    fn from(snowflake: u64) -> Snowflake { Snowflake(snowflake) }
+}
+
+impl From<Snowflake> for u64 {
+    fn from(snowflake: Snowflake) -> u64 { snowflake.0 }
+}
+
+/// Generates valid snowflakes for a worker and process, such as for test data
+/// or tooling.
+///
+/// Generated snowflakes are strictly increasing: if a timestamp earlier than
+/// or equal to the last one is given, the last timestamp is reused with the
+/// next increment, moving on to the next millisecond once the increment is
+/// exhausted.
+#[derive(Clone, Debug)]
+pub struct SnowflakeGenerator {
+    worker_id: u8,
+    process_id: u8,
+    last: Option<SnowflakeParts>,
+}
+
+impl SnowflakeGenerator {
+    /// Creates a generator for the given worker and process Ids, each of which
+    /// is truncated to 5 bits.
+    pub fn new(worker_id: u8, process_id: u8) -> Self {
+        SnowflakeGenerator {
+            worker_id: worker_id & 0x1F,
+            process_id: process_id & 0x1F,
+            last: None,
+        }
+    }
+
+    /// Generates a snowflake for the current time.
+    pub fn generate(&mut self) -> Snowflake {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() * 1000 + u64::from(elapsed.subsec_nanos() / 1_000_000))
+            .unwrap_or(DISCORD_EPOCH);
+
+        self.generate_at(now)
+    }
+
+    /// Generates a snowflake for the given Unix timestamp, in milliseconds.
+    ///
+    /// Timestamps before the [`DISCORD_EPOCH`] are clamped to it.
+    ///
+    /// [`DISCORD_EPOCH`]: constant.DISCORD_EPOCH.html
+    pub fn generate_at(&mut self, timestamp: u64) -> Snowflake {
+        let timestamp = cmp::max(timestamp, DISCORD_EPOCH);
+
+        let (timestamp, increment) = match self.last {
+            Some(last) if timestamp <= last.timestamp => {
+                if last.increment >= 0xFFF {
+                    (last.timestamp + 1, 0)
+                } else {
+                    (last.timestamp, last.increment + 1)
+                }
+            },
+            _ => (timestamp, 0),
+        };
+
+        let parts = SnowflakeParts {
+            timestamp: timestamp,
+            worker_id: self.worker_id,
+            process_id: self.process_id,
+            increment: increment,
+        };
+        self.last = Some(parts);
+
+        Snowflake::from_parts(parts)
+    }
+}
+
 #[derive(Debug)]
 pub struct U16Visitor;
 
@@ -231,3 +830,97 @@ impl<'de> Visitor<'de> for U64Visitor {
 
     fn visit_u64<E: DeError>(self, v: u64) -> StdResult<Self::Value, E> { Ok(v) }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::split_message;
+
+    const CONTENT: &'static str = "alpha z alpha\nbeta beta beta\n```rust\n\n```";
+
+    /// Checks that every chunk is within the limit, has its code blocks
+    /// closed, and has no code blocks that were emptied by the split.
+    fn assert_chunks(content: &str, limit: usize) {
+        for chunk in split_message(content, limit) {
+            assert!(
+                chunk.chars().count() <= limit,
+                "chunk of {:?} over {} characters: {:?}",
+                content,
+                limit,
+                chunk,
+            );
+
+            let lines = chunk.split('\n').collect::<Vec<&str>>();
+            let fences = lines.iter().filter(|line| line.trim().starts_with("```")).count();
+
+            assert_eq!(fences % 2, 0, "unclosed code block: {:?}", chunk);
+
+            let mut open = false;
+
+            for pair in lines.windows(2) {
+                if pair[0].trim().starts_with("```") {
+                    open = !open;
+
+                    assert!(!(open && pair[1] == "```"), "empty code block: {:?}", chunk);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn split_at_lines() {
+        assert_eq!(
+            split_message("first line\nsecond line\nthird line", 22),
+            vec!["first line\nsecond line", "third line"],
+        );
+    }
+
+    #[test]
+    fn split_long_line_at_words() {
+        assert_eq!(
+            split_message("one two three four five", 10),
+            vec!["one two", "three four", "five"],
+        );
+    }
+
+    #[test]
+    fn reopen_code_block() {
+        assert_eq!(
+            split_message("```rust\nlet a = 1;\nlet b = 2;\n```", 24),
+            vec!["```rust\nlet a = 1;\n```", "```rust\nlet b = 2;\n```"],
+        );
+    }
+
+    #[test]
+    fn move_code_block_to_next_chunk() {
+        assert_eq!(
+            split_message(CONTENT, 40),
+            vec!["alpha z alpha\nbeta beta beta", "```rust\n\n```"],
+        );
+    }
+
+    #[test]
+    fn keep_text_after_closing_fence() {
+        assert_eq!(
+            split_message("intro\n```\nlet x = 1;\n``` and then the explanation", 25),
+            vec!["intro\n```\nlet x = 1;\n```", "and then the explanation"],
+        );
+    }
+
+    #[test]
+    fn chunks_fit_limit() {
+        let contents = [
+            CONTENT,
+            "```rust\n\n\n```\ntext after the block",
+            "intro\n```\nfn main() {\n    println!(\"a long line of code here\");\n}\n```\noutro",
+            "```py\nprint('one')\n```\n```js\nconsole.log('two')\n```",
+            "intro\n```\nlet x = 1;\n``` and then the explanation",
+            "a line with several words that is longer than most limits\n\n\nand more",
+        ];
+
+        for content in &contents {
+            for limit in 20..100 {
+                assert_chunks(content, limit);
+            }
+        }
+    }
+}
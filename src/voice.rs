@@ -1,4 +1,5 @@
-use super::*;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use super::{ChannelId, UserId};
 
 /// Information about an available voice region.
 #[derive(Clone, Debug, Deserialize)]
@@ -22,7 +23,11 @@ pub struct VoiceRegion {
 }
 
 /// A user's state within a voice channel.
-#[derive(Clone, Debug, Deserialize)]
+///
+/// The voice [`token`] is redacted from the `Debug` output.
+///
+/// [`token`]: #structfield.token
+#[derive(Clone, Deserialize, Serialize)]
 pub struct VoiceState {
     pub channel_id: Option<ChannelId>,
     pub deaf: bool,
@@ -34,3 +39,19 @@ pub struct VoiceState {
     pub token: Option<String>,
     pub user_id: UserId,
 }
+
+impl Debug for VoiceState {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("VoiceState")
+            .field("channel_id", &self.channel_id)
+            .field("deaf", &self.deaf)
+            .field("mute", &self.mute)
+            .field("self_deaf", &self.self_deaf)
+            .field("self_mute", &self.self_mute)
+            .field("session_id", &self.session_id)
+            .field("suppress", &self.suppress)
+            .field("token", &self.token.as_ref().map(|_| "[redacted]"))
+            .field("user_id", &self.user_id)
+            .finish()
+    }
+}
@@ -1,18 +1,27 @@
-use super::*;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use serenity_common::prelude::Value;
+use super::{ChannelId, GuildId, MessageFlags, User, WebhookId};
 
-#[cfg(feature = "model")]
-use builder::ExecuteWebhook;
 #[cfg(feature = "model")]
 use internal::prelude::*;
 #[cfg(feature = "model")]
-use std::mem;
+use serde_json;
+#[cfg(feature = "model")]
+use serenity_common::prelude::JsonMap;
+#[cfg(feature = "model")]
+use super::{Message, MessageId, ModelError};
 #[cfg(feature = "model")]
 use {http, utils};
 
 /// A representation of a webhook, which is a low-effort way to post messages to
 /// channels. They do not necessarily require a bot user or authentication to
 /// use.
-#[derive(Clone, Debug, Deserialize)]
+///
+/// The webhook's token is redacted from the `Debug` output, and can only be
+/// accessed via [`expose_token`].
+///
+/// [`expose_token`]: #method.expose_token
+#[derive(Clone, Deserialize)]
 pub struct Webhook {
     /// The unique Id.
     ///
@@ -20,9 +29,9 @@ pub struct Webhook {
     pub id: WebhookId,
     /// The default avatar.
     ///
-    /// This can be modified via [`ExecuteWebhook::avatar`].
+    /// This can be overridden per message via [`ExecuteWebhook::avatar_url`].
     ///
-    /// [`ExecuteWebhook::avatar`]: ../builder/struct.ExecuteWebhook.html#method.avatar
+    /// [`ExecuteWebhook::avatar_url`]: struct.ExecuteWebhook.html#method.avatar_url
     pub avatar: Option<String>,
     /// The Id of the channel that owns the webhook.
     pub channel_id: ChannelId,
@@ -30,14 +39,344 @@ pub struct Webhook {
     pub guild_id: Option<GuildId>,
     /// The default name of the webhook.
     ///
-    /// This can be modified via [`ExecuteWebhook::username`].
+    /// This can be overridden per message via [`ExecuteWebhook::username`].
     ///
-    /// [`ExecuteWebhook::username`]: ../builder/struct.ExecuteWebhook.html#method.username
+    /// [`ExecuteWebhook::username`]: struct.ExecuteWebhook.html#method.username
     pub name: Option<String>,
     /// The webhook's secure token.
-    pub token: String,
+    token: String,
     /// The user that created the webhook.
     ///
     /// **Note**: This is not received when getting a webhook by its token.
     pub user: Option<User>,
-}
\ No newline at end of file
+}
+
+impl Webhook {
+    /// Retrieves the webhook's secure token.
+    ///
+    /// **Note**: Keep this information private, as anyone with the token can
+    /// execute the webhook.
+    #[inline]
+    pub fn expose_token(&self) -> &str { &self.token }
+}
+
+impl Debug for Webhook {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("Webhook")
+            .field("id", &self.id)
+            .field("avatar", &self.avatar)
+            .field("channel_id", &self.channel_id)
+            .field("guild_id", &self.guild_id)
+            .field("name", &self.name)
+            .field("token", &"[redacted]")
+            .field("user", &self.user)
+            .finish()
+    }
+}
+
+#[cfg(feature = "model")]
+impl Webhook {
+    /// Deletes the webhook.
+    ///
+    /// This uses the webhook's token, and so does not require any permissions.
+    #[inline]
+    pub fn delete(&self) -> Result<()> {
+        http::delete_webhook_with_token(self.id.0, self.expose_token())
+    }
+
+    /// Deletes a message that was sent through the webhook.
+    ///
+    /// This uses the webhook's token, and so does not require any permissions.
+    #[inline]
+    pub fn delete_message<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
+        http::delete_webhook_message(self.id.0, self.expose_token(), message_id.into().0, None)
+    }
+
+    /// Edits the webhook's default name and avatar, updating the webhook with
+    /// the result.
+    ///
+    /// Either may be `None` to leave it unchanged. The avatar is given as the
+    /// raw bytes of an image.
+    ///
+    /// This uses the webhook's token, and so does not require any permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidWebhookName`] if the name is not between
+    /// 1 and 80 characters long or contains "clyde", and a
+    /// [`ModelError::UnsupportedImageFormat`] if the avatar is not a PNG,
+    /// JPEG, GIF, or WebP image.
+    ///
+    /// [`ModelError::InvalidWebhookName`]: enum.ModelError.html#variant.InvalidWebhookName
+    /// [`ModelError::UnsupportedImageFormat`]: enum.ModelError.html#variant.UnsupportedImageFormat
+    pub fn edit(&mut self, name: Option<&str>, avatar: Option<&[u8]>) -> Result<()> {
+        let mut map = JsonMap::new();
+
+        if let Some(name) = name {
+            validate_webhook_name(name)?;
+
+            map.insert("name".to_string(), Value::String(name.to_string()));
+        }
+
+        if let Some(avatar) = avatar {
+            let avatar = utils::image_data_uri(avatar)
+                .ok_or(Error::Model(ModelError::UnsupportedImageFormat))?;
+
+            map.insert("avatar".to_string(), Value::String(avatar));
+        }
+
+        let edited = http::edit_webhook_with_token(
+            self.id.0,
+            self.expose_token(),
+            &Value::Object(map),
+        )?;
+        *self = edited;
+
+        Ok(())
+    }
+
+    /// Edits a message that was sent through the webhook, returning the edited
+    /// message.
+    ///
+    /// This uses the webhook's token, and so does not require any permissions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// // assumes a `webhook` and `message_id` have already been bound
+    /// let message = webhook.edit_message(message_id, |m| m.content("edited"))?;
+    /// ```
+    pub fn edit_message<M, F>(&self, message_id: M, f: F) -> Result<Message>
+        where M: Into<MessageId>, F: FnOnce(EditWebhookMessage) -> EditWebhookMessage {
+        edit_webhook_message(self.id.0, self.expose_token(), message_id.into(), f)
+    }
+
+    /// Executes the webhook, sending a message through it.
+    ///
+    /// If `wait` is `true`, then Discord waits for the message to be created
+    /// and the message is returned. Otherwise, `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// Sending a message with a custom username:
+    ///
+    /// ```rust,ignore
+    /// // assumes a `webhook` has already been bound
+    /// let message = webhook.execute(true, |w| w
+    ///     .content("hello")
+    ///     .username("logger"))?;
+    /// ```
+    pub fn execute<F>(&self, wait: bool, f: F) -> Result<Option<Message>>
+        where F: FnOnce(ExecuteWebhook) -> ExecuteWebhook {
+        let execution = f(ExecuteWebhook::default());
+        let map = serde_json::to_value(&execution)?;
+
+        http::execute_webhook(
+            self.id.0,
+            self.expose_token(),
+            wait,
+            execution.thread_id.map(|id| id.0),
+            &map,
+        )
+    }
+}
+
+/// Edits a message sent through a webhook, or a followup message of an
+/// interaction, whose Id is the application's Id.
+#[cfg(feature = "model")]
+pub(crate) fn edit_webhook_message<F>(webhook_id: u64, token: &str, message_id: MessageId, f: F)
+    -> Result<Message> where F: FnOnce(EditWebhookMessage) -> EditWebhookMessage {
+    let edit = f(EditWebhookMessage::default());
+    let map = serde_json::to_value(&edit)?;
+
+    http::edit_webhook_message(
+        webhook_id,
+        token,
+        message_id.0,
+        edit.thread_id.map(|id| id.0),
+        &map,
+    )
+}
+
+/// Checks that a webhook name is between 1 and 80 characters long and does
+/// not contain "clyde", which Discord reserves.
+#[cfg(feature = "model")]
+pub(crate) fn validate_webhook_name(name: &str) -> Result<()> {
+    let len = name.chars().count();
+    let valid = len >= 1 && len <= 80 && !name.to_lowercase().contains("clyde");
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::Model(ModelError::InvalidWebhookName))
+    }
+}
+
+#[cfg(feature = "model")]
+impl WebhookId {
+    /// Retrieves the webhook using its token.
+    ///
+    /// Unlike retrieving a webhook by its Id alone, this does not require the
+    /// [Manage Webhooks] permission, although the [`user`] that created the
+    /// webhook is not received.
+    ///
+    /// [`user`]: struct.Webhook.html#structfield.user
+    /// [Manage Webhooks]: permissions/constant.MANAGE_WEBHOOKS.html
+    #[inline]
+    pub fn to_webhook_with_token(&self, token: &str) -> Result<Webhook> {
+        http::get_webhook_with_token(self.0, token)
+    }
+}
+
+/// The payload of a message sent through a [`Webhook`].
+///
+/// At least one of the content, embeds, or components must be set for the
+/// execution to succeed.
+///
+/// [`Webhook`]: struct.Webhook.html
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ExecuteWebhook {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    components: Vec<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    embeds: Vec<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<MessageFlags>,
+    // This is sent as a query parameter rather than in the body.
+    #[serde(skip)]
+    thread_id: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+}
+
+impl ExecuteWebhook {
+    /// Overrides the default avatar of the webhook with an image URL.
+    pub fn avatar_url(mut self, avatar_url: &str) -> Self {
+        self.avatar_url = Some(avatar_url.to_string());
+
+        self
+    }
+
+    /// Sets the message components, each being a serialized action row.
+    ///
+    /// Rows built with [`CreateActionRow`] can be validated and converted
+    /// through [`action_rows_to_values`].
+    ///
+    /// [`CreateActionRow`]: struct.CreateActionRow.html
+    /// [`action_rows_to_values`]: fn.action_rows_to_values.html
+    pub fn components(mut self, components: Vec<Value>) -> Self {
+        self.components = components;
+
+        self
+    }
+
+    /// Sets the content of the message.
+    pub fn content<D: ToString>(mut self, content: D) -> Self {
+        self.content = Some(content.to_string());
+
+        self
+    }
+
+    /// Sets the embeds of the message, each being a serialized embed.
+    ///
+    /// Up to 10 embeds may be sent with a single message.
+    pub fn embeds(mut self, embeds: Vec<Value>) -> Self {
+        self.embeds = embeds;
+
+        self
+    }
+
+    /// Sets the flags of the message.
+    ///
+    /// Only [`MessageFlags::SUPPRESS_EMBEDS`] can be set when sending, along
+    /// with [`MessageFlags::EPHEMERAL`] for interaction followups.
+    ///
+    /// [`MessageFlags::EPHEMERAL`]: struct.MessageFlags.html#associatedconstant.EPHEMERAL
+    /// [`MessageFlags::SUPPRESS_EMBEDS`]: struct.MessageFlags.html#associatedconstant.SUPPRESS_EMBEDS
+    pub fn flags(mut self, flags: MessageFlags) -> Self {
+        self.flags = Some(flags);
+
+        self
+    }
+
+    /// Sends the message to a thread within the webhook's channel.
+    pub fn thread_id<C: Into<ChannelId>>(mut self, thread_id: C) -> Self {
+        self.thread_id = Some(thread_id.into());
+
+        self
+    }
+
+    /// Sets whether the message is played back via text-to-speech.
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.tts = Some(tts);
+
+        self
+    }
+
+    /// Overrides the default name of the webhook.
+    pub fn username(mut self, username: &str) -> Self {
+        self.username = Some(username.to_string());
+
+        self
+    }
+}
+
+/// The payload used to edit a message sent through a [`Webhook`], or a
+/// followup message of an interaction.
+///
+/// Fields that are not set are left unchanged.
+///
+/// [`Webhook`]: struct.Webhook.html
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EditWebhookMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embeds: Option<Vec<Value>>,
+    // This is sent as a query parameter rather than in the body.
+    #[serde(skip)]
+    thread_id: Option<ChannelId>,
+}
+
+impl EditWebhookMessage {
+    /// Replaces the message components, each being a serialized action row.
+    ///
+    /// An empty list removes all components.
+    pub fn components(mut self, components: Vec<Value>) -> Self {
+        self.components = Some(components);
+
+        self
+    }
+
+    /// Replaces the content of the message.
+    pub fn content<D: ToString>(mut self, content: D) -> Self {
+        self.content = Some(content.to_string());
+
+        self
+    }
+
+    /// Replaces the embeds of the message, each being a serialized embed.
+    ///
+    /// An empty list removes all embeds.
+    pub fn embeds(mut self, embeds: Vec<Value>) -> Self {
+        self.embeds = Some(embeds);
+
+        self
+    }
+
+    /// Sets the thread that the message is in, if it was sent to a thread
+    /// within the webhook's channel.
+    pub fn thread_id<C: Into<ChannelId>>(mut self, thread_id: C) -> Self {
+        self.thread_id = Some(thread_id.into());
+
+        self
+    }
+}